@@ -3,3 +3,9 @@
 pub fn secret_1kb() -> &'static [u8] {
     include_bytes!("resources/1KB.txt")
 }
+
+/// A 1 MB secret, made of the 1 KB one repeated.
+#[allow(dead_code)]
+pub fn secret_1mb() -> Vec<u8> {
+    secret_1kb().iter().cycle().take(1 << 20).cloned().collect()
+}
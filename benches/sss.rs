@@ -19,7 +19,7 @@ mod sss {
                 let secret = shared::$secret();
 
                 b.iter(move || {
-                    let shares = sss::split_secret($k, $n, secret, $signed).unwrap();
+                    let shares = sss::split_secret($k, $n, &secret, $signed).unwrap();
                     black_box(shares);
                 });
             }
@@ -54,4 +54,11 @@ mod sss {
     bench_generate!(generate_1kb_10_25_signed, 10, 25, secret_1kb, true);
     bench_recover!(recover_1kb_10_25_signed, 10, 25, secret_1kb, true);
 
+    bench_recover!(recover_1mb_3_5, 3, 5, secret_1mb, false);
+    bench_recover!(recover_1mb_10_25, 10, 25, secret_1mb, false);
+
+    // The counterparts of the n-of-n benchmarks of the `xor` scheme.
+    bench_generate!(generate_1mb_5_5, 5, 5, secret_1mb, false);
+    bench_recover!(recover_1mb_5_5, 5, 5, secret_1mb, false);
+
 }
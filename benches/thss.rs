@@ -29,11 +29,15 @@ mod thss {
 
     macro_rules! bench_recover {
         ($name:ident, $k:expr, $n:expr, $secret:ident) => {
+            bench_recover!($name, $k, $n, $secret, $k);
+        };
+        // Recovering from more than `k` shares checks the extra ones for consistency.
+        ($name:ident, $k:expr, $n:expr, $secret:ident, $count:expr) => {
             #[bench]
             fn $name(b: &mut Bencher) {
                 let secret = shared::$secret();
                 let all_shares = thss::split_secret($k, $n, &secret, &None).unwrap();
-                let shares = &all_shares.into_iter().take($k).collect::<Vec<_>>().clone();
+                let shares = &all_shares.into_iter().take($count).collect::<Vec<_>>().clone();
 
                 b.iter(|| {
                     let result = thss::recover_secret(&shares.to_vec()).unwrap();
@@ -48,5 +52,10 @@ mod thss {
 
     bench_generate!(generate_1kb_10_25, 10, 25, secret_1kb);
     bench_recover!(recover_1kb_10_25, 10, 25, secret_1kb);
+    bench_recover!(recover_1kb_10_25_checked, 10, 25, secret_1kb, 25);
+
+    bench_recover!(recover_1mb_3_5, 3, 5, secret_1mb);
+    bench_recover!(recover_1mb_10_25, 10, 25, secret_1mb);
+    bench_recover!(recover_1mb_10_25_checked, 10, 25, secret_1mb, 25);
 
 }
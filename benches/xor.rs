@@ -0,0 +1,59 @@
+#![cfg(test)]
+#![feature(test)]
+
+extern crate rusty_secrets;
+extern crate test;
+
+mod shared;
+
+mod xor {
+
+    use rusty_secrets::xor;
+    use shared;
+    use test::{black_box, Bencher};
+
+    macro_rules! bench_generate {
+        ($name:ident, $n:expr, $secret:ident, $signed:expr) => {
+            #[bench]
+            fn $name(b: &mut Bencher) {
+                let secret = shared::$secret();
+
+                b.iter(|| {
+                    let shares = xor::split_secret($n, &secret, $signed).unwrap();
+                    black_box(shares);
+                });
+            }
+        };
+    }
+
+    macro_rules! bench_recover {
+        ($name:ident, $n:expr, $secret:ident, $signed:expr) => {
+            #[bench]
+            fn $name(b: &mut Bencher) {
+                let secret = shared::$secret();
+                let shares = xor::split_secret($n, &secret, $signed).unwrap();
+
+                b.iter(|| {
+                    let result = xor::recover_secret(&shares, $signed).unwrap();
+                    black_box(result);
+                });
+            }
+        };
+    }
+
+    bench_generate!(generate_1kb_5_5, 5, secret_1kb, false);
+    bench_recover!(recover_1kb_5_5, 5, secret_1kb, false);
+
+    bench_generate!(generate_1kb_5_5_signed, 5, secret_1kb, true);
+    bench_recover!(recover_1kb_5_5_signed, 5, secret_1kb, true);
+
+    bench_generate!(generate_1kb_25_25, 25, secret_1kb, false);
+    bench_recover!(recover_1kb_25_25, 25, secret_1kb, false);
+
+    bench_generate!(generate_1mb_5_5, 5, secret_1mb, false);
+    bench_recover!(recover_1mb_5_5, 5, secret_1mb, false);
+
+    bench_generate!(generate_1mb_25_25, 25, secret_1mb, false);
+    bench_recover!(recover_1mb_25_25, 25, secret_1mb, false);
+
+}
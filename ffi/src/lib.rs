@@ -0,0 +1,655 @@
+//! C bindings to the `sss`, `wrapped_secrets` and `dss::ss1` schemes of `rusty_secrets`.
+//!
+//! This crate builds the `cdylib` and `staticlib` exposing the bindings, whose C header lives
+//! in `include/rusty_secrets.h`, and is generated from this crate with `make header`.
+//!
+//! Every function returns `RUSTY_SECRETS_OK` on success, and an error code otherwise, in which
+//! case a description of the error can be retrieved with `rusty_secrets_last_error`.
+//! Error codes are the ones of `rusty_secrets::errors::ErrorCode`, which the header declares
+//! as the `RustySecretsError` enumeration, and which also holds the codes 60 and above,
+//! reported for errors specific to the bindings.
+//!
+//! Buffers, strings and shares returned by the library are owned by the caller, and must be
+//! freed with `rusty_secrets_buffer_free`, `rusty_secrets_string_free` and
+//! `rusty_secrets_shares_free` respectively.
+
+#![deny(missing_docs, missing_debug_implementations, missing_copy_implementations, trivial_casts,
+        trivial_numeric_casts, unstable_features, unused_import_braces, unused_qualifications)]
+
+extern crate rusty_secrets;
+
+use std::cell::RefCell;
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+use std::slice;
+
+use rusty_secrets::dss::ss1;
+use rusty_secrets::errors::*;
+use rusty_secrets::inspect::inspect_share;
+use rusty_secrets::scheme::SchemeKind;
+use rusty_secrets::share_set::SHARE_SET_ID_LEN;
+use rusty_secrets::{sss, wrapped_secrets};
+
+/// The call succeeded.
+pub const RUSTY_SECRETS_OK: i32 = 0;
+
+/// A buffer allocated by the library, to be freed with `rusty_secrets_buffer_free`.
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct RustySecretsBuffer {
+    /// The content of the buffer
+    pub data: *mut u8,
+    /// The length of the buffer, in bytes
+    pub len: usize,
+}
+
+/// Shares allocated by the library, to be freed with `rusty_secrets_shares_free`.
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct RustySecretsShares {
+    /// The shares, as NUL-terminated strings
+    pub shares: *mut *mut c_char,
+    /// The number of shares
+    pub count: usize,
+}
+
+/// Information about a share, as returned by the `inspect` functions.
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct RustySecretsShareInfo {
+    /// The identifier of the share
+    pub id: u8,
+    /// The number of shares necessary to recover the secret
+    pub threshold: u8,
+    /// The total number of shares that have been dealt, or 0 if the scheme does not record it
+    pub shares_count: u8,
+    /// Whether the share is signed
+    pub is_signed: bool,
+    /// The length of the share data, in bytes
+    pub data_len: usize,
+    /// Whether the share records the share set it belongs to
+    pub has_share_set_id: bool,
+    /// The identifier of the share set, if `has_share_set_id` is set, and zeroes otherwise
+    pub share_set_id: [u8; SHARE_SET_ID_LEN],
+}
+
+/// Errors reported by the bindings.
+enum FfiError {
+    Lib(Error),
+    NullPointer(&'static str),
+    InvalidUtf8(&'static str),
+}
+
+impl From<Error> for FfiError {
+    fn from(e: Error) -> Self {
+        FfiError::Lib(e)
+    }
+}
+
+impl From<ErrorKind> for FfiError {
+    fn from(kind: ErrorKind) -> Self {
+        FfiError::Lib(kind.into())
+    }
+}
+
+type FfiResult<T> = ::std::result::Result<T, FfiError>;
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = RefCell::new(None);
+}
+
+fn set_last_error(message: String) {
+    // Error messages never contain NUL bytes, but better safe than sorry.
+    let message = CString::new(message.replace('\0', "")).ok();
+    LAST_ERROR.with(|last| *last.borrow_mut() = message);
+}
+
+/// Runs `f`, records the error it returns, if any, and converts it to an error code.
+fn call<F: FnOnce() -> FfiResult<()>>(f: F) -> i32 {
+    let (code, message) = match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(())) => (RUSTY_SECRETS_OK, None),
+        Ok(Err(FfiError::Lib(e))) => {
+            let mut message = e.to_string();
+            for cause in e.iter().skip(1) {
+                message.push_str(&format!(" Caused by: {}", cause));
+            }
+            (i32::from(e.kind().code()), Some(message))
+        }
+        Ok(Err(FfiError::NullPointer(name))) => (
+            ErrorCode::NullPointer as i32,
+            Some(format!("`{}` must not be NULL.", name)),
+        ),
+        Ok(Err(FfiError::InvalidUtf8(name))) => (
+            ErrorCode::InvalidUtf8 as i32,
+            Some(format!("`{}` must be a valid UTF-8 string.", name)),
+        ),
+        Err(_) => (
+            ErrorCode::Panic as i32,
+            Some("RustySecrets panicked.".to_string()),
+        ),
+    };
+
+    match message {
+        Some(message) => set_last_error(message),
+        None => LAST_ERROR.with(|last| *last.borrow_mut() = None),
+    }
+
+    code
+}
+
+unsafe fn bytes_arg<'a>(data: *const u8, len: usize, name: &'static str) -> FfiResult<&'a [u8]> {
+    if data.is_null() {
+        if len == 0 {
+            return Ok(&[]);
+        }
+        return Err(FfiError::NullPointer(name));
+    }
+    Ok(slice::from_raw_parts(data, len))
+}
+
+unsafe fn str_arg<'a>(s: *const c_char, name: &'static str) -> FfiResult<&'a str> {
+    if s.is_null() {
+        return Err(FfiError::NullPointer(name));
+    }
+    CStr::from_ptr(s)
+        .to_str()
+        .map_err(|_| FfiError::InvalidUtf8(name))
+}
+
+unsafe fn shares_arg(shares: *const *const c_char, count: usize) -> FfiResult<Vec<String>> {
+    if shares.is_null() {
+        if count == 0 {
+            return Ok(Vec::new());
+        }
+        return Err(FfiError::NullPointer("shares"));
+    }
+    slice::from_raw_parts(shares, count)
+        .iter()
+        .map(|&share| str_arg(share, "shares").map(str::to_string))
+        .collect()
+}
+
+unsafe fn out_arg<'a, T>(out: *mut T, name: &'static str) -> FfiResult<&'a mut T> {
+    if out.is_null() {
+        return Err(FfiError::NullPointer(name));
+    }
+    Ok(&mut *out)
+}
+
+fn new_buffer(data: Vec<u8>) -> RustySecretsBuffer {
+    let len = data.len();
+    let data = Box::into_raw(data.into_boxed_slice());
+    RustySecretsBuffer {
+        data: data as *mut u8,
+        len,
+    }
+}
+
+fn new_string(s: String) -> FfiResult<*mut c_char> {
+    CString::new(s)
+        .map(CString::into_raw)
+        .map_err(|_| ErrorKind::ShareParsingError("Unexpected NUL byte.".to_string()).into())
+}
+
+fn new_shares(shares: Vec<String>) -> FfiResult<RustySecretsShares> {
+    let shares = shares
+        .into_iter()
+        .map(new_string)
+        .collect::<FfiResult<Vec<_>>>()?;
+    let count = shares.len();
+    let shares = Box::into_raw(shares.into_boxed_slice());
+    Ok(RustySecretsShares {
+        shares: shares as *mut *mut c_char,
+        count,
+    })
+}
+
+fn sss_inspect(share: &str) -> Result<RustySecretsShareInfo> {
+    let info = inspect_share(share)?;
+    if !info.schemes.contains(&SchemeKind::SSS) {
+        return Err(ErrorKind::ShareParsingError(format!(
+            "Expected a share of the SSS scheme, got a share of the {} scheme.",
+            info.scheme()
+        )).into());
+    }
+    Ok(share_info(
+        info.id,
+        info.threshold,
+        0,
+        info.signed,
+        info.data_len,
+        info.share_set_id.as_ref().map(|id| id.as_bytes()),
+    ))
+}
+
+fn share_info(
+    id: u8,
+    threshold: u8,
+    shares_count: u8,
+    is_signed: bool,
+    data_len: usize,
+    share_set_id: Option<&[u8]>,
+) -> RustySecretsShareInfo {
+    let mut info = RustySecretsShareInfo {
+        id,
+        threshold,
+        shares_count,
+        is_signed,
+        data_len,
+        has_share_set_id: share_set_id.is_some(),
+        share_set_id: [0; SHARE_SET_ID_LEN],
+    };
+    if let Some(share_set_id) = share_set_id {
+        info.share_set_id.copy_from_slice(share_set_id);
+    }
+    info
+}
+
+/// Splits `secret` into `n` shares, `k` of which are necessary to recover it,
+/// with the `sss` scheme, optionally signing them.
+///
+/// # Safety
+///
+/// `secret` must point to `secret_len` readable bytes, and `shares` to a writable
+/// `RustySecretsShares`, which must be freed with `rusty_secrets_shares_free` on success.
+#[no_mangle]
+pub unsafe extern "C" fn rusty_secrets_sss_split(
+    k: u8,
+    n: u8,
+    secret: *const u8,
+    secret_len: usize,
+    sign_shares: bool,
+    shares: *mut RustySecretsShares,
+) -> i32 {
+    call(|| {
+        let secret = bytes_arg(secret, secret_len, "secret")?;
+        let out = out_arg(shares, "shares")?;
+        *out = new_shares(sss::split_secret(k, n, secret, sign_shares)?)?;
+        Ok(())
+    })
+}
+
+/// Recovers the secret from the given `sss` shares, optionally verifying their signatures.
+///
+/// # Safety
+///
+/// `shares` must point to `shares_count` NUL-terminated strings, and `secret` to a writable
+/// `RustySecretsBuffer`, which must be freed with `rusty_secrets_buffer_free` on success.
+#[no_mangle]
+pub unsafe extern "C" fn rusty_secrets_sss_recover(
+    shares: *const *const c_char,
+    shares_count: usize,
+    verify_signatures: bool,
+    secret: *mut RustySecretsBuffer,
+) -> i32 {
+    call(|| {
+        let shares = shares_arg(shares, shares_count)?;
+        let out = out_arg(secret, "secret")?;
+        *out = new_buffer(sss::recover_secret(&shares, verify_signatures)?);
+        Ok(())
+    })
+}
+
+/// Fills `info` with information about the given `sss` share.
+///
+/// # Safety
+///
+/// `share` must be a NUL-terminated string, and `info` must point to a writable
+/// `RustySecretsShareInfo`.
+#[no_mangle]
+pub unsafe extern "C" fn rusty_secrets_sss_inspect(
+    share: *const c_char,
+    info: *mut RustySecretsShareInfo,
+) -> i32 {
+    call(|| {
+        let share = str_arg(share, "share")?;
+        let out = out_arg(info, "info")?;
+        *out = sss_inspect(share)?;
+        Ok(())
+    })
+}
+
+/// Splits `secret` into `n` shares, `k` of which are necessary to recover it,
+/// with the `wrapped_secrets` scheme, optionally signing them.
+///
+/// # Safety
+///
+/// `secret` must point to `secret_len` readable bytes, `mime_type` must be either `NULL`
+/// or a NUL-terminated string, and `shares` must point to a writable `RustySecretsShares`,
+/// which must be freed with `rusty_secrets_shares_free` on success.
+#[no_mangle]
+pub unsafe extern "C" fn rusty_secrets_wrapped_split(
+    k: u8,
+    n: u8,
+    secret: *const u8,
+    secret_len: usize,
+    mime_type: *const c_char,
+    sign_shares: bool,
+    shares: *mut RustySecretsShares,
+) -> i32 {
+    call(|| {
+        let secret = bytes_arg(secret, secret_len, "secret")?;
+        let mime_type = if mime_type.is_null() {
+            None
+        } else {
+            Some(str_arg(mime_type, "mime_type")?.to_string())
+        };
+        let out = out_arg(shares, "shares")?;
+        *out = new_shares(wrapped_secrets::split_secret(
+            k,
+            n,
+            secret,
+            mime_type,
+            sign_shares,
+        )?)?;
+        Ok(())
+    })
+}
+
+/// Recovers the secret from the given `wrapped_secrets` shares, optionally verifying
+/// their signatures.
+///
+/// If `mime_type` is not `NULL`, it is set to the MIME type of the secret, which must be
+/// freed with `rusty_secrets_string_free`, or to `NULL` if the secret has none.
+///
+/// # Safety
+///
+/// `shares` must point to `shares_count` NUL-terminated strings, and `secret` to a writable
+/// `RustySecretsBuffer`, which must be freed with `rusty_secrets_buffer_free` on success.
+#[no_mangle]
+pub unsafe extern "C" fn rusty_secrets_wrapped_recover(
+    shares: *const *const c_char,
+    shares_count: usize,
+    verify_signatures: bool,
+    secret: *mut RustySecretsBuffer,
+    mime_type: *mut *mut c_char,
+) -> i32 {
+    call(|| {
+        let shares = shares_arg(shares, shares_count)?;
+        let out = out_arg(secret, "secret")?;
+        let mut recovered = wrapped_secrets::recover_secret(&shares, verify_signatures)?;
+
+        if !mime_type.is_null() {
+            *mime_type = if recovered.get_mime_type().is_empty() {
+                ptr::null_mut()
+            } else {
+                new_string(recovered.take_mime_type())?
+            };
+        }
+        *out = new_buffer(recovered.take_secret());
+        Ok(())
+    })
+}
+
+/// Fills `info` with information about the given `wrapped_secrets` share.
+///
+/// # Safety
+///
+/// `share` must be a NUL-terminated string, and `info` must point to a writable
+/// `RustySecretsShareInfo`.
+#[no_mangle]
+pub unsafe extern "C" fn rusty_secrets_wrapped_inspect(
+    share: *const c_char,
+    info: *mut RustySecretsShareInfo,
+) -> i32 {
+    // Wrapped secrets are shared with the `sss` scheme, and their shares thus share its format.
+    rusty_secrets_sss_inspect(share, info)
+}
+
+/// Splits `secret` into `n` shares, `k` of which are necessary to recover it,
+/// with the `dss::ss1` scheme.
+///
+/// If `reproducible` is set, splitting the same secret with the same parameters
+/// always yields the same shares, and if `sign_shares` is set, every share is signed.
+/// The signatures of reproducible shares still differ between two splits.
+///
+/// # Safety
+///
+/// `secret` must point to `secret_len` readable bytes, and `shares` to a writable
+/// `RustySecretsShares`, which must be freed with `rusty_secrets_shares_free` on success.
+#[no_mangle]
+pub unsafe extern "C" fn rusty_secrets_ss1_split(
+    k: u8,
+    n: u8,
+    secret: *const u8,
+    secret_len: usize,
+    reproducible: bool,
+    sign_shares: bool,
+    shares: *mut RustySecretsShares,
+) -> i32 {
+    call(|| {
+        let secret = bytes_arg(secret, secret_len, "secret")?;
+        let out = out_arg(shares, "shares")?;
+        let reproducibility = if reproducible {
+            ss1::Reproducibility::reproducible()
+        } else {
+            ss1::Reproducibility::none()
+        };
+        let split = ss1::SS1::builder()
+            .sign_shares(sign_shares)
+            .build()?
+            .split_secret(k, n, secret, reproducibility, &None)?
+            .into_iter()
+            .map(ss1::Share::into_string)
+            .collect();
+        *out = new_shares(split)?;
+        Ok(())
+    })
+}
+
+/// Recovers the secret from the given `dss::ss1` shares.
+/// If `verify_signatures` is set, all the shares must be signed by the same dealer.
+///
+/// # Safety
+///
+/// `shares` must point to `shares_count` NUL-terminated strings, and `secret` to a writable
+/// `RustySecretsBuffer`, which must be freed with `rusty_secrets_buffer_free` on success.
+#[no_mangle]
+pub unsafe extern "C" fn rusty_secrets_ss1_recover(
+    shares: *const *const c_char,
+    shares_count: usize,
+    verify_signatures: bool,
+    secret: *mut RustySecretsBuffer,
+) -> i32 {
+    call(|| {
+        let shares = shares_arg(shares, shares_count)?
+            .iter()
+            .map(|share| ss1::Share::from_string(share))
+            .collect::<Result<Vec<_>>>()?;
+        let out = out_arg(secret, "secret")?;
+        let (recovered, _, _) = ss1::SS1::builder()
+            .verify_signatures(verify_signatures)
+            .build()?
+            .recover_secret(&shares)?;
+        *out = new_buffer(recovered);
+        Ok(())
+    })
+}
+
+/// Fills `info` with information about the given `dss::ss1` share.
+///
+/// # Safety
+///
+/// `share` must be a NUL-terminated string, and `info` must point to a writable
+/// `RustySecretsShareInfo`.
+#[no_mangle]
+pub unsafe extern "C" fn rusty_secrets_ss1_inspect(
+    share: *const c_char,
+    info: *mut RustySecretsShareInfo,
+) -> i32 {
+    call(|| {
+        let share = ss1::Share::from_string(str_arg(share, "share")?)?;
+        let out = out_arg(info, "info")?;
+        *out = share_info(
+            share.id,
+            share.threshold,
+            share.shares_count,
+            share.is_signed(),
+            share.data.len(),
+            share.share_set_id.as_ref().map(|id| id.as_bytes()),
+        );
+        Ok(())
+    })
+}
+
+/// Zeroes and frees a buffer returned by the library, and resets it.
+/// Does nothing if `buffer` or its data is `NULL`.
+///
+/// # Safety
+///
+/// `buffer` must have been filled by the library, and not freed already.
+#[no_mangle]
+pub unsafe extern "C" fn rusty_secrets_buffer_free(buffer: *mut RustySecretsBuffer) {
+    if buffer.is_null() || (*buffer).data.is_null() {
+        return;
+    }
+
+    let data: *mut [u8] = slice::from_raw_parts_mut((*buffer).data, (*buffer).len);
+    let mut data = Box::from_raw(data);
+    for byte in data.iter_mut() {
+        ptr::write_volatile(byte, 0);
+    }
+    drop(data);
+
+    (*buffer).data = ptr::null_mut();
+    (*buffer).len = 0;
+}
+
+/// Frees shares returned by the library, and resets them.
+/// Does nothing if `shares` or its content is `NULL`.
+///
+/// # Safety
+///
+/// `shares` must have been filled by the library, and not freed already.
+#[no_mangle]
+pub unsafe extern "C" fn rusty_secrets_shares_free(shares: *mut RustySecretsShares) {
+    if shares.is_null() || (*shares).shares.is_null() {
+        return;
+    }
+
+    let strings: *mut [*mut c_char] = slice::from_raw_parts_mut((*shares).shares, (*shares).count);
+    for &string in Box::from_raw(strings).iter() {
+        rusty_secrets_string_free(string);
+    }
+
+    (*shares).shares = ptr::null_mut();
+    (*shares).count = 0;
+}
+
+/// Frees a string returned by the library. Does nothing if `string` is `NULL`.
+///
+/// # Safety
+///
+/// `string` must have been returned by the library, and not freed already.
+#[no_mangle]
+pub unsafe extern "C" fn rusty_secrets_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        drop(CString::from_raw(string));
+    }
+}
+
+/// Returns a description of the last error which occurred on the calling thread, or `NULL`
+/// if the last call succeeded. The description must be freed with `rusty_secrets_string_free`.
+#[no_mangle]
+pub extern "C" fn rusty_secrets_last_error() -> *mut c_char {
+    LAST_ERROR.with(|last| {
+        last.borrow()
+            .as_ref()
+            .map_or(ptr::null_mut(), |message| message.clone().into_raw())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::ffi::{CStr, CString};
+    use std::ptr;
+
+    use super::*;
+
+    fn split(k: u8, n: u8, secret: &[u8]) -> Vec<CString> {
+        let mut shares = RustySecretsShares {
+            shares: ptr::null_mut(),
+            count: 0,
+        };
+        let code = unsafe {
+            rusty_secrets_sss_split(k, n, secret.as_ptr(), secret.len(), true, &mut shares)
+        };
+        assert_eq!(code, RUSTY_SECRETS_OK);
+
+        let result = unsafe {
+            slice::from_raw_parts(shares.shares, shares.count)
+                .iter()
+                .map(|&share| CStr::from_ptr(share).to_owned())
+                .collect()
+        };
+        unsafe { rusty_secrets_shares_free(&mut shares) };
+        assert!(shares.shares.is_null());
+        result
+    }
+
+    fn recover(shares: &[CString]) -> (i32, Vec<u8>) {
+        let pointers = shares.iter().map(|s| s.as_ptr()).collect::<Vec<_>>();
+        let mut secret = RustySecretsBuffer {
+            data: ptr::null_mut(),
+            len: 0,
+        };
+        let code = unsafe {
+            rusty_secrets_sss_recover(pointers.as_ptr(), pointers.len(), true, &mut secret)
+        };
+        if code != RUSTY_SECRETS_OK {
+            return (code, Vec::new());
+        }
+        let result = unsafe { slice::from_raw_parts(secret.data, secret.len).to_vec() };
+        unsafe { rusty_secrets_buffer_free(&mut secret) };
+        (code, result)
+    }
+
+    fn last_error() -> Option<String> {
+        let message = rusty_secrets_last_error();
+        if message.is_null() {
+            return None;
+        }
+        let result = unsafe { CStr::from_ptr(message).to_string_lossy().into_owned() };
+        unsafe { rusty_secrets_string_free(message) };
+        Some(result)
+    }
+
+    #[test]
+    fn sss_roundtrip() {
+        let shares = split(3, 5, b"Hello, World!");
+        assert_eq!(shares.len(), 5);
+        assert_eq!(recover(&shares[1..4]), (RUSTY_SECRETS_OK, b"Hello, World!".to_vec()));
+        assert_eq!(last_error(), None);
+
+        let mut info = share_info(0, 0, 0, false, 0, None);
+        let code = unsafe { rusty_secrets_sss_inspect(shares[0].as_ptr(), &mut info) };
+        assert_eq!(code, RUSTY_SECRETS_OK);
+        assert_eq!((info.id, info.threshold, info.data_len), (1, 3, 13));
+        assert!(info.is_signed && info.has_share_set_id);
+    }
+
+    #[test]
+    fn sss_errors() {
+        let shares = split(3, 5, b"Hello, World!");
+        assert_eq!(recover(&shares[..2]).0, ErrorCode::MissingShares as i32);
+        assert!(last_error().unwrap().contains("3 shares are required"));
+
+        let null = unsafe {
+            rusty_secrets_sss_recover(ptr::null(), 1, false, ptr::null_mut())
+        };
+        assert_eq!(null, ErrorCode::NullPointer as i32);
+
+        let invalid = CStr::from_bytes_with_nul(b"\xff\0").unwrap();
+        let pointers = [invalid.as_ptr()];
+        let mut secret = RustySecretsBuffer {
+            data: ptr::null_mut(),
+            len: 0,
+        };
+        let utf8 = unsafe { rusty_secrets_sss_recover(pointers.as_ptr(), 1, false, &mut secret) };
+        assert_eq!(utf8, ErrorCode::InvalidUtf8 as i32);
+        assert!(secret.data.is_null());
+    }
+}
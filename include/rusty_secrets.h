@@ -0,0 +1,453 @@
+#ifndef RUSTY_SECRETS_H
+#define RUSTY_SECRETS_H
+
+/* This file is generated by cbindgen from the rusty-secrets-ffi crate, do not edit it by hand. Run `make header` instead. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * The call succeeded.
+ */
+#define RUSTY_SECRETS_OK 0
+
+/**
+ * The stable numeric codes identifying the kinds of errors, as returned by `ErrorKind::code`.
+ *
+ * This is the single table of error codes, from which the error codes of the C bindings
+ * are generated. Generation errors are numbered from 10, parsing errors from 20, recovery
+ * errors from 30, signature errors from 40, I/O errors from 50, errors specific to the
+ * C bindings from 60, and errors of the sealing and passphrase protection of shares from 70.
+ * Kinds of errors added once the range of their kind was full take the next free code
+ * instead, such as the recovery error `CorruptedShares` (42), and codes from 77 onwards
+ * are assigned in the order the kinds of errors were added, whatever their kind.
+ *
+ * A code is never reassigned to a different kind of error, and `0` is never used.
+ */
+enum RustySecretsError {
+  /**
+   * An error without a more specific code
+   */
+  RUSTY_SECRETS_ERROR_OTHER = 1,
+  /**
+   * See `ErrorKind::ThresholdTooBig`
+   */
+  RUSTY_SECRETS_ERROR_THRESHOLD_TOO_BIG = 10,
+  /**
+   * See `ErrorKind::ThresholdTooSmall`
+   */
+  RUSTY_SECRETS_ERROR_THRESHOLD_TOO_SMALL = 11,
+  /**
+   * See `ErrorKind::SecretTooBig`
+   */
+  RUSTY_SECRETS_ERROR_SECRET_TOO_BIG = 12,
+  /**
+   * See `ErrorKind::InvalidShareCountMax`
+   */
+  RUSTY_SECRETS_ERROR_INVALID_SHARE_COUNT_MAX = 13,
+  /**
+   * See `ErrorKind::InvalidShareCountMin`
+   */
+  RUSTY_SECRETS_ERROR_INVALID_SHARE_COUNT_MIN = 14,
+  /**
+   * See `ErrorKind::EmptySecret`
+   */
+  RUSTY_SECRETS_ERROR_EMPTY_SECRET = 15,
+  /**
+   * See `ErrorKind::InvalidSS1Parameters`
+   */
+  RUSTY_SECRETS_ERROR_INVALID_SS1_PARAMETERS = 16,
+  /**
+   * See `ErrorKind::InvalidSplitParametersZero`
+   */
+  RUSTY_SECRETS_ERROR_INVALID_SPLIT_PARAMETERS_ZERO = 17,
+  /**
+   * See `ErrorKind::CannotGenerateRandomNumbers`
+   */
+  RUSTY_SECRETS_ERROR_CANNOT_GENERATE_RANDOM_NUMBERS = 18,
+  /**
+   * See `ErrorKind::UnsupportedHashAlgorithm`
+   */
+  RUSTY_SECRETS_ERROR_UNSUPPORTED_HASH_ALGORITHM = 19,
+  /**
+   * See `ErrorKind::ShareParsingError`
+   */
+  RUSTY_SECRETS_ERROR_SHARE_PARSING = 20,
+  /**
+   * See `ErrorKind::ShareParsingErrorEmptyShare`
+   */
+  RUSTY_SECRETS_ERROR_EMPTY_SHARE = 21,
+  /**
+   * See `ErrorKind::ShareParsingInvalidShareId`
+   */
+  RUSTY_SECRETS_ERROR_INVALID_SHARE_ID = 22,
+  /**
+   * See `ErrorKind::ShareParsingInvalidShareThreshold`
+   */
+  RUSTY_SECRETS_ERROR_INVALID_SHARE_THRESHOLD = 23,
+  /**
+   * See `ErrorKind::IntegerParsingError`
+   */
+  RUSTY_SECRETS_ERROR_INTEGER_PARSING = 24,
+  /**
+   * See `ErrorKind::SecretDeserializationError`
+   */
+  RUSTY_SECRETS_ERROR_SECRET_DESERIALIZATION = 25,
+  /**
+   * See `ErrorKind::InvalidArmor`
+   */
+  RUSTY_SECRETS_ERROR_INVALID_ARMOR = 26,
+  /**
+   * See `ErrorKind::ArmorChecksumMismatch`
+   */
+  RUSTY_SECRETS_ERROR_ARMOR_CHECKSUM_MISMATCH = 27,
+  /**
+   * See `ErrorKind::EmptyShares`
+   */
+  RUSTY_SECRETS_ERROR_EMPTY_SHARES = 30,
+  /**
+   * See `ErrorKind::MissingShares`
+   */
+  RUSTY_SECRETS_ERROR_MISSING_SHARES = 31,
+  /**
+   * See `ErrorKind::DuplicateShareId`
+   */
+  RUSTY_SECRETS_ERROR_DUPLICATE_SHARE_ID = 32,
+  /**
+   * See `ErrorKind::InconsistentThresholds`
+   */
+  RUSTY_SECRETS_ERROR_INCONSISTENT_THRESHOLDS = 33,
+  /**
+   * See `ErrorKind::InconsistentSecretLengths`
+   */
+  RUSTY_SECRETS_ERROR_INCONSISTENT_SECRET_LENGTHS = 34,
+  /**
+   * See `ErrorKind::InconsistentShares`
+   */
+  RUSTY_SECRETS_ERROR_INCONSISTENT_SHARES = 35,
+  /**
+   * See `ErrorKind::InconsistentShareSets`
+   */
+  RUSTY_SECRETS_ERROR_INCONSISTENT_SHARE_SETS = 36,
+  /**
+   * See `ErrorKind::IncompatibleSets`
+   */
+  RUSTY_SECRETS_ERROR_INCOMPATIBLE_SETS = 37,
+  /**
+   * See `ErrorKind::MismatchingShares`
+   */
+  RUSTY_SECRETS_ERROR_MISMATCHING_SHARES = 38,
+  /**
+   * See `ErrorKind::InconsistentSS1Parameters`
+   */
+  RUSTY_SECRETS_ERROR_INCONSISTENT_SS1_PARAMETERS = 39,
+  /**
+   * See `ErrorKind::InvalidSignature`
+   */
+  RUSTY_SECRETS_ERROR_INVALID_SIGNATURE = 40,
+  /**
+   * See `ErrorKind::MissingSignature`
+   */
+  RUSTY_SECRETS_ERROR_MISSING_SIGNATURE = 41,
+  /**
+   * See `ErrorKind::CorruptedShares`
+   */
+  RUSTY_SECRETS_ERROR_CORRUPTED_SHARES = 42,
+  /**
+   * See `ErrorKind::Io`
+   */
+  RUSTY_SECRETS_ERROR_IO = 50,
+  /**
+   * A required pointer argument was `NULL`, only reported by the C bindings
+   */
+  RUSTY_SECRETS_ERROR_NULL_POINTER = 60,
+  /**
+   * A string argument was not valid UTF-8, only reported by the C bindings
+   */
+  RUSTY_SECRETS_ERROR_INVALID_UTF8 = 61,
+  /**
+   * The library panicked, which is a bug, only reported by the C bindings
+   */
+  RUSTY_SECRETS_ERROR_PANIC = 62,
+  /**
+   * See `ErrorKind::InvalidKey`
+   */
+  RUSTY_SECRETS_ERROR_INVALID_KEY = 70,
+  /**
+   * See `ErrorKind::WrongRecipient`
+   */
+  RUSTY_SECRETS_ERROR_WRONG_RECIPIENT = 71,
+  /**
+   * See `ErrorKind::CannotOpenShare`
+   */
+  RUSTY_SECRETS_ERROR_CANNOT_OPEN_SHARE = 72,
+  /**
+   * See `ErrorKind::WrongPassphrase`
+   */
+  RUSTY_SECRETS_ERROR_WRONG_PASSPHRASE = 73,
+  /**
+   * See `ErrorKind::MissingPassphrase`
+   */
+  RUSTY_SECRETS_ERROR_MISSING_PASSPHRASE = 74,
+  /**
+   * See `ErrorKind::WrongRequest`
+   */
+  RUSTY_SECRETS_ERROR_WRONG_REQUEST = 75,
+  /**
+   * See `ErrorKind::ReplayedResponse`
+   */
+  RUSTY_SECRETS_ERROR_REPLAYED_RESPONSE = 76,
+  /**
+   * See `ErrorKind::DuplicateContribution`
+   */
+  RUSTY_SECRETS_ERROR_DUPLICATE_CONTRIBUTION = 77,
+  /**
+   * See `ErrorKind::InvalidXorThreshold`
+   */
+  RUSTY_SECRETS_ERROR_INVALID_XOR_THRESHOLD = 78,
+  /**
+   * See `ErrorKind::UnknownScheme`
+   */
+  RUSTY_SECRETS_ERROR_UNKNOWN_SCHEME = 79,
+};
+typedef uint8_t RustySecretsError;
+
+/**
+ * A buffer allocated by the library, to be freed with `rusty_secrets_buffer_free`.
+ */
+typedef struct {
+  /**
+   * The content of the buffer
+   */
+  uint8_t *data;
+  /**
+   * The length of the buffer, in bytes
+   */
+  size_t len;
+} RustySecretsBuffer;
+
+/**
+ * Shares allocated by the library, to be freed with `rusty_secrets_shares_free`.
+ */
+typedef struct {
+  /**
+   * The shares, as NUL-terminated strings
+   */
+  char **shares;
+  /**
+   * The number of shares
+   */
+  size_t count;
+} RustySecretsShares;
+
+/**
+ * Information about a share, as returned by the `inspect` functions.
+ */
+typedef struct {
+  /**
+   * The identifier of the share
+   */
+  uint8_t id;
+  /**
+   * The number of shares necessary to recover the secret
+   */
+  uint8_t threshold;
+  /**
+   * The total number of shares that have been dealt, or 0 if the scheme does not record it
+   */
+  uint8_t shares_count;
+  /**
+   * Whether the share is signed
+   */
+  bool is_signed;
+  /**
+   * The length of the share data, in bytes
+   */
+  size_t data_len;
+  /**
+   * Whether the share records the share set it belongs to
+   */
+  bool has_share_set_id;
+  /**
+   * The identifier of the share set, if `has_share_set_id` is set, and zeroes otherwise
+   */
+  uint8_t share_set_id[16];
+} RustySecretsShareInfo;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Zeroes and frees a buffer returned by the library, and resets it.
+ * Does nothing if `buffer` or its data is `NULL`.
+ *
+ * # Safety
+ *
+ * `buffer` must have been filled by the library, and not freed already.
+ */
+void rusty_secrets_buffer_free(RustySecretsBuffer *buffer);
+
+/**
+ * Returns a description of the last error which occurred on the calling thread, or `NULL`
+ * if the last call succeeded. The description must be freed with `rusty_secrets_string_free`.
+ */
+char *rusty_secrets_last_error(void);
+
+/**
+ * Frees shares returned by the library, and resets them.
+ * Does nothing if `shares` or its content is `NULL`.
+ *
+ * # Safety
+ *
+ * `shares` must have been filled by the library, and not freed already.
+ */
+void rusty_secrets_shares_free(RustySecretsShares *shares);
+
+/**
+ * Fills `info` with information about the given `dss::ss1` share.
+ *
+ * # Safety
+ *
+ * `share` must be a NUL-terminated string, and `info` must point to a writable
+ * `RustySecretsShareInfo`.
+ */
+int32_t rusty_secrets_ss1_inspect(const char *share, RustySecretsShareInfo *info);
+
+/**
+ * Recovers the secret from the given `dss::ss1` shares.
+ * If `verify_signatures` is set, all the shares must be signed by the same dealer.
+ *
+ * # Safety
+ *
+ * `shares` must point to `shares_count` NUL-terminated strings, and `secret` to a writable
+ * `RustySecretsBuffer`, which must be freed with `rusty_secrets_buffer_free` on success.
+ */
+int32_t rusty_secrets_ss1_recover(const char *const *shares,
+                                  size_t shares_count,
+                                  bool verify_signatures,
+                                  RustySecretsBuffer *secret);
+
+/**
+ * Splits `secret` into `n` shares, `k` of which are necessary to recover it,
+ * with the `dss::ss1` scheme.
+ *
+ * If `reproducible` is set, splitting the same secret with the same parameters
+ * always yields the same shares, and if `sign_shares` is set, every share is signed.
+ * The signatures of reproducible shares still differ between two splits.
+ *
+ * # Safety
+ *
+ * `secret` must point to `secret_len` readable bytes, and `shares` to a writable
+ * `RustySecretsShares`, which must be freed with `rusty_secrets_shares_free` on success.
+ */
+int32_t rusty_secrets_ss1_split(uint8_t k,
+                                uint8_t n,
+                                const uint8_t *secret,
+                                size_t secret_len,
+                                bool reproducible,
+                                bool sign_shares,
+                                RustySecretsShares *shares);
+
+/**
+ * Fills `info` with information about the given `sss` share.
+ *
+ * # Safety
+ *
+ * `share` must be a NUL-terminated string, and `info` must point to a writable
+ * `RustySecretsShareInfo`.
+ */
+int32_t rusty_secrets_sss_inspect(const char *share, RustySecretsShareInfo *info);
+
+/**
+ * Recovers the secret from the given `sss` shares, optionally verifying their signatures.
+ *
+ * # Safety
+ *
+ * `shares` must point to `shares_count` NUL-terminated strings, and `secret` to a writable
+ * `RustySecretsBuffer`, which must be freed with `rusty_secrets_buffer_free` on success.
+ */
+int32_t rusty_secrets_sss_recover(const char *const *shares,
+                                  size_t shares_count,
+                                  bool verify_signatures,
+                                  RustySecretsBuffer *secret);
+
+/**
+ * Splits `secret` into `n` shares, `k` of which are necessary to recover it,
+ * with the `sss` scheme, optionally signing them.
+ *
+ * # Safety
+ *
+ * `secret` must point to `secret_len` readable bytes, and `shares` to a writable
+ * `RustySecretsShares`, which must be freed with `rusty_secrets_shares_free` on success.
+ */
+int32_t rusty_secrets_sss_split(uint8_t k,
+                                uint8_t n,
+                                const uint8_t *secret,
+                                size_t secret_len,
+                                bool sign_shares,
+                                RustySecretsShares *shares);
+
+/**
+ * Frees a string returned by the library. Does nothing if `string` is `NULL`.
+ *
+ * # Safety
+ *
+ * `string` must have been returned by the library, and not freed already.
+ */
+void rusty_secrets_string_free(char *string);
+
+/**
+ * Fills `info` with information about the given `wrapped_secrets` share.
+ *
+ * # Safety
+ *
+ * `share` must be a NUL-terminated string, and `info` must point to a writable
+ * `RustySecretsShareInfo`.
+ */
+int32_t rusty_secrets_wrapped_inspect(const char *share, RustySecretsShareInfo *info);
+
+/**
+ * Recovers the secret from the given `wrapped_secrets` shares, optionally verifying
+ * their signatures.
+ *
+ * If `mime_type` is not `NULL`, it is set to the MIME type of the secret, which must be
+ * freed with `rusty_secrets_string_free`, or to `NULL` if the secret has none.
+ *
+ * # Safety
+ *
+ * `shares` must point to `shares_count` NUL-terminated strings, and `secret` to a writable
+ * `RustySecretsBuffer`, which must be freed with `rusty_secrets_buffer_free` on success.
+ */
+int32_t rusty_secrets_wrapped_recover(const char *const *shares,
+                                      size_t shares_count,
+                                      bool verify_signatures,
+                                      RustySecretsBuffer *secret,
+                                      char **mime_type);
+
+/**
+ * Splits `secret` into `n` shares, `k` of which are necessary to recover it,
+ * with the `wrapped_secrets` scheme, optionally signing them.
+ *
+ * # Safety
+ *
+ * `secret` must point to `secret_len` readable bytes, `mime_type` must be either `NULL`
+ * or a NUL-terminated string, and `shares` must point to a writable `RustySecretsShares`,
+ * which must be freed with `rusty_secrets_shares_free` on success.
+ */
+int32_t rusty_secrets_wrapped_split(uint8_t k,
+                                    uint8_t n,
+                                    const uint8_t *secret,
+                                    size_t secret_len,
+                                    const char *mime_type,
+                                    bool sign_shares,
+                                    RustySecretsShares *shares);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif /* RUSTY_SECRETS_H */
@@ -0,0 +1,30 @@
+"""Python bindings to RustySecrets, a secret sharing library written in Rust.
+
+The package mirrors the modules of the Rust crate: ``rusty_secrets.sss``,
+``rusty_secrets.wrapped_secrets``, ``rusty_secrets.dss.thss`` and
+``rusty_secrets.dss.ss1``.
+
+Errors are raised as subclasses of ``RustySecretsError`` named after the
+variant of ``rusty_secrets::errors::ErrorKind`` they correspond to, such as
+``MissingShares`` or ``InvalidSignature``. Every exception exposes the error
+``message``, the ``kind`` name, the fields of the kind as a ``details``
+dictionary, named as in the Rust crate, and the numeric ``code`` of the kind,
+as returned by ``ErrorKind::code`` and declared by the C bindings.
+"""
+
+from . import _rusty_secrets
+from ._rusty_secrets import RustySecretsError
+
+# Re-export the exception of every error kind, as declared by the native module.
+for _name in dir(_rusty_secrets):
+    _value = getattr(_rusty_secrets, _name)
+    if isinstance(_value, type) and issubclass(_value, RustySecretsError):
+        globals()[_name] = _value
+del _name, _value
+
+# The native exceptions are raised with `(message, kind, details, code)` as arguments.
+RustySecretsError.message = property(lambda self: self.args[0])
+RustySecretsError.kind = property(lambda self: self.args[1])
+RustySecretsError.details = property(lambda self: self.args[2])
+RustySecretsError.code = property(lambda self: self.args[3])
+RustySecretsError.__str__ = lambda self: self.args[0]
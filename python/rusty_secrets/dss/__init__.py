@@ -0,0 +1 @@
+"""Deterministic threshold secret sharing schemes."""
@@ -0,0 +1,29 @@
+"""The SS1 deterministic threshold secret sharing scheme."""
+
+from .._rusty_secrets import Ss1Share as Share
+from .._rusty_secrets import ss1_recover_secret, ss1_split_secret
+
+
+def split_secret(k, n, secret, reproducible=False, seed=None, metadata=None,
+                 sign_shares=False):
+    """Splits the `secret` bytes into `n` shares, `k` of which are necessary
+    to recover it, attaching the `metadata` dictionary to every share.
+
+    If `reproducible` is set, or a `seed` is given, splitting the same secret
+    with the same parameters always yields the same shares. If `sign_shares`
+    is set, every share is signed by the dealer, although the signatures of
+    reproducible shares still differ."""
+    return ss1_split_secret(k, n, secret, reproducible, seed, metadata, sign_shares)
+
+
+def recover_secret(shares, verify_signatures=False):
+    """Recovers the secret from the given shares, which may be `Share`
+    objects or strings. Returns the secret bytes, the access structure
+    `(k, n)`, and the metadata of the shares.
+    If `verify_signatures` is set, all the shares must be signed by the
+    same dealer."""
+    return ss1_recover_secret([_parse(share) for share in shares], verify_signatures)
+
+
+def _parse(share):
+    return Share.from_string(share) if isinstance(share, str) else share
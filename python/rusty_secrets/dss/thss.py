@@ -0,0 +1,24 @@
+"""The ThSS threshold secret sharing scheme."""
+
+from .._rusty_secrets import ThssShare as Share
+from .._rusty_secrets import thss_recover_secret, thss_split_secret
+
+
+def split_secret(k, n, secret, metadata=None, sign_shares=False):
+    """Splits the `secret` bytes into `n` shares, `k` of which are necessary
+    to recover it, attaching the `metadata` dictionary to every share.
+    If `sign_shares` is set, every share is signed by the dealer."""
+    return thss_split_secret(k, n, secret, metadata, sign_shares)
+
+
+def recover_secret(shares, verify_signatures=False):
+    """Recovers the secret from the given shares, which may be `Share`
+    objects or strings. Returns the secret bytes, the access structure
+    `(k, n)`, and the metadata of the shares.
+    If `verify_signatures` is set, all the shares must be signed by the
+    same dealer."""
+    return thss_recover_secret([_parse(share) for share in shares], verify_signatures)
+
+
+def _parse(share):
+    return Share.from_string(share) if isinstance(share, str) else share
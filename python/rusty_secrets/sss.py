@@ -0,0 +1,14 @@
+"""Shamir's secret sharing with raw data."""
+
+from ._rusty_secrets import sss_recover_secret, sss_split_secret
+
+
+def split_secret(k, n, secret, sign_shares=False):
+    """Splits the `secret` bytes into `n` shares, `k` of which are necessary
+    to recover it. Returns the shares as strings."""
+    return sss_split_secret(k, n, secret, sign_shares)
+
+
+def recover_secret(shares, verify_signatures=False):
+    """Recovers the secret bytes from the given shares."""
+    return sss_recover_secret(list(shares), verify_signatures)
@@ -0,0 +1,15 @@
+"""Shamir's secret sharing with a secret wrapped along with its MIME type."""
+
+from ._rusty_secrets import wrapped_recover_secret, wrapped_split_secret
+
+
+def split_secret(k, n, secret, mime_type=None, sign_shares=False):
+    """Splits the `secret` bytes, along with their MIME type, into `n` shares,
+    `k` of which are necessary to recover them. Returns the shares as strings."""
+    return wrapped_split_secret(k, n, secret, mime_type, sign_shares)
+
+
+def recover_secret(shares, verify_signatures=False):
+    """Recovers the secret from the given shares.
+    Returns the secret bytes and their MIME type, or `None` if there is none."""
+    return wrapped_recover_secret(list(shares), verify_signatures)
@@ -0,0 +1,17 @@
+from setuptools import setup
+from setuptools_rust import Binding, RustExtension
+
+setup(
+    name="rusty-secrets",
+    version="0.1.0",
+    description="Python bindings to RustySecrets",
+    url="https://github.com/freedomofpress/RustySecrets",
+    license="BSD-3-Clause",
+    packages=["rusty_secrets", "rusty_secrets.dss"],
+    rust_extensions=[
+        RustExtension("rusty_secrets._rusty_secrets", binding=Binding.PyO3)
+    ],
+    setup_requires=["setuptools-rust>=0.10"],
+    tests_require=["pytest"],
+    zip_safe=False,
+)
@@ -0,0 +1,611 @@
+//! Python bindings to `rusty_secrets`.
+//!
+//! This crate builds the `rusty_secrets._rusty_secrets` extension module, which is wrapped
+//! by the pure Python `rusty_secrets` package, mirroring the modules of the Rust crate.
+
+#![deny(trivial_casts, trivial_numeric_casts, unused_import_braces)]
+
+#[macro_use]
+extern crate pyo3;
+extern crate rusty_secrets;
+
+use std::collections::{BTreeMap, HashMap};
+
+use pyo3::class::basic::PyObjectProtocol;
+use pyo3::exceptions;
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyDict};
+
+use rusty_secrets::dss::{ss1, thss, AccessStructure, MetaData};
+use rusty_secrets::errors::{Error, ErrorKind};
+use rusty_secrets::{sss, wrapped_secrets};
+
+create_exception!(_rusty_secrets, RustySecretsError, exceptions::Exception);
+
+/// Declares an exception deriving from `RustySecretsError` for each of the given error kinds,
+/// and a function registering them in a module.
+macro_rules! error_kinds {
+    ($($kind:ident),*) => {
+        $(
+            create_exception!(_rusty_secrets, $kind, RustySecretsError);
+        )*
+
+        fn add_exceptions(py: Python, m: &PyModule) -> PyResult<()> {
+            m.add("RustySecretsError", py.get_type::<RustySecretsError>())?;
+            $(
+                m.add(stringify!($kind), py.get_type::<$kind>())?;
+            )*
+            Ok(())
+        }
+    }
+}
+
+error_kinds!(
+    ThresholdTooBig,
+    ThresholdTooSmall,
+    SecretTooBig,
+    InvalidShareCountMax,
+    InvalidShareCountMin,
+    EmptySecret,
+    EmptyShares,
+    IncompatibleSets,
+    MissingShares,
+    InvalidSignature,
+    MissingSignature,
+    SecretDeserializationError,
+    ShareParsingError,
+    ShareParsingErrorEmptyShare,
+    ShareParsingInvalidShareId,
+    ShareParsingInvalidShareThreshold,
+    InvalidSS1Parameters,
+    InvalidSplitParametersZero,
+    MismatchingShares,
+    CannotGenerateRandomNumbers,
+    UnsupportedHashAlgorithm,
+    DuplicateShareId,
+    InconsistentSecretLengths,
+    InconsistentShares,
+    InconsistentThresholds,
+    InconsistentShareSets,
+    InconsistentSS1Parameters,
+    CorruptedShares,
+    InvalidArmor,
+    ArmorChecksumMismatch,
+    InvalidKey,
+    WrongRecipient,
+    CannotOpenShare,
+    WrongPassphrase,
+    MissingPassphrase,
+    WrongRequest,
+    ReplayedResponse,
+    DuplicateContribution,
+    InvalidXorThreshold,
+    UnknownScheme,
+    Io,
+    IntegerParsingError
+);
+
+/// Converts an error into an instance of the exception named after its kind.
+///
+/// The arguments of the exception are the error message, the name of the kind,
+/// a dictionary holding the fields of the kind, named as in `ErrorKind`, and the
+/// code of the kind, as returned by `ErrorKind::code`.
+fn to_py_err(py: Python, e: &Error) -> PyErr {
+    let mut message = e.to_string();
+    for cause in e.iter().skip(1) {
+        message.push_str(&format!(" Caused by: {}", cause));
+    }
+
+    let code = e.kind().code();
+    let details = PyDict::new(py);
+
+    macro_rules! raise {
+        ($kind:ident $(, $field:ident => $value:expr)*) => {{
+            $(
+                details
+                    .set_item(stringify!($field), $value)
+                    .expect("Could not build the details of the error");
+            )*
+            $kind::py_err((message, stringify!($kind), details.to_object(py), code))
+        }}
+    }
+
+    match *e.kind() {
+        ErrorKind::ThresholdTooBig(k, n) => raise!(ThresholdTooBig, k => k, n => n),
+        ErrorKind::ThresholdTooSmall(k) => raise!(ThresholdTooSmall, k => k),
+        ErrorKind::SecretTooBig(len, max) => raise!(SecretTooBig, len => len, max => max),
+        ErrorKind::InvalidShareCountMax(nb_shares, max) => {
+            raise!(InvalidShareCountMax, nb_shares => nb_shares, max => max)
+        }
+        ErrorKind::InvalidShareCountMin(nb_shares, min) => {
+            raise!(InvalidShareCountMin, nb_shares => nb_shares, min => min)
+        }
+        ErrorKind::EmptySecret => raise!(EmptySecret),
+        ErrorKind::EmptyShares => raise!(EmptyShares),
+        ErrorKind::IncompatibleSets(ref sets) => {
+            let sets = sets.iter()
+                .map(|set| {
+                    let mut ids = set.iter().cloned().collect::<Vec<_>>();
+                    ids.sort();
+                    ids
+                })
+                .collect::<Vec<_>>();
+            raise!(IncompatibleSets, sets => sets)
+        }
+        ErrorKind::MissingShares(provided, required) => {
+            raise!(MissingShares, provided => provided, required => required)
+        }
+        ErrorKind::InvalidSignature(share_id, ref signature) => raise!(
+            InvalidSignature,
+            share_id => share_id,
+            signature => signature
+        ),
+        ErrorKind::MissingSignature(share_id) => raise!(MissingSignature, share_id => share_id),
+        ErrorKind::SecretDeserializationError => raise!(SecretDeserializationError),
+        ErrorKind::ShareParsingError(ref reason) => raise!(ShareParsingError, reason => reason),
+        ErrorKind::ShareParsingErrorEmptyShare(share_id) => {
+            raise!(ShareParsingErrorEmptyShare, share_id => share_id)
+        }
+        ErrorKind::ShareParsingInvalidShareId(share_id) => {
+            raise!(ShareParsingInvalidShareId, share_id => share_id)
+        }
+        ErrorKind::ShareParsingInvalidShareThreshold(k, id) => {
+            raise!(ShareParsingInvalidShareThreshold, k => k, id => id)
+        }
+        ErrorKind::InvalidSS1Parameters(r, s) => raise!(InvalidSS1Parameters, r => r, s => s),
+        ErrorKind::InvalidSplitParametersZero(k, n) => {
+            raise!(InvalidSplitParametersZero, k => k, n => n)
+        }
+        ErrorKind::MismatchingShares(ref got, ref expected) => raise!(
+            MismatchingShares,
+            got => got.clone().into_string(),
+            expected => expected.clone().into_string()
+        ),
+        ErrorKind::CannotGenerateRandomNumbers => raise!(CannotGenerateRandomNumbers),
+        ErrorKind::UnsupportedHashAlgorithm(algorithm, ref reason) => raise!(
+            UnsupportedHashAlgorithm,
+            algorithm => algorithm.to_string(),
+            reason => reason
+        ),
+        ErrorKind::DuplicateShareId(share_id) => raise!(DuplicateShareId, share_id => share_id),
+        ErrorKind::InconsistentSecretLengths(id, slen_, ref ids, slen) => raise!(
+            InconsistentSecretLengths,
+            id => id,
+            slen_ => slen_,
+            ids => ids.clone(),
+            slen => slen
+        ),
+        ErrorKind::InconsistentShares => raise!(InconsistentShares),
+        ErrorKind::InconsistentThresholds(id, k_, ref ids, k) => raise!(
+            InconsistentThresholds,
+            id => id,
+            k_ => k_,
+            ids => ids.clone(),
+            k => k
+        ),
+        ErrorKind::InconsistentShareSets(id, set_, ref ids, set) => raise!(
+            InconsistentShareSets,
+            id => id,
+            set_ => set_.to_string(),
+            ids => ids.clone(),
+            set => set.to_string()
+        ),
+        ErrorKind::CorruptedShares(ref culprits) => {
+            raise!(CorruptedShares, culprits => culprits.clone())
+        }
+        ErrorKind::InconsistentSS1Parameters(id, parameters_, ref ids, parameters) => raise!(
+            InconsistentSS1Parameters,
+            id => id,
+            parameters_ => parameters_.to_string(),
+            ids => ids.clone(),
+            parameters => parameters.to_string()
+        ),
+        ErrorKind::InvalidArmor(ref reason) => raise!(InvalidArmor, reason => reason),
+        ErrorKind::ArmorChecksumMismatch(expected, found) => raise!(
+            ArmorChecksumMismatch,
+            expected => expected,
+            found => found
+        ),
+        ErrorKind::InvalidKey(ref reason) => raise!(InvalidKey, reason => reason),
+        ErrorKind::WrongRecipient(recipient, key) => raise!(
+            WrongRecipient,
+            recipient => recipient.to_string(),
+            key => key.to_string()
+        ),
+        ErrorKind::CannotOpenShare => raise!(CannotOpenShare),
+        ErrorKind::WrongPassphrase => raise!(WrongPassphrase),
+        ErrorKind::MissingPassphrase => raise!(MissingPassphrase),
+        ErrorKind::WrongRequest(expected, found) => raise!(
+            WrongRequest,
+            expected => expected.to_string(),
+            found => found.to_string()
+        ),
+        ErrorKind::ReplayedResponse => raise!(ReplayedResponse),
+        ErrorKind::DuplicateContribution(set) => {
+            raise!(DuplicateContribution, set => set.to_string())
+        }
+        ErrorKind::InvalidXorThreshold(k, n) => raise!(InvalidXorThreshold, k => k, n => n),
+        ErrorKind::UnknownScheme(ref name) => raise!(UnknownScheme, name => name),
+        ErrorKind::Io(_) => raise!(Io),
+        ErrorKind::IntegerParsingError(_) => raise!(IntegerParsingError),
+        _ => RustySecretsError::py_err((message, "Msg", details.to_object(py))),
+    }
+}
+
+/// Converts the error of the given result, if any, into an exception.
+fn py_result<T>(py: Python, result: Result<T, Error>) -> PyResult<T> {
+    result.map_err(|e| to_py_err(py, &e))
+}
+
+fn to_metadata(tags: Option<HashMap<String, String>>) -> Option<MetaData> {
+    tags.map(|tags| MetaData::with_tags(tags.into_iter().collect::<BTreeMap<_, _>>()))
+}
+
+fn from_metadata(metadata: &Option<MetaData>) -> Option<HashMap<String, String>> {
+    metadata.as_ref().map(|metadata| {
+        metadata
+            .tags
+            .iter()
+            .map(|(tag, value)| (tag.clone(), value.clone()))
+            .collect()
+    })
+}
+
+fn from_access_structure(access_structure: &AccessStructure) -> (u8, u8) {
+    (access_structure.threshold, access_structure.shares_count)
+}
+
+/// Splits `secret` into `n` shares, `k` of which are necessary to recover it,
+/// with Shamir's secret sharing, optionally signing the shares.
+#[pyfunction(sign_shares = false)]
+fn sss_split_secret(
+    py: Python,
+    k: u8,
+    n: u8,
+    secret: &PyBytes,
+    sign_shares: bool,
+) -> PyResult<Vec<String>> {
+    py_result(py, sss::split_secret(k, n, secret.as_bytes(), sign_shares))
+}
+
+/// Recovers the secret from the given shares, optionally verifying their signatures.
+#[pyfunction(verify_signatures = false)]
+fn sss_recover_secret(
+    py: Python,
+    shares: Vec<String>,
+    verify_signatures: bool,
+) -> PyResult<PyObject> {
+    let secret = py_result(py, sss::recover_secret(&shares, verify_signatures))?;
+    Ok(PyBytes::new(py, &secret).to_object(py))
+}
+
+/// Splits `secret`, wrapped along with its MIME type, into `n` shares,
+/// `k` of which are necessary to recover it, optionally signing the shares.
+#[pyfunction(mime_type = "None", sign_shares = false)]
+fn wrapped_split_secret(
+    py: Python,
+    k: u8,
+    n: u8,
+    secret: &PyBytes,
+    mime_type: Option<String>,
+    sign_shares: bool,
+) -> PyResult<Vec<String>> {
+    py_result(
+        py,
+        wrapped_secrets::split_secret(k, n, secret.as_bytes(), mime_type, sign_shares),
+    )
+}
+
+/// Recovers the secret, along with its MIME type, if any, from the given shares,
+/// optionally verifying their signatures.
+#[pyfunction(verify_signatures = false)]
+fn wrapped_recover_secret(
+    py: Python,
+    shares: Vec<String>,
+    verify_signatures: bool,
+) -> PyResult<(PyObject, Option<String>)> {
+    let mut secret = py_result(
+        py,
+        wrapped_secrets::recover_secret(&shares, verify_signatures),
+    )?;
+    let mime_type = if secret.get_mime_type().is_empty() {
+        None
+    } else {
+        Some(secret.take_mime_type())
+    };
+    Ok((PyBytes::new(py, &secret.take_secret()).to_object(py), mime_type))
+}
+
+/// A share of the `dss::thss` scheme.
+#[pyclass]
+#[derive(Debug)]
+struct ThssShare {
+    share: thss::Share,
+}
+
+#[pymethods]
+impl ThssShare {
+    /// Parses a share, as formatted by `str`.
+    #[staticmethod]
+    fn from_string(py: Python, raw: &str) -> PyResult<Py<ThssShare>> {
+        let share = py_result(py, thss::Share::from_string(raw))?;
+        Py::new(py, ThssShare { share })
+    }
+
+    /// The identifier of the share, between 1 and `shares_count`
+    #[getter]
+    fn id(&self) -> PyResult<u8> {
+        Ok(self.share.id)
+    }
+
+    /// The number of shares necessary to recover the secret
+    #[getter]
+    fn threshold(&self) -> PyResult<u8> {
+        Ok(self.share.threshold)
+    }
+
+    /// The total number of shares that have been dealt
+    #[getter]
+    fn shares_count(&self) -> PyResult<u8> {
+        Ok(self.share.shares_count)
+    }
+
+    /// The share data itself
+    #[getter]
+    fn data(&self, py: Python) -> PyResult<PyObject> {
+        Ok(PyBytes::new(py, &self.share.data).to_object(py))
+    }
+
+    /// The public metadata of the share, as a dictionary, if any
+    #[getter]
+    fn metadata(&self) -> PyResult<Option<HashMap<String, String>>> {
+        Ok(from_metadata(&self.share.metadata))
+    }
+
+    /// The identifier of the share set, as an hexadecimal string, if any
+    #[getter]
+    fn share_set_id(&self) -> PyResult<Option<String>> {
+        Ok(self.share.share_set_id.map(|id| id.to_string()))
+    }
+
+    /// Whether the share is signed by the dealer
+    #[getter]
+    fn is_signed(&self) -> PyResult<bool> {
+        Ok(self.share.is_signed())
+    }
+}
+
+#[pyproto]
+impl<'p> PyObjectProtocol<'p> for ThssShare {
+    fn __str__(&self) -> PyResult<String> {
+        Ok(self.share.clone().into_string())
+    }
+
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(format!("ThssShare('{}')", self.share.clone().into_string()))
+    }
+}
+
+/// A share of the `dss::ss1` scheme.
+#[pyclass]
+#[derive(Debug)]
+struct Ss1Share {
+    share: ss1::Share,
+}
+
+#[pymethods]
+impl Ss1Share {
+    /// Parses a share, as formatted by `str`.
+    #[staticmethod]
+    fn from_string(py: Python, raw: &str) -> PyResult<Py<Ss1Share>> {
+        let share = py_result(py, ss1::Share::from_string(raw))?;
+        Py::new(py, Ss1Share { share })
+    }
+
+    /// The identifier of the share, between 1 and `shares_count`
+    #[getter]
+    fn id(&self) -> PyResult<u8> {
+        Ok(self.share.id)
+    }
+
+    /// The number of shares necessary to recover the secret
+    #[getter]
+    fn threshold(&self) -> PyResult<u8> {
+        Ok(self.share.threshold)
+    }
+
+    /// The total number of shares that have been dealt
+    #[getter]
+    fn shares_count(&self) -> PyResult<u8> {
+        Ok(self.share.shares_count)
+    }
+
+    /// The share data itself
+    #[getter]
+    fn data(&self, py: Python) -> PyResult<PyObject> {
+        Ok(PyBytes::new(py, &self.share.data).to_object(py))
+    }
+
+    /// The hash of the secret
+    #[getter]
+    fn hash(&self, py: Python) -> PyResult<PyObject> {
+        Ok(PyBytes::new(py, &self.share.hash).to_object(py))
+    }
+
+    /// The public metadata of the share, as a dictionary, if any
+    #[getter]
+    fn metadata(&self) -> PyResult<Option<HashMap<String, String>>> {
+        Ok(from_metadata(&self.share.metadata))
+    }
+
+    /// The identifier of the share set, as an hexadecimal string, if any
+    #[getter]
+    fn share_set_id(&self) -> PyResult<Option<String>> {
+        Ok(self.share.share_set_id.map(|id| id.to_string()))
+    }
+
+    /// Whether the share is signed by the dealer
+    #[getter]
+    fn is_signed(&self) -> PyResult<bool> {
+        Ok(self.share.is_signed())
+    }
+}
+
+#[pyproto]
+impl<'p> PyObjectProtocol<'p> for Ss1Share {
+    fn __str__(&self) -> PyResult<String> {
+        Ok(self.share.clone().into_string())
+    }
+
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(format!("Ss1Share('{}')", self.share.clone().into_string()))
+    }
+}
+
+/// Splits `secret` into `n` shares, `k` of which are necessary to recover it,
+/// with the `ThSS` scheme, attaching the given public metadata to every share.
+/// If `sign_shares` is set, every share is signed by the dealer.
+#[pyfunction(metadata = "None", sign_shares = false)]
+fn thss_split_secret(
+    py: Python,
+    k: u8,
+    n: u8,
+    secret: &PyBytes,
+    metadata: Option<HashMap<String, String>>,
+    sign_shares: bool,
+) -> PyResult<Vec<Py<ThssShare>>> {
+    let shares = py_result(
+        py,
+        thss::ThSS::builder()
+            .sign_shares(sign_shares)
+            .build()
+            .split_secret(k, n, secret.as_bytes(), &to_metadata(metadata)),
+    )?;
+    shares
+        .into_iter()
+        .map(|share| Py::new(py, ThssShare { share }))
+        .collect()
+}
+
+/// Recovers the secret from the given shares.
+/// Returns the secret, the access structure `(k, n)`, and the metadata of the shares.
+/// If `verify_signatures` is set, all the shares must be signed by the same dealer.
+#[pyfunction(verify_signatures = false)]
+fn thss_recover_secret(
+    py: Python,
+    shares: Vec<&ThssShare>,
+    verify_signatures: bool,
+) -> PyResult<(PyObject, (u8, u8), Option<HashMap<String, String>>)> {
+    let shares = shares
+        .iter()
+        .map(|share| share.share.clone())
+        .collect::<Vec<_>>();
+    let (secret, access_structure, metadata) =
+        py_result(
+            py,
+            thss::ThSS::builder()
+                .verify_signatures(verify_signatures)
+                .build()
+                .recover_secret(&shares),
+        )?;
+    Ok((
+        PyBytes::new(py, &secret).to_object(py),
+        from_access_structure(&access_structure),
+        from_metadata(&metadata),
+    ))
+}
+
+/// Splits `secret` into `n` shares, `k` of which are necessary to recover it,
+/// with the `SS1` scheme, attaching the given public metadata to every share.
+///
+/// If `reproducible` is set, or a `seed` is given, splitting the same secret with the
+/// same parameters always yields the same shares. If `sign_shares` is set, every share
+/// is signed by the dealer, although the signatures of reproducible shares still differ.
+#[pyfunction(reproducible = false, seed = "None", metadata = "None", sign_shares = false)]
+fn ss1_split_secret(
+    py: Python,
+    k: u8,
+    n: u8,
+    secret: &PyBytes,
+    reproducible: bool,
+    seed: Option<&PyBytes>,
+    metadata: Option<HashMap<String, String>>,
+    sign_shares: bool,
+) -> PyResult<Vec<Py<Ss1Share>>> {
+    let reproducibility = match seed {
+        Some(seed) if seed.as_bytes().is_empty() => {
+            return Err(exceptions::ValueError::py_err("The seed cannot be empty"))
+        }
+        Some(seed) => ss1::Reproducibility::seeded(seed.as_bytes().to_vec()),
+        None if reproducible => ss1::Reproducibility::reproducible(),
+        None => ss1::Reproducibility::none(),
+    };
+
+    let shares = py_result(
+        py,
+        ss1::SS1::builder()
+            .sign_shares(sign_shares)
+            .build()
+            .and_then(|scheme| {
+                scheme.split_secret(
+                    k,
+                    n,
+                    secret.as_bytes(),
+                    reproducibility,
+                    &to_metadata(metadata),
+                )
+            }),
+    )?;
+    shares
+        .into_iter()
+        .map(|share| Py::new(py, Ss1Share { share }))
+        .collect()
+}
+
+/// Recovers the secret from the given shares.
+/// Returns the secret, the access structure `(k, n)`, and the metadata of the shares.
+/// If `verify_signatures` is set, all the shares must be signed by the same dealer.
+#[pyfunction(verify_signatures = false)]
+fn ss1_recover_secret(
+    py: Python,
+    shares: Vec<&Ss1Share>,
+    verify_signatures: bool,
+) -> PyResult<(PyObject, (u8, u8), Option<HashMap<String, String>>)> {
+    let shares = shares
+        .iter()
+        .map(|share| share.share.clone())
+        .collect::<Vec<_>>();
+    let (secret, access_structure, metadata) =
+        py_result(
+            py,
+            ss1::SS1::builder()
+                .verify_signatures(verify_signatures)
+                .build()
+                .and_then(|scheme| scheme.recover_secret(&shares)),
+        )?;
+    Ok((
+        PyBytes::new(py, &secret).to_object(py),
+        from_access_structure(&access_structure),
+        from_metadata(&metadata),
+    ))
+}
+
+/// The native part of the `rusty_secrets` package.
+#[pymodule]
+fn _rusty_secrets(py: Python, m: &PyModule) -> PyResult<()> {
+    add_exceptions(py, m)?;
+
+    m.add_class::<ThssShare>()?;
+    m.add_class::<Ss1Share>()?;
+
+    m.add_wrapped(wrap_pyfunction!(sss_split_secret))?;
+    m.add_wrapped(wrap_pyfunction!(sss_recover_secret))?;
+    m.add_wrapped(wrap_pyfunction!(wrapped_split_secret))?;
+    m.add_wrapped(wrap_pyfunction!(wrapped_recover_secret))?;
+    m.add_wrapped(wrap_pyfunction!(thss_split_secret))?;
+    m.add_wrapped(wrap_pyfunction!(thss_recover_secret))?;
+    m.add_wrapped(wrap_pyfunction!(ss1_split_secret))?;
+    m.add_wrapped(wrap_pyfunction!(ss1_recover_secret))?;
+
+    Ok(())
+}
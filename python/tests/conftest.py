@@ -0,0 +1,29 @@
+import os
+import subprocess
+
+import pytest
+
+ROOT = os.path.join(os.path.dirname(os.path.abspath(__file__)), "..", "..")
+
+
+@pytest.fixture(scope="session")
+def cli():
+    """Runs the `rusty-secrets` command line tool, built from the Rust crate,
+    which produces and consumes shares with the Rust API."""
+    path = os.environ.get(
+        "RUSTY_SECRETS_CLI",
+        os.path.join(ROOT, "target", "debug", "rusty-secrets"),
+    )
+    if not os.path.exists(path):
+        pytest.skip("build the command line tool with `cargo build --features dss`")
+
+    def run(*args, input=b""):
+        result = subprocess.run(
+            [path] + list(args),
+            input=input,
+            stdout=subprocess.PIPE,
+            stderr=subprocess.PIPE,
+        )
+        return result.returncode, result.stdout
+
+    return run
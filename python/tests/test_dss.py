@@ -0,0 +1,88 @@
+import pytest
+
+import rusty_secrets
+from rusty_secrets.dss import ss1, thss
+
+SECRET = b"These programs were never about terrorism."
+METADATA = {"mime_type": "text/plain", "owner": "ops"}
+
+
+def test_thss_roundtrip():
+    shares = thss.split_secret(3, 5, SECRET, metadata=METADATA)
+    assert len(shares) == 5
+
+    share = shares[4]
+    assert (share.id, share.threshold, share.shares_count) == (5, 3, 5)
+    assert share.metadata == METADATA
+    assert len(share.share_set_id) == 32
+    assert thss.Share.from_string(str(share)).data == share.data
+
+    assert thss.recover_secret(shares[2:]) == (SECRET, (3, 5), METADATA)
+    assert thss.recover_secret([str(s) for s in shares[:3]]) == (SECRET, (3, 5), METADATA)
+
+
+def test_ss1_roundtrip():
+    shares = ss1.split_secret(3, 5, SECRET)
+    assert shares[0].metadata is None
+    assert ss1.recover_secret(shares[1:4]) == (SECRET, (3, 5), None)
+
+
+def test_ss1_reproducible():
+    first = ss1.split_secret(2, 3, SECRET, reproducible=True, metadata=METADATA)
+    second = ss1.split_secret(2, 3, SECRET, reproducible=True, metadata=METADATA)
+    assert [str(s) for s in first] == [str(s) for s in second]
+
+    seeded = ss1.split_secret(2, 3, SECRET, seed=b"seed")
+    assert [str(s) for s in seeded] != [str(s) for s in first]
+    assert ss1.recover_secret(seeded[:2])[0] == SECRET
+
+    with pytest.raises(ValueError):
+        ss1.split_secret(2, 3, SECRET, seed=b"")
+
+
+def test_errors():
+    shares = thss.split_secret(3, 5, SECRET)
+    with pytest.raises(rusty_secrets.MissingShares) as error:
+        thss.recover_secret(shares[:2])
+    assert error.value.kind == "MissingShares"
+
+    with pytest.raises(rusty_secrets.ShareParsingError) as error:
+        ss1.Share.from_string("garbage")
+    assert "reason" in error.value.details
+
+    with pytest.raises(rusty_secrets.ThresholdTooBig):
+        ss1.split_secret(6, 5, SECRET)
+
+
+def test_thss_with_rust(cli):
+    code, output = cli("split", "-s", "thss", "-k", "2", "-n", "3", input=SECRET)
+    assert code == 0
+    assert thss.recover_secret(output.decode().split()[1:])[0] == SECRET
+
+    shares = thss.split_secret(2, 3, SECRET)
+    code, output = cli("recover", "-s", "thss", input="\n".join(map(str, shares[:2])).encode())
+    assert code == 0
+    assert output == SECRET
+
+
+def test_ss1_with_rust(cli):
+    code, output = cli("split", "-s", "ss1", "-k", "2", "-n", "3", "--reproducible", input=SECRET)
+    assert code == 0
+    assert output.decode().split() == [str(s) for s in ss1.split_secret(2, 3, SECRET, reproducible=True)]
+
+    shares = ss1.split_secret(2, 3, SECRET)
+    code, output = cli("recover", "-s", "ss1", input="\n".join(map(str, shares[1:])).encode())
+    assert code == 0
+    assert output == SECRET
+
+
+def test_signed_shares():
+    shares = thss.split_secret(2, 3, SECRET, sign_shares=True)
+    assert all(share.is_signed for share in shares)
+    assert thss.recover_secret(shares[1:], verify_signatures=True)[0] == SECRET
+
+    shares = ss1.split_secret(2, 3, SECRET, sign_shares=True)
+    assert ss1.recover_secret([str(s) for s in shares[:2]], verify_signatures=True)[0] == SECRET
+
+    with pytest.raises(rusty_secrets.MissingSignature):
+        ss1.recover_secret(ss1.split_secret(2, 3, SECRET)[:2], verify_signatures=True)
@@ -0,0 +1,108 @@
+import base64
+
+import pytest
+
+import rusty_secrets
+from rusty_secrets import sss
+
+SECRET = b"These programs were never about terrorism."
+
+
+def wrap_from_sellibitze(share):
+    """Wraps a share created by the original implementation of the library
+    into a protobuf, as done by `tests/test_vectors.rs`."""
+    k, i, data = share.split("-")
+    data = base64.b64decode(data + "=" * (-len(data) % 4))
+    proto = bytes([0x0A, len(data)]) + data
+    return "{}-{}-{}".format(k, i, base64.b64encode(proto).decode().rstrip("="))
+
+
+def test_roundtrip():
+    shares = sss.split_secret(3, 5, SECRET)
+    assert len(shares) == 5
+    assert all(share.startswith("3-") for share in shares)
+    assert sss.recover_secret(shares[2:]) == SECRET
+    assert sss.recover_secret(reversed(shares)) == SECRET
+
+
+def test_signed_roundtrip():
+    shares = sss.split_secret(2, 3, SECRET, sign_shares=True)
+    assert sss.recover_secret(shares[:2], verify_signatures=True) == SECRET
+
+
+def test_recover_rust_test_vectors():
+    shares = [wrap_from_sellibitze(s) for s in ["2-1-1YAYwmOHqZ69jA", "2-4-F7rAjX3UOa53KA"]]
+    assert sss.recover_secret(shares) == b"My secret\n"
+
+
+def test_missing_shares():
+    shares = sss.split_secret(3, 5, SECRET)
+    with pytest.raises(rusty_secrets.MissingShares) as error:
+        sss.recover_secret(shares[:2])
+
+    assert isinstance(error.value, rusty_secrets.RustySecretsError)
+    assert error.value.kind == "MissingShares"
+    assert error.value.details == {"provided": 2, "required": 3}
+    assert error.value.code == 31
+    assert str(error.value) == "3 shares are required to recover the secret, found only 2."
+
+
+def test_invalid_signature():
+    shares = sss.split_secret(2, 3, SECRET, sign_shares=True)
+
+    # Tamper with the share data, which follows the protobuf field header.
+    prefix, data = shares[0].rsplit("-", 1)
+    data = data[:3] + ("B" if data[3] == "A" else "A") + data[4:]
+    tampered = "{}-{}".format(prefix, data)
+
+    with pytest.raises(rusty_secrets.InvalidSignature) as error:
+        sss.recover_secret([tampered, shares[1]], verify_signatures=True)
+    assert error.value.details["share_id"] == 1
+
+
+def test_missing_signature():
+    shares = sss.split_secret(2, 3, SECRET)
+    with pytest.raises(rusty_secrets.MissingSignature):
+        sss.recover_secret(shares, verify_signatures=True)
+
+
+def test_invalid_shares():
+    shares = sss.split_secret(2, 3, SECRET)
+    others = sss.split_secret(2, 3, SECRET)
+
+    with pytest.raises(rusty_secrets.DuplicateShareId) as error:
+        sss.recover_secret([shares[0], shares[0]])
+    assert error.value.details == {"share_id": 1}
+
+    with pytest.raises(rusty_secrets.InconsistentShareSets) as error:
+        sss.recover_secret([shares[0], others[1]])
+    assert error.value.details["id"] == 2
+
+    with pytest.raises(rusty_secrets.ShareParsingError):
+        sss.recover_secret(["2-1-not base64!", shares[1]])
+
+
+def test_invalid_parameters():
+    with pytest.raises(rusty_secrets.ThresholdTooBig) as error:
+        sss.split_secret(4, 3, SECRET)
+    assert error.value.details == {"k": 4, "n": 3}
+
+    with pytest.raises(rusty_secrets.ThresholdTooSmall):
+        sss.split_secret(1, 3, SECRET)
+
+
+def test_split_with_rust(cli):
+    code, output = cli("split", "-k", "2", "-n", "3", "--signed", input=SECRET)
+    assert code == 0
+    shares = output.decode().split()
+    assert sss.recover_secret(shares[1:], verify_signatures=True) == SECRET
+
+
+def test_recover_with_rust(cli):
+    shares = sss.split_secret(3, 4, SECRET)
+    code, output = cli("recover", input="\n".join(shares[1:]).encode())
+    assert code == 0
+    assert output == SECRET
+
+    code, _ = cli("recover", input="\n".join(shares[2:]).encode())
+    assert code == 31  # `ErrorKind::MissingShares`
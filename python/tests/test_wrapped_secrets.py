@@ -0,0 +1,40 @@
+import pytest
+
+import rusty_secrets
+from rusty_secrets import wrapped_secrets
+
+SECRET = b"These programs were never about terrorism."
+
+
+def test_roundtrip():
+    shares = wrapped_secrets.split_secret(2, 3, SECRET, mime_type="text/plain")
+    assert wrapped_secrets.recover_secret(shares[1:]) == (SECRET, "text/plain")
+
+
+def test_roundtrip_without_mime_type():
+    shares = wrapped_secrets.split_secret(2, 3, SECRET, sign_shares=True)
+    assert wrapped_secrets.recover_secret(shares, verify_signatures=True) == (SECRET, None)
+
+
+def test_missing_shares():
+    shares = wrapped_secrets.split_secret(3, 3, SECRET)
+    with pytest.raises(rusty_secrets.MissingShares) as error:
+        wrapped_secrets.recover_secret(shares[:1])
+    assert error.value.details == {"provided": 1, "required": 3}
+
+
+def test_split_with_rust(cli):
+    code, output = cli(
+        "split", "-s", "wrapped_secrets", "-k", "2", "-n", "3", "--mime-type", "text/plain",
+        input=SECRET,
+    )
+    assert code == 0
+    shares = output.decode().split()
+    assert wrapped_secrets.recover_secret(shares[:2]) == (SECRET, "text/plain")
+
+
+def test_recover_with_rust(cli):
+    shares = wrapped_secrets.split_secret(2, 3, SECRET)
+    code, output = cli("recover", "-s", "wrapped_secrets", input="\n".join(shares[1:]).encode())
+    assert code == 0
+    assert output == SECRET
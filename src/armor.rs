@@ -0,0 +1,587 @@
+//! ASCII armor for shares, suitable for printing, or pasting into an e-mail.
+//!
+//! An armored share looks like the following:
+//!
+//! ```text
+//! -----BEGIN RUSTY SECRETS SHARE-----
+//! Scheme: SSS
+//! Threshold: 2
+//! Share-Id: 1
+//! Share-Set-Id: 5f0c8d3b4e2a17f6c09b2e6ad4f1a3c8
+//! Label: Backup of the signing key
+//! Created: 2018-03-09T17:25:00Z
+//!
+//! ChYp2NuX0smTElqbSaMgeKXMp4F9o9iTXyIQXwyNO0oqF+wXlZIuBCV9FqDX
+//! =xq2E
+//! -----END RUSTY SECRETS SHARE-----
+//! ```
+//!
+//! The body holds the Base64 encoding of the share's protobuf, wrapped at 64 columns,
+//! and is followed by a CRC-24 checksum, as computed by OpenPGP.
+//! The `Scheme`, `Threshold` and `Share-Id` headers are mandatory, the other ones are
+//! informative. Since the share is rebuilt from the mandatory headers and the body, the
+//! checksum covers the whole `threshold-id-body` share string, not only the body.
+//!
+//! When parsing an armored share, e-mail quoting (`> `), indentation, trailing whitespace,
+//! and Windows line endings are ignored.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use base64;
+
+use errors::*;
+use share_set::ShareSetId;
+use sss;
+use xor;
+
+#[cfg(feature = "dss")]
+use dss::{ss1, thss};
+
+const BEGIN: &str = "-----BEGIN RUSTY SECRETS SHARE-----";
+const END: &str = "-----END RUSTY SECRETS SHARE-----";
+
+const LINE_WIDTH: usize = 64;
+
+const CRC24_INIT: u32 = 0xB7_04CE;
+const CRC24_POLY: u32 = 0x186_4CFB;
+
+/// The scheme a share was created with.
+pub use scheme::SchemeKind as Scheme;
+
+/// A share along with the headers of its armored representation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ArmoredShare {
+    /// The scheme the share was created with
+    pub scheme: Scheme,
+    /// The number of shares necessary to recover the secret, aka the threshold
+    pub threshold: u8,
+    /// The identifier of the share
+    pub share_id: u8,
+    /// The identifier of the share set this share belongs to, if any
+    pub share_set_id: Option<ShareSetId>,
+    /// A free-form, single-line label
+    pub label: Option<String>,
+    /// When the share was armored, with a precision of one second
+    pub created: Option<SystemTime>,
+    /// The share itself, in the format accepted by the scheme's `from_string` method
+    pub share: String,
+}
+
+impl ArmoredShare {
+    /// Prepares the given share, created with the given `scheme`, for armoring.
+    /// The creation time is set to the current time.
+    pub fn new(scheme: Scheme, share: &str) -> Result<Self> {
+        let share = share.trim();
+        let (threshold, share_id, share_set_id) = parse_share(scheme, share)?;
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| UNIX_EPOCH + Duration::from_secs(elapsed.as_secs()))
+            .ok();
+
+        Ok(ArmoredShare {
+            scheme,
+            threshold,
+            share_id,
+            share_set_id,
+            label: None,
+            created: now,
+            share: share.to_string(),
+        })
+    }
+
+    /// Attaches the given label to the share.
+    /// Line breaks are replaced with spaces, since headers span a single line.
+    pub fn with_label(mut self, label: &str) -> Self {
+        let label = label
+            .trim()
+            .chars()
+            .map(|c| if c == '\r' || c == '\n' { ' ' } else { c })
+            .collect();
+        self.label = Some(label);
+        self
+    }
+
+    /// Returns the armored representation of this share.
+    pub fn to_armor(&self) -> Result<String> {
+        let (_, _, payload) = sss::share_payload_from_string(&self.share)?;
+
+        let mut armor = String::new();
+        armor.push_str(BEGIN);
+        armor.push('\n');
+        armor.push_str(&format!("Scheme: {}\n", self.scheme));
+        armor.push_str(&format!("Threshold: {}\n", self.threshold));
+        armor.push_str(&format!("Share-Id: {}\n", self.share_id));
+        if let Some(share_set_id) = self.share_set_id {
+            armor.push_str(&format!("Share-Set-Id: {}\n", share_set_id));
+        }
+        if let Some(ref label) = self.label {
+            armor.push_str(&format!("Label: {}\n", label));
+        }
+        if let Some(created) = self.created {
+            armor.push_str(&format!("Created: {}\n", format_time(created)));
+        }
+        armor.push('\n');
+
+        let body = base64::encode_config(&payload, base64::STANDARD);
+        for line in body.as_bytes().chunks(LINE_WIDTH) {
+            // Base64 only produces ASCII characters.
+            armor.push_str(&String::from_utf8_lossy(line));
+            armor.push('\n');
+        }
+        let share = share_string(self.threshold, self.share_id, &payload);
+        armor.push_str(&format_checksum(crc24(share.as_bytes())));
+        armor.push('\n');
+        armor.push_str(END);
+        armor.push('\n');
+
+        Ok(armor)
+    }
+
+    /// Parses the first armored share found in the given text.
+    pub fn from_armor(text: &str) -> Result<Self> {
+        let mut lines = text.lines().map(unquote);
+        parse_armor(&mut lines)?
+            .ok_or_else(|| ErrorKind::InvalidArmor("No armored share found.".to_string()).into())
+    }
+
+    /// Parses all the armored shares found in the given text.
+    pub fn parse_all(text: &str) -> Result<Vec<Self>> {
+        let mut lines = text.lines().map(unquote);
+        let mut shares = Vec::new();
+        while let Some(share) = parse_armor(&mut lines)? {
+            shares.push(share);
+        }
+        Ok(shares)
+    }
+}
+
+/// Strips e-mail quoting and surrounding whitespace from the given line.
+fn unquote(line: &str) -> &str {
+    line.trim_left_matches(|c: char| c == '>' || c.is_whitespace())
+        .trim_right()
+}
+
+fn parse_armor<'a, I: Iterator<Item = &'a str>>(lines: &mut I) -> Result<Option<ArmoredShare>> {
+    if !lines.any(|line| line == BEGIN) {
+        return Ok(None);
+    }
+
+    let mut headers = Vec::new();
+    let mut body = String::new();
+    let mut checksum = None;
+    let mut terminated = false;
+
+    for line in lines {
+        if line == END {
+            terminated = true;
+            break;
+        } else if line.is_empty() {
+            continue;
+        } else if line.starts_with('=') && !body.is_empty() {
+            checksum = Some(parse_checksum(&line[1..])?);
+        } else if let Some(colon) = line.find(':') {
+            if !body.is_empty() {
+                bail!(ErrorKind::InvalidArmor(
+                    "Found a header after the body.".to_string()
+                ));
+            }
+            let (name, value) = line.split_at(colon);
+            headers.push((name.trim().to_lowercase(), value[1..].trim().to_string()));
+        } else {
+            body.push_str(line);
+        }
+    }
+
+    if !terminated {
+        bail!(ErrorKind::InvalidArmor(format!("Missing '{}' line.", END)));
+    }
+
+    let payload = base64::decode_config(&body, base64::STANDARD).chain_err(|| {
+        ErrorKind::InvalidArmor("Base64 decoding of the body failed.".to_string())
+    })?;
+
+    let checksum =
+        checksum.ok_or_else(|| Error::from(ErrorKind::InvalidArmor("Missing checksum.".to_string())))?;
+
+    let header = |name: &str| {
+        headers
+            .iter()
+            .find(|&&(ref header, _)| header == name)
+            .map(|&(_, ref value)| value.clone())
+    };
+    let required = |name: &str| {
+        header(name).ok_or_else(|| {
+            Error::from(ErrorKind::InvalidArmor(format!(
+                "Missing '{}' header.",
+                name
+            )))
+        })
+    };
+
+    let scheme = required("scheme")?.parse::<Scheme>()?;
+    let threshold = required("threshold")?.parse::<u8>()?;
+    let share_id = required("share-id")?.parse::<u8>()?;
+    let label = header("label");
+    let created = match header("created") {
+        Some(created) => Some(parse_time(&created)?),
+        None => None,
+    };
+
+    let share = share_string(threshold, share_id, &payload);
+    let found = crc24(share.as_bytes());
+    if checksum != found {
+        bail!(ErrorKind::ArmorChecksumMismatch(checksum, found));
+    }
+
+    let (_, _, share_set_id) = parse_share(scheme, &share)?;
+
+    if let Some(value) = header("share-set-id") {
+        if share_set_id.map(|id| id.to_string()) != Some(value.to_lowercase()) {
+            bail!(ErrorKind::InvalidArmor(format!(
+                "The 'Share-Set-Id' header ({}) does not match the share.",
+                value
+            )));
+        }
+    }
+
+    Ok(Some(ArmoredShare {
+        scheme,
+        threshold,
+        share_id,
+        share_set_id,
+        label,
+        created,
+        share,
+    }))
+}
+
+/// Parses the given share with the parser of the given scheme, and returns its
+/// threshold, identifier, and share-set identifier.
+fn parse_share(scheme: Scheme, share: &str) -> Result<(u8, u8, Option<ShareSetId>)> {
+    match scheme {
+        Scheme::SSS | Scheme::WrappedSecrets => {
+            let share = sss::Share::from_string(share, false)?;
+            Ok((share.threshold, share.id, share.share_set_id))
+        }
+        Scheme::Xor => {
+            let share = xor::Share::from_string(share, false)?;
+            Ok((share.shares_count, share.id, share.share_set_id))
+        }
+        #[cfg(feature = "dss")]
+        Scheme::ThSS => {
+            let share = thss::Share::from_string(share)?;
+            Ok((share.threshold, share.id, share.share_set_id))
+        }
+        #[cfg(feature = "dss")]
+        Scheme::SS1 => {
+            let share = ss1::Share::from_string(share)?;
+            Ok((share.threshold, share.id, share.share_set_id))
+        }
+    }
+}
+
+/// Rebuilds the share string, as accepted by the schemes' `from_string` methods, from the
+/// mandatory headers and the body of an armored share.
+fn share_string(threshold: u8, share_id: u8, payload: &[u8]) -> String {
+    format!(
+        "{}-{}-{}",
+        threshold,
+        share_id,
+        base64::encode_config(payload, base64::STANDARD_NO_PAD)
+    )
+}
+
+/// Computes the CRC-24 checksum defined in section 6.1 of RFC 4880.
+fn crc24(data: &[u8]) -> u32 {
+    let mut crc = CRC24_INIT;
+    for &byte in data {
+        crc ^= u32::from(byte) << 16;
+        for _ in 0..8 {
+            crc <<= 1;
+            if crc & 0x100_0000 != 0 {
+                crc ^= CRC24_POLY;
+            }
+        }
+    }
+    crc & 0xFF_FFFF
+}
+
+fn format_checksum(crc: u32) -> String {
+    let bytes = [(crc >> 16) as u8, (crc >> 8) as u8, crc as u8];
+    format!("={}", base64::encode_config(&bytes, base64::STANDARD))
+}
+
+fn parse_checksum(encoded: &str) -> Result<u32> {
+    let bytes = base64::decode_config(encoded, base64::STANDARD).chain_err(|| {
+        ErrorKind::InvalidArmor("Base64 decoding of the checksum failed.".to_string())
+    })?;
+    if bytes.len() != 3 {
+        bail!(ErrorKind::InvalidArmor(
+            "The checksum must be 3 bytes long.".to_string()
+        ));
+    }
+    Ok(bytes
+        .iter()
+        .fold(0, |crc, &byte| (crc << 8) | u32::from(byte)))
+}
+
+/// Formats the given time as a RFC 3339 UTC timestamp, eg. `2018-03-09T17:25:00Z`.
+fn format_time(time: SystemTime) -> String {
+    let secs = time.duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or(0);
+    let (year, month, day) = civil_from_days((secs / 86_400) as i64);
+    let secs_of_day = secs % 86_400;
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        secs_of_day / 3600,
+        secs_of_day % 3600 / 60,
+        secs_of_day % 60
+    )
+}
+
+/// Parses a RFC 3339 UTC timestamp, as produced by `format_time`.
+fn parse_time(s: &str) -> Result<SystemTime> {
+    let invalid = || Error::from(ErrorKind::InvalidArmor(format!("Invalid creation time '{}'.", s)));
+
+    let bytes = s.as_bytes();
+    let separators = [(4, b'-'), (7, b'-'), (10, b'T'), (13, b':'), (16, b':'), (19, b'Z')];
+    if bytes.len() != 20 || separators.iter().any(|&(i, c)| bytes[i] != c) {
+        return Err(invalid());
+    }
+    let field = |from: usize, to: usize| s[from..to].parse::<u32>().map_err(|_| invalid());
+
+    let (year, month, day) = (field(0, 4)?, field(5, 7)?, field(8, 10)?);
+    let (hours, minutes, seconds) = (field(11, 13)?, field(14, 16)?, field(17, 19)?);
+
+    if year < 1970 || month < 1 || month > 12 || day < 1 || day > 31 || hours > 23
+        || minutes > 59 || seconds > 59
+    {
+        return Err(invalid());
+    }
+
+    let days = days_from_civil(i64::from(year), month, day);
+    if civil_from_days(days) != (i64::from(year), month, day) {
+        // Such as the 31st of April.
+        return Err(invalid());
+    }
+
+    let secs = days as u64 * 86_400 + u64::from(hours * 3600 + minutes * 60 + seconds);
+    Ok(UNIX_EPOCH + Duration::from_secs(secs))
+}
+
+/// Converts a number of days since 1970-01-01 into a (year, month, day) date.
+/// See http://howardhinnant.github.io/date_algorithms.html
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = (if z >= 0 { z } else { z - 146_096 }) / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = (if mp < 10 { mp + 3 } else { mp - 9 }) as u32;
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+/// Converts a (year, month, day) date into a number of days since 1970-01-01.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = (if year >= 0 { year } else { year - 399 }) / 400;
+    let yoe = year - era * 400;
+    let month = i64::from(month);
+    let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(day) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::*;
+
+    fn sss_share() -> String {
+        sss::split_secret(2, 3, b"Hello, World!", false)
+            .unwrap()
+            .remove(0)
+    }
+
+    #[test]
+    fn crc24_matches_openpgp() {
+        assert_eq!(crc24(b""), 0xB7_04CE);
+        assert_eq!(crc24(b"123456789"), 0x21_CF02);
+    }
+
+    #[test]
+    fn time_roundtrip() {
+        let dates = vec![
+            (0, "1970-01-01T00:00:00Z"),
+            (951_782_400, "2000-02-29T00:00:00Z"),
+            (1_520_616_300, "2018-03-09T17:25:00Z"),
+        ];
+        for (secs, date) in dates {
+            let time = UNIX_EPOCH + Duration::from_secs(secs);
+            assert_eq!(format_time(time), date);
+            assert_eq!(parse_time(date).unwrap(), time);
+        }
+
+        assert!(parse_time("2018-04-31T00:00:00Z").is_err());
+        assert!(parse_time("2018-03-09 17:25:00").is_err());
+    }
+
+    #[test]
+    fn armor_roundtrip() {
+        let share = sss_share();
+        let armored = ArmoredShare::new(Scheme::SSS, &share)
+            .unwrap()
+            .with_label("Backup\nkey");
+        let text = armored.to_armor().unwrap();
+
+        assert!(text.starts_with(BEGIN));
+        assert!(text.contains("Label: Backup key\n"));
+        assert!(text.lines().all(|line| line.len() <= LINE_WIDTH));
+
+        let parsed = ArmoredShare::from_armor(&text).unwrap();
+        assert_eq!(parsed, armored);
+        assert_eq!(parsed.share, share);
+        assert!(parsed.share_set_id.is_some());
+    }
+
+    #[test]
+    fn armor_long_share_is_wrapped() {
+        let secret = vec![42; 1024];
+        let share = sss::split_secret(2, 3, &secret, false).unwrap().remove(1);
+        let text = ArmoredShare::new(Scheme::SSS, &share)
+            .unwrap()
+            .to_armor()
+            .unwrap();
+
+        assert!(text.lines().filter(|line| line.len() == LINE_WIDTH).count() > 10);
+        assert_eq!(ArmoredShare::from_armor(&text).unwrap().share, share);
+    }
+
+    #[test]
+    fn parse_tolerates_email_quoting() {
+        let share = sss_share();
+        let text = ArmoredShare::new(Scheme::SSS, &share)
+            .unwrap()
+            .with_label("Quoted")
+            .to_armor()
+            .unwrap();
+
+        let quoted = text.lines()
+            .map(|line| format!(">  > {}   \r\n", line))
+            .collect::<String>();
+        let email = format!("On Monday, Alice wrote:\r\n{}\r\n> Regards\r\n", quoted);
+
+        let parsed = ArmoredShare::from_armor(&email).unwrap();
+        assert_eq!(parsed.share, share);
+        assert_eq!(parsed.label, Some("Quoted".to_string()));
+    }
+
+    #[test]
+    fn parse_all_finds_every_share() {
+        let shares = sss::split_secret(2, 3, b"Hello, World!", false).unwrap();
+        let text = shares
+            .iter()
+            .map(|share| {
+                ArmoredShare::new(Scheme::SSS, share)
+                    .unwrap()
+                    .to_armor()
+                    .unwrap()
+            })
+            .collect::<Vec<_>>()
+            .join("\nSome text in between\n");
+
+        let parsed = ArmoredShare::parse_all(&text).unwrap();
+        let parsed = parsed.into_iter().map(|armored| armored.share).collect::<Vec<_>>();
+        assert_eq!(parsed, shares);
+
+        assert_eq!(sss::recover_secret(&parsed[1..], false).unwrap(), b"Hello, World!");
+    }
+
+    #[test]
+    fn parse_rejects_corrupted_body() {
+        let text = ArmoredShare::new(Scheme::SSS, &sss_share())
+            .unwrap()
+            .to_armor()
+            .unwrap();
+
+        let mut lines = text.lines().map(String::from).collect::<Vec<_>>();
+        let body_line = lines.iter().position(|line| line.is_empty()).unwrap() + 1;
+        let corrupted = if lines[body_line].starts_with('A') { "B" } else { "A" };
+        lines[body_line] = format!("{}{}", corrupted, &lines[body_line][1..]);
+
+        let err = ArmoredShare::from_armor(&lines.join("\n")).unwrap_err();
+        match *err.kind() {
+            ErrorKind::ArmorChecksumMismatch(_, _) => {}
+            ref kind => panic!("Unexpected error: {}", kind),
+        }
+    }
+
+    #[test]
+    fn parse_rejects_tampered_share_id() {
+        let armored = ArmoredShare::new(Scheme::SSS, &sss_share()).unwrap();
+        let text = armored.to_armor().unwrap();
+
+        let tampered = text.replace(
+            &format!("Share-Id: {}\n", armored.share_id),
+            &format!("Share-Id: {}\n", armored.share_id % 3 + 1),
+        );
+        assert_ne!(tampered, text);
+
+        let err = ArmoredShare::from_armor(&tampered).unwrap_err();
+        match *err.kind() {
+            ErrorKind::ArmorChecksumMismatch(_, _) => {}
+            ref kind => panic!("Unexpected error: {}", kind),
+        }
+    }
+
+    #[test]
+    fn parse_rejects_missing_headers_and_delimiters() {
+        let text = ArmoredShare::new(Scheme::SSS, &sss_share())
+            .unwrap()
+            .to_armor()
+            .unwrap();
+
+        let without_threshold = text.lines()
+            .filter(|line| !line.starts_with("Threshold"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert!(ArmoredShare::from_armor(&without_threshold).is_err());
+
+        let without_end = text.replace(END, "");
+        assert!(ArmoredShare::from_armor(&without_end).is_err());
+
+        assert!(ArmoredShare::from_armor("no share here").is_err());
+    }
+
+    #[cfg(feature = "dss")]
+    #[test]
+    fn armor_dss_shares() {
+        let thss_share = thss::split_secret(2, 3, b"Hello, World!", &None)
+            .unwrap()
+            .remove(0)
+            .into_string();
+        let ss1_share = ss1::split_secret(2, 3, b"Hello, World!", ss1::Reproducibility::none(), &None)
+            .unwrap()
+            .remove(0)
+            .into_string();
+
+        for &(scheme, ref share) in &[(Scheme::ThSS, thss_share), (Scheme::SS1, ss1_share)] {
+            let text = ArmoredShare::new(scheme, share)
+                .unwrap()
+                .to_armor()
+                .unwrap();
+            let parsed = ArmoredShare::from_armor(&text).unwrap();
+            assert_eq!(parsed.scheme, scheme);
+            assert_eq!(&parsed.share, share);
+        }
+    }
+}
@@ -0,0 +1,121 @@
+//! Parsing of the command line arguments.
+
+use rusty_secrets::armor::Scheme;
+use rusty_secrets::server::DEFAULT_TIMEOUT_SECS;
+
+/// Where the recovered secret is written to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Output {
+    Stdout,
+    File(String),
+    Fd(i32),
+}
+
+/// The parsed command line arguments.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Args {
+    pub help: bool,
+    pub socket: String,
+    pub scheme: Scheme,
+    pub signed: bool,
+    pub timeout: u64,
+    pub output: Output,
+}
+
+impl Args {
+    /// Parses the given arguments, not including the program name.
+    pub fn parse<I: Iterator<Item = String>>(mut args: I) -> Result<Args, String> {
+        let mut socket = None;
+        let mut parsed = Args {
+            help: false,
+            socket: String::new(),
+            scheme: Scheme::SSS,
+            signed: false,
+            timeout: DEFAULT_TIMEOUT_SECS,
+            output: Output::Stdout,
+        };
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--socket" => socket = Some(value(&mut args, &arg)?),
+                "-s" | "--scheme" => {
+                    let scheme = value(&mut args, &arg)?;
+                    parsed.scheme = scheme
+                        .parse()
+                        .map_err(|_| format!("unknown scheme '{}'", scheme))?;
+                }
+                "--signed" => parsed.signed = true,
+                "-t" | "--timeout" => parsed.timeout = number(&mut args, &arg)?,
+                "-o" | "--output" => parsed.output = Output::File(value(&mut args, &arg)?),
+                "--output-fd" => parsed.output = Output::Fd(number(&mut args, &arg)?),
+                "-h" | "--help" => parsed.help = true,
+                other => return Err(format!("unexpected argument '{}'", other)),
+            }
+        }
+
+        if parsed.help {
+            return Ok(parsed);
+        }
+
+        parsed.socket = socket.ok_or_else(|| "missing '--socket' option".to_string())?;
+        if parsed.timeout == 0 {
+            return Err("the timeout must be positive".to_string());
+        }
+
+        Ok(parsed)
+    }
+}
+
+fn value<I: Iterator<Item = String>>(args: &mut I, flag: &str) -> Result<String, String> {
+    args.next()
+        .ok_or_else(|| format!("missing value for '{}'", flag))
+}
+
+fn number<I, T>(args: &mut I, flag: &str) -> Result<T, String>
+where
+    I: Iterator<Item = String>,
+    T: ::std::str::FromStr,
+{
+    let value = value(args, flag)?;
+    value
+        .parse()
+        .map_err(|_| format!("invalid value '{}' for '{}'", value, flag))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Args, String> {
+        Args::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn parse_options() {
+        let args = parse(&[
+            "--socket",
+            "/run/unseal.sock",
+            "--signed",
+            "-t",
+            "60",
+            "--output-fd",
+            "3",
+        ]).unwrap();
+        assert_eq!(args.socket, "/run/unseal.sock");
+        assert_eq!(args.scheme, Scheme::SSS);
+        assert!(args.signed);
+        assert_eq!(args.timeout, 60);
+        assert_eq!(args.output, Output::Fd(3));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert!(parse(&[]).is_err());
+        assert!(parse(&["--socket"]).is_err());
+        assert!(parse(&["--socket", "unseal.sock", "--timeout", "0"]).is_err());
+        assert!(parse(&["--socket", "unseal.sock", "--output-fd", "stdout"]).is_err());
+        assert!(parse(&["--socket", "unseal.sock", "share.txt"]).is_err());
+        assert!(parse(&["--help"]).unwrap().help);
+    }
+}
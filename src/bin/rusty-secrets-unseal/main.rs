@@ -0,0 +1,107 @@
+//! `rusty-secrets-unseal` collects shares submitted over a Unix domain socket,
+//! and writes the secret out once enough of them were submitted.
+
+extern crate rusty_secrets;
+
+mod args;
+
+use std::fs::{File, OpenOptions, Permissions};
+use std::io::{self, Write};
+use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+use std::os::unix::io::FromRawFd;
+use std::process;
+use std::time::Duration;
+
+use rusty_secrets::errors::*;
+use rusty_secrets::server::{SecretSink, Server, Unsealer};
+
+use args::{Args, Output};
+
+const USAGE: &str = "\
+Usage: rusty-secrets-unseal --socket <path> [options]
+
+Listens on the Unix domain socket <path> for shares, validating each of them as it is
+submitted, and writes the secret out once enough of them were submitted.
+
+Options:
+        --socket <path>      Path of the socket to create
+    -s, --scheme <scheme>    sss (default), wrapped_secrets, xor, thss or ss1
+        --signed             Verify the signatures of the shares
+    -t, --timeout <secs>     Forget the shares if the secret is not recovered within
+                             <secs> seconds of the first share (default: 300)
+    -o, --output <file>      Write the secret to <file> instead of stdout
+        --output-fd <fd>     Write the secret to the inherited file descriptor <fd>
+    -h, --help               Print this message
+
+Protocol:
+    Clients send one command per line, and receive one response per command:
+
+    share <share>    Submit a share
+    status           Report the progress of the recovery
+    reset            Forget the shares submitted so far
+
+Exit status:
+    0 once the secret was written out, 2 on invalid arguments, and otherwise the code
+    associated with the error by `rusty_secrets::errors::ErrorKind::code`.";
+
+/// Exit status used when the command line arguments are invalid.
+const EXIT_USAGE: i32 = 2;
+
+fn main() {
+    let args = match Args::parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(message) => {
+            eprintln!("rusty-secrets-unseal: {}\n\n{}", message, USAGE);
+            process::exit(EXIT_USAGE);
+        }
+    };
+
+    if args.help {
+        println!("{}", USAGE);
+        return;
+    }
+
+    if let Err(e) = run(&args) {
+        eprintln!("rusty-secrets-unseal: {}", e);
+        for cause in e.iter().skip(1) {
+            eprintln!("  caused by: {}", cause);
+        }
+        process::exit(i32::from(e.kind().code()));
+    }
+}
+
+fn run(args: &Args) -> Result<()> {
+    let sink = SecretSink::Writer(open_output(&args.output)?);
+    let unsealer = Unsealer::new(
+        args.scheme,
+        args.signed,
+        Duration::from_secs(args.timeout),
+        sink,
+    );
+
+    let server = Server::bind(&args.socket, unsealer)?;
+    eprintln!("rusty-secrets-unseal: sealed, listening on {}", args.socket);
+    server.run()?;
+    eprintln!("rusty-secrets-unseal: unsealed");
+
+    Ok(())
+}
+
+fn open_output(output: &Output) -> Result<Box<Write>> {
+    match *output {
+        Output::Stdout => Ok(Box::new(io::stdout())),
+        Output::File(ref path) => {
+            let file = OpenOptions::new()
+                .write(true)
+                .create(true)
+                .truncate(true)
+                .mode(0o600)
+                .open(path)?;
+            // An existing file keeps its permissions, which must not let others read the secret.
+            file.set_permissions(Permissions::from_mode(0o600))?;
+            Ok(Box::new(file))
+        }
+        // The descriptor is inherited from the parent process, and is only used from here on.
+        Output::Fd(fd) => Ok(Box::new(unsafe { File::from_raw_fd(fd) })),
+    }
+}
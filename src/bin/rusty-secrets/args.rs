@@ -0,0 +1,197 @@
+//! Parsing of the command line arguments.
+
+use rusty_secrets::armor::Scheme;
+
+/// The sub-command to run.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Command {
+    Split,
+    Recover,
+    Inspect,
+    Verify,
+    Help,
+}
+
+/// The parsed command line arguments.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Args {
+    pub command: Command,
+    pub scheme: Option<Scheme>,
+    pub threshold: Option<u8>,
+    pub shares_count: Option<u8>,
+    pub signed: bool,
+    pub armor: bool,
+    pub reproducible: bool,
+    pub interactive: bool,
+    pub mime_type: Option<String>,
+    pub label: Option<String>,
+    pub input: Option<String>,
+    pub output: Option<String>,
+    pub output_dir: Option<String>,
+    pub files: Vec<String>,
+}
+
+impl Args {
+    /// Parses the given arguments, not including the program name.
+    pub fn parse<I: Iterator<Item = String>>(mut args: I) -> Result<Args, String> {
+        let command = match args.next() {
+            None => Command::Help,
+            Some(command) => match command.as_str() {
+                "split" => Command::Split,
+                "recover" => Command::Recover,
+                "inspect" => Command::Inspect,
+                "verify" => Command::Verify,
+                "help" | "-h" | "--help" => Command::Help,
+                other => return Err(format!("unknown command '{}'", other)),
+            },
+        };
+
+        let mut parsed = Args {
+            command,
+            scheme: None,
+            threshold: None,
+            shares_count: None,
+            signed: false,
+            armor: false,
+            reproducible: false,
+            interactive: false,
+            mime_type: None,
+            label: None,
+            input: None,
+            output: None,
+            output_dir: None,
+            files: Vec::new(),
+        };
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-s" | "--scheme" => {
+                    let scheme = value(&mut args, &arg)?;
+                    parsed.scheme = Some(scheme
+                        .parse()
+                        .map_err(|_| format!("unknown scheme '{}'", scheme))?);
+                }
+                "-k" | "--threshold" => parsed.threshold = Some(number(&mut args, &arg)?),
+                "-n" | "--shares" => parsed.shares_count = Some(number(&mut args, &arg)?),
+                "--signed" => parsed.signed = true,
+                "--armor" => parsed.armor = true,
+                "--reproducible" => parsed.reproducible = true,
+                "--interactive" => parsed.interactive = true,
+                "--mime-type" => parsed.mime_type = Some(value(&mut args, &arg)?),
+                "--label" => parsed.label = Some(value(&mut args, &arg)?),
+                "-i" | "--input" => parsed.input = Some(value(&mut args, &arg)?),
+                "-o" | "--output" => parsed.output = Some(value(&mut args, &arg)?),
+                "-d" | "--output-dir" => parsed.output_dir = Some(value(&mut args, &arg)?),
+                "-h" | "--help" => parsed.command = Command::Help,
+                flag if flag.starts_with('-') && flag.len() > 1 => {
+                    return Err(format!("unknown option '{}'", flag))
+                }
+                _ => parsed.files.push(arg.clone()),
+            }
+        }
+
+        if parsed.command == Command::Split {
+            if parsed.threshold.is_none() {
+                return Err("missing '--threshold' option".to_string());
+            }
+            if parsed.shares_count.is_none() {
+                return Err("missing '--shares' option".to_string());
+            }
+            if !parsed.files.is_empty() {
+                return Err("the secret must be given with '--input', or on stdin".to_string());
+            }
+            if parsed.output.is_some() && parsed.output_dir.is_some() {
+                return Err("'--output' and '--output-dir' cannot be used together".to_string());
+            }
+        } else if parsed.output_dir.is_some() && parsed.command != Command::Help {
+            return Err("'--output-dir' can only be used with 'split'".to_string());
+        }
+
+        if parsed.interactive {
+            if parsed.command != Command::Recover {
+                return Err("'--interactive' can only be used with 'recover'".to_string());
+            }
+            if !parsed.files.is_empty() {
+                return Err("shares are read from stdin with '--interactive'".to_string());
+            }
+        }
+
+        Ok(parsed)
+    }
+}
+
+fn value<I: Iterator<Item = String>>(args: &mut I, flag: &str) -> Result<String, String> {
+    args.next()
+        .ok_or_else(|| format!("missing value for '{}'", flag))
+}
+
+fn number<I: Iterator<Item = String>>(args: &mut I, flag: &str) -> Result<u8, String> {
+    let value = value(args, flag)?;
+    value
+        .parse()
+        .map_err(|_| format!("invalid value '{}' for '{}'", value, flag))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Args, String> {
+        Args::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn parse_split() {
+        let args = parse(&["split", "-k", "3", "--shares", "5", "--scheme", "ss1", "--armor"]).unwrap();
+        assert_eq!(args.command, Command::Split);
+        assert_eq!(args.threshold, Some(3));
+        assert_eq!(args.shares_count, Some(5));
+        assert!(args.armor);
+        assert!(!args.signed);
+        assert_eq!(args.scheme, "ss1".parse().ok());
+    }
+
+    #[test]
+    fn parse_recover() {
+        let args = parse(&["recover", "--signed", "share1.txt", "share2.txt", "-o", "secret"]).unwrap();
+        assert_eq!(args.command, Command::Recover);
+        assert!(args.signed);
+        assert_eq!(args.files, vec!["share1.txt", "share2.txt"]);
+        assert_eq!(args.output, Some("secret".to_string()));
+    }
+
+    #[test]
+    fn parse_split_to_directory() {
+        let args = parse(&["split", "-k", "2", "-n", "3", "--output-dir", "shares"]).unwrap();
+        assert_eq!(args.output_dir, Some("shares".to_string()));
+        assert_eq!(args.output, None);
+    }
+
+    #[test]
+    fn parse_interactive_recover() {
+        let args = parse(&["recover", "--interactive", "--scheme", "ss1"]).unwrap();
+        assert!(args.interactive);
+        assert!(args.files.is_empty());
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert!(parse(&["unknown"]).is_err());
+        assert!(parse(&["split", "-k", "3"]).is_err());
+        assert!(parse(&["split", "-k", "300", "-n", "5"]).is_err());
+        assert!(parse(&["recover", "--scheme"]).is_err());
+        assert!(parse(&["recover", "--scheme", "rot13"]).is_err());
+        assert!(parse(&["verify", "--unknown"]).is_err());
+        assert!(parse(&["split", "-k", "2", "-n", "3", "-o", "out", "-d", "dir"]).is_err());
+        assert!(parse(&["recover", "--output-dir", "dir"]).is_err());
+        assert!(parse(&["recover", "--interactive", "share1.txt"]).is_err());
+        assert!(parse(&["inspect", "--interactive"]).is_err());
+    }
+
+    #[test]
+    fn parse_help() {
+        assert_eq!(parse(&[]).unwrap().command, Command::Help);
+        assert_eq!(parse(&["split", "--help"]).unwrap().command, Command::Help);
+    }
+}
@@ -0,0 +1,379 @@
+//! `rusty-secrets` splits secrets into shares, and recovers them, from the command line.
+
+extern crate rusty_secrets;
+
+mod args;
+mod shares;
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Write};
+use std::path::Path;
+use std::process;
+
+use rusty_secrets::armor::{ArmoredShare, Scheme};
+use rusty_secrets::errors::*;
+use rusty_secrets::inspect::{inspect_share, ShareInfo};
+use rusty_secrets::share_set::SortedShares;
+use rusty_secrets::{sss, wrapped_secrets, xor};
+
+#[cfg(feature = "dss")]
+use rusty_secrets::dss::{ss1, thss};
+
+use args::{Args, Command};
+use shares::{prompt_shares, read_shares};
+
+const USAGE: &str = "\
+Usage: rusty-secrets <command> [options] [share files or directories...]
+
+Commands:
+    split      Split the secret read from --input (or stdin) into shares
+    recover    Recover the secret from the given shares
+    inspect    Print information about the given shares
+    verify     Check that the given shares are consistent, without printing the secret
+
+Options:
+    -s, --scheme <scheme>    sss (default), wrapped_secrets, xor, thss or ss1
+    -k, --threshold <k>      Number of shares necessary to recover the secret,
+                             which must be all of them (xor)
+    -n, --shares <n>         Total number of shares to generate
+        --signed             Sign the shares, or verify their signatures
+        --mime-type <type>   MIME type of the secret (wrapped_secrets)
+        --reproducible       Generate the same shares for the same secret (ss1)
+        --armor              Output ASCII-armored shares
+        --label <label>      Label to include in the armored shares
+    -i, --input <file>       Read the secret from <file> instead of stdin
+    -o, --output <file>      Write the output to <file> instead of stdout
+    -d, --output-dir <dir>   Write every share to its own file in <dir> (split)
+        --interactive        Prompt for the shares one at a time, until enough
+                             of them were entered (recover)
+    -h, --help               Print this message
+
+Shares are read from the given files, from all the files in the given directories,
+or from stdin, one per line, or as ASCII-armored blocks, in which case the scheme
+is detected automatically.
+
+Exit status:
+    0 on success, 2 on invalid arguments, and otherwise the code
+    associated with the error by `rusty_secrets::errors::ErrorKind::code`.";
+
+/// Exit status used when the command line arguments are invalid.
+const EXIT_USAGE: i32 = 2;
+
+fn main() {
+    let args = match Args::parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(message) => {
+            eprintln!("rusty-secrets: {}\n\n{}", message, USAGE);
+            process::exit(EXIT_USAGE);
+        }
+    };
+
+    if let Err(e) = run(&args) {
+        eprintln!("rusty-secrets: {}", e);
+        for cause in e.iter().skip(1) {
+            eprintln!("  caused by: {}", cause);
+        }
+        process::exit(i32::from(e.kind().code()));
+    }
+}
+
+fn run(args: &Args) -> Result<()> {
+    match args.command {
+        Command::Split => split(args),
+        Command::Recover => recover(args),
+        Command::Inspect => inspect(args),
+        Command::Verify => verify(args),
+        Command::Help => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+    }
+}
+
+fn split(args: &Args) -> Result<()> {
+    // Both are checked while parsing the arguments.
+    let k = args.threshold.unwrap();
+    let n = args.shares_count.unwrap();
+    let scheme = args.scheme.unwrap_or(Scheme::SSS);
+
+    let mut secret = Vec::new();
+    match args.input {
+        Some(ref path) => File::open(path)?.read_to_end(&mut secret)?,
+        None => io::stdin().read_to_end(&mut secret)?,
+    };
+
+    let shares = match scheme {
+        Scheme::SSS => sss::split_secret(k, n, &secret, args.signed)?,
+        Scheme::WrappedSecrets => {
+            wrapped_secrets::split_secret(k, n, &secret, args.mime_type.clone(), args.signed)?
+        }
+        Scheme::Xor => {
+            if k != n {
+                return Err(ErrorKind::InvalidXorThreshold(k, n).into());
+            }
+            xor::split_secret(n, &secret, args.signed)?
+        }
+        #[cfg(feature = "dss")]
+        Scheme::ThSS => thss_scheme(args)
+            .split_secret(k, n, &secret, &None)?
+            .into_iter()
+            .map(thss::Share::into_string)
+            .collect(),
+        #[cfg(feature = "dss")]
+        Scheme::SS1 => {
+            let reproducibility = if args.reproducible {
+                ss1::Reproducibility::reproducible()
+            } else {
+                ss1::Reproducibility::none()
+            };
+            ss1_scheme(args)?
+                .split_secret(k, n, &secret, reproducibility, &None)?
+                .into_iter()
+                .map(ss1::Share::into_string)
+                .collect()
+        }
+    };
+
+    if let Some(ref dir) = args.output_dir {
+        return write_share_files(args, scheme, &shares, Path::new(dir));
+    }
+
+    let mut output = open_output(args)?;
+    for share in shares {
+        writeln!(output, "{}", format_share(args, scheme, &share)?)?;
+    }
+
+    Ok(())
+}
+
+/// Formats the given share for output, armoring it if requested.
+fn format_share(args: &Args, scheme: Scheme, share: &str) -> Result<String> {
+    if !args.armor {
+        return Ok(share.to_string());
+    }
+
+    let mut armored = ArmoredShare::new(scheme, share)?;
+    if let Some(ref label) = args.label {
+        armored = armored.with_label(label);
+    }
+    armored.to_armor()
+}
+
+/// Writes every share to its own file in the given directory, which is created if needed.
+/// Existing files are never overwritten, so that the shares of a previous split are kept.
+fn write_share_files(args: &Args, scheme: Scheme, shares: &[String], dir: &Path) -> Result<()> {
+    fs::create_dir_all(dir)?;
+    let extension = if args.armor { "asc" } else { "txt" };
+
+    for (i, share) in shares.iter().enumerate() {
+        let path = dir.join(format!("share-{}.{}", i + 1, extension));
+        let mut file = open_private(&path, OpenOptions::new().write(true).create_new(true))?;
+        writeln!(file, "{}", format_share(args, scheme, share)?)?;
+    }
+
+    Ok(())
+}
+
+fn recover(args: &Args) -> Result<()> {
+    let input = if args.interactive {
+        prompt_shares(args.scheme, args.signed)?
+    } else {
+        read_shares(&args.files, args.scheme)?
+    };
+
+    let secret = match input.scheme {
+        Scheme::SSS => sss::recover_secret(&input.shares, args.signed)?,
+        Scheme::WrappedSecrets => {
+            let mut secret = wrapped_secrets::recover_secret(&input.shares, args.signed)?;
+            if !secret.get_mime_type().is_empty() {
+                eprintln!("MIME type: {}", secret.get_mime_type());
+            }
+            secret.take_secret()
+        }
+        Scheme::Xor => xor::recover_secret(&input.shares, args.signed)?,
+        #[cfg(feature = "dss")]
+        Scheme::ThSS => {
+            let shares = input
+                .shares
+                .iter()
+                .map(|share| thss::Share::from_string(share))
+                .collect::<Result<Vec<_>>>()?;
+            thss_scheme(args).recover_secret(&shares)?.0
+        }
+        #[cfg(feature = "dss")]
+        Scheme::SS1 => {
+            let shares = input
+                .shares
+                .iter()
+                .map(|share| ss1::Share::from_string(share))
+                .collect::<Result<Vec<_>>>()?;
+            ss1_scheme(args)?.recover_secret(&shares)?.0
+        }
+    };
+
+    let mut output = open_output(args)?;
+    output.write_all(&secret)?;
+
+    Ok(())
+}
+
+fn inspect(args: &Args) -> Result<()> {
+    let input = read_shares(&args.files, args.scheme)?;
+    let mut output = open_output(args)?;
+
+    for share in &input.shares {
+        let info = inspect_share(share)?;
+
+        // Armored shares, and the command line, tell `SSS` and `WrappedSecrets` shares apart.
+        let scheme = if info.schemes.contains(&input.scheme) {
+            input.scheme
+        } else if args.scheme.is_some() {
+            return Err(ErrorKind::ShareParsingError(format!(
+                "Expected a share of the {} scheme, found a share of the {} scheme.",
+                input.scheme,
+                info.scheme()
+            )).into());
+        } else {
+            info.scheme()
+        };
+
+        writeln!(output, "Scheme: {}", scheme)?;
+        writeln!(output, "Threshold: {}", info.threshold)?;
+        writeln!(output, "Share-Id: {}", info.id)?;
+        match info.share_set_id {
+            Some(share_set_id) => writeln!(output, "Share-Set-Id: {}", share_set_id)?,
+            None => writeln!(output, "Share-Set-Id: none")?,
+        }
+        if let Some(shares_count) = info.shares_count {
+            writeln!(output, "Shares-Count: {}", shares_count)?;
+        }
+        writeln!(output, "Data-Length: {}", info.data_len)?;
+        writeln!(output, "Signed: {}", info.signed)?;
+        writeln!(output, "Format-Version: {}", info.format_version)?;
+        write_parameters(&mut output, &info)?;
+        for (tag, value) in &info.tags {
+            writeln!(output, "Tag: {}={}", tag, value)?;
+        }
+
+        writeln!(output, "")?;
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "dss")]
+fn write_parameters(output: &mut Box<Write>, info: &ShareInfo) -> Result<()> {
+    if let Some(parameters) = info.ss1_parameters {
+        writeln!(output, "Parameters: {}", parameters)?;
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "dss"))]
+fn write_parameters(_output: &mut Box<Write>, _info: &ShareInfo) -> Result<()> {
+    Ok(())
+}
+
+fn verify(args: &Args) -> Result<()> {
+    let input = read_shares(&args.files, args.scheme)?;
+    let mut output = open_output(args)?;
+
+    match input.scheme {
+        Scheme::SSS => report(&mut output, sss::sort_shares(&input.shares, args.signed)),
+        Scheme::WrappedSecrets => report(
+            &mut output,
+            wrapped_secrets::sort_shares(&input.shares, args.signed),
+        ),
+        Scheme::Xor => report(&mut output, xor::sort_shares(&input.shares, args.signed)),
+        #[cfg(feature = "dss")]
+        Scheme::ThSS => report(&mut output, thss_scheme(args).sort_shares(&input.shares)),
+        #[cfg(feature = "dss")]
+        Scheme::SS1 => report(&mut output, ss1_scheme(args)?.sort_shares(&input.shares)),
+    }
+}
+
+/// Prints the outcome of the verification of every share set, and fails with
+/// the first error encountered, if any.
+fn report<T>(output: &mut Box<Write>, sorted: SortedShares<T>) -> Result<()> {
+    let mut failure = None;
+
+    for (share, e) in sorted.rejected {
+        writeln!(output, "Invalid share {}: {}", share, e)?;
+        failure = failure.or(Some(e));
+    }
+
+    for set in sorted.sets {
+        let id = set.id
+            .map(|id| id.to_string())
+            .unwrap_or_else(|| "without identifier".to_string());
+        write!(
+            output,
+            "Share set {} (k = {}, shares {:?}): ",
+            id, set.threshold, set.share_ids
+        )?;
+
+        match set.recovered {
+            Some(Ok(_)) => writeln!(output, "OK")?,
+            Some(Err(e)) => {
+                writeln!(output, "{}", e)?;
+                failure = failure.or(Some(e));
+            }
+            None => {
+                writeln!(output, "{} more share(s) needed", set.missing)?;
+                let missing = ErrorKind::MissingShares(set.share_ids.len(), set.threshold);
+                failure = failure.or(Some(missing.into()));
+            }
+        }
+    }
+
+    match failure {
+        Some(e) => Err(e),
+        None => Ok(()),
+    }
+}
+
+/// Builds the `ThSS` scheme which signs shares, and verifies their signatures, if requested.
+#[cfg(feature = "dss")]
+fn thss_scheme(args: &Args) -> thss::ThSS {
+    thss::ThSS::builder()
+        .sign_shares(args.signed)
+        .verify_signatures(args.signed)
+        .build()
+}
+
+/// Builds the `SS1` scheme which signs shares, and verifies their signatures, if requested.
+#[cfg(feature = "dss")]
+fn ss1_scheme(args: &Args) -> Result<ss1::SS1> {
+    ss1::SS1::builder()
+        .sign_shares(args.signed)
+        .verify_signatures(args.signed)
+        .build()
+}
+
+fn open_output(args: &Args) -> Result<Box<Write>> {
+    match args.output {
+        Some(ref path) => Ok(Box::new(open_private(
+            path,
+            OpenOptions::new().write(true).create(true).truncate(true),
+        )?)),
+        None => Ok(Box::new(io::stdout())),
+    }
+}
+
+/// Opens the file at the given path, and makes it only accessible to the current user,
+/// since it is meant to hold shares or secrets.
+/// An existing file keeps its permissions when opened, so they are restricted as well.
+#[cfg(unix)]
+fn open_private<P: AsRef<Path>>(path: P, options: &mut OpenOptions) -> Result<File> {
+    use std::fs::Permissions;
+    use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+
+    let file = options.mode(0o600).open(path)?;
+    file.set_permissions(Permissions::from_mode(0o600))?;
+    Ok(file)
+}
+
+#[cfg(not(unix))]
+fn open_private<P: AsRef<Path>>(path: P, options: &mut OpenOptions) -> Result<File> {
+    Ok(options.open(path)?)
+}
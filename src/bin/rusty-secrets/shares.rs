@@ -0,0 +1,215 @@
+//! Reading of shares from files or stdin.
+
+use std::fs::{self, File};
+use std::io::{self, BufRead, Read};
+use std::path::{Path, PathBuf};
+
+use rusty_secrets::armor::{ArmoredShare, Scheme};
+use rusty_secrets::errors::*;
+use rusty_secrets::recovery::RecoverySession;
+
+/// Marker identifying ASCII-armored input.
+const ARMOR_MARKER: &str = "-----BEGIN RUSTY SECRETS SHARE-----";
+
+/// Marker ending an ASCII-armored share.
+const ARMOR_END_MARKER: &str = "-----END RUSTY SECRETS SHARE-----";
+
+/// Shares read from the input, along with the scheme they belong to.
+#[derive(Debug)]
+pub struct Shares {
+    pub scheme: Scheme,
+    pub shares: Vec<String>,
+}
+
+/// Reads the shares contained in the given files, or on stdin if no file is given.
+/// Directories are replaced with the files they contain.
+///
+/// Plain shares are expected one per line, blank lines and lines starting with `#`
+/// being ignored. Armored shares carry their scheme, which must then agree with the
+/// one given on the command line, if any.
+pub fn read_shares(files: &[String], scheme: Option<Scheme>) -> Result<Shares> {
+    let mut text = String::new();
+    if files.is_empty() {
+        io::stdin().read_to_string(&mut text)?;
+    } else {
+        for path in expand_directories(files)? {
+            File::open(path)?.read_to_string(&mut text)?;
+            text.push('\n');
+        }
+    }
+
+    if !text.contains(ARMOR_MARKER) {
+        let shares = text.lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(str::to_string)
+            .collect();
+
+        return Ok(Shares {
+            scheme: scheme.unwrap_or(Scheme::SSS),
+            shares,
+        });
+    }
+
+    let armored = ArmoredShare::parse_all(&text)?;
+    let mut detected = scheme;
+    let mut shares = Vec::with_capacity(armored.len());
+
+    for share in armored {
+        match detected {
+            Some(expected) if expected != share.scheme => {
+                return Err(ErrorKind::InvalidArmor(format!(
+                    "found a share for scheme {} while expecting scheme {}",
+                    share.scheme, expected
+                )).into());
+            }
+            _ => detected = Some(share.scheme),
+        }
+        shares.push(share.share);
+    }
+
+    Ok(Shares {
+        scheme: detected.unwrap_or(Scheme::SSS),
+        shares,
+    })
+}
+
+/// Replaces the directories among the given paths with the files they contain, in
+/// alphabetical order, such as the ones written by `split --output-dir`.
+/// Hidden files and subdirectories are skipped.
+fn expand_directories(paths: &[String]) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+
+    for path in paths {
+        let path = Path::new(path);
+        if !path.is_dir() {
+            files.push(path.to_path_buf());
+            continue;
+        }
+
+        let mut entries = Vec::new();
+        for entry in fs::read_dir(path)? {
+            let entry = entry?;
+            let hidden = entry.file_name().to_string_lossy().starts_with('.');
+            if !hidden && entry.file_type()?.is_file() {
+                entries.push(entry.path());
+            }
+        }
+        entries.sort();
+        files.extend(entries);
+    }
+
+    Ok(files)
+}
+
+/// Prompts for the shares one at a time on stdin, until enough of them were entered
+/// to recover the secret.
+///
+/// Every share is validated as soon as it is entered, and rejected shares can be entered
+/// again. Plain shares are entered on a single line, and armored ones as a whole block,
+/// whose scheme must then agree with the one of the shares entered before, if any,
+/// or with the one given on the command line.
+pub fn prompt_shares(scheme: Option<Scheme>, verify_signatures: bool) -> Result<Shares> {
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    let mut session: Option<RecoverySession> = None;
+
+    loop {
+        let missing = session.as_ref().and_then(RecoverySession::missing);
+        match missing {
+            Some(0) => break,
+            Some(missing) => eprint!("Share ({} more needed): ", missing),
+            None => eprint!("Share: "),
+        }
+
+        let text = match read_entry(&mut lines)? {
+            Some(text) => text,
+            None => {
+                eprintln!();
+                return Err(match session {
+                    Some(ref session) => ErrorKind::MissingShares(
+                        session.shares().len(),
+                        session.threshold().unwrap_or(0),
+                    ),
+                    None => ErrorKind::EmptyShares,
+                }.into());
+            }
+        };
+
+        let expected = session.as_ref().map(RecoverySession::scheme).or(scheme);
+        let (share_scheme, share) = match parse_entry(&text, expected) {
+            Ok(entry) => entry,
+            Err(e) => {
+                eprintln!("Rejected: {}", e);
+                continue;
+            }
+        };
+
+        if session.is_none() {
+            session = Some(RecoverySession::new(share_scheme, verify_signatures));
+        }
+        let added = session.as_mut().unwrap().add_share(&share);
+        if let Err(e) = added {
+            eprintln!("Rejected: {}", e);
+            // The scheme is only settled once a share was accepted.
+            if session.as_ref().map_or(false, |session| session.shares().is_empty()) {
+                session = None;
+            }
+        }
+    }
+
+    // The loop only exits once the session is complete.
+    let session = session.unwrap();
+    Ok(Shares {
+        scheme: session.scheme(),
+        shares: session.shares().to_vec(),
+    })
+}
+
+/// Reads a plain share, or a whole armored share, skipping blank lines and comments.
+fn read_entry<B: BufRead>(lines: &mut io::Lines<B>) -> Result<Option<String>> {
+    let line = loop {
+        match lines.next() {
+            None => return Ok(None),
+            Some(line) => {
+                let line = line?;
+                if !line.trim().is_empty() && !line.trim().starts_with('#') {
+                    break line;
+                }
+            }
+        }
+    };
+
+    if !line.trim().ends_with(ARMOR_MARKER) {
+        return Ok(Some(line.trim().to_string()));
+    }
+
+    let mut block = line;
+    block.push('\n');
+    for line in lines {
+        let line = line?;
+        block.push_str(&line);
+        block.push('\n');
+        if line.trim().ends_with(ARMOR_END_MARKER) {
+            break;
+        }
+    }
+
+    Ok(Some(block))
+}
+
+/// Parses the given plain or armored share, and returns its scheme along with the share.
+fn parse_entry(text: &str, expected: Option<Scheme>) -> Result<(Scheme, String)> {
+    if !text.contains(ARMOR_MARKER) {
+        return Ok((expected.unwrap_or(Scheme::SSS), text.to_string()));
+    }
+
+    let armored = ArmoredShare::from_armor(text)?;
+    match expected {
+        Some(expected) if expected != armored.scheme => Err(ErrorKind::InvalidArmor(format!(
+            "found a share for scheme {} while expecting scheme {}",
+            armored.scheme, expected
+        )).into()),
+        _ => Ok((armored.scheme, armored.share)),
+    }
+}
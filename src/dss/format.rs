@@ -1,10 +1,14 @@
 use std::error::Error;
 
 use base64;
-use protobuf::{self, Message};
+use protobuf::{self, Message, RepeatedField};
 
+use dss::Signature;
+use dss::metadata::MetaData;
 use errors::*;
+use hash::HashAlgorithm;
 use proto::dss::ShareProto;
+use share_set::ShareSetId;
 
 const BASE64_CONFIG: base64::Config = base64::STANDARD_NO_PAD;
 
@@ -14,6 +18,74 @@ pub(crate) fn format_share_protobuf(share: &ShareProto) -> String {
     format!("{}-{}-{}", share.threshold, share.id, base64_data)
 }
 
+/// Formats the fields common to the shares of both schemes into the message signed by the
+/// dealer. Metadata tags are sorted, and every variable-length field is Base64-encoded,
+/// so that two different shares never yield the same message.
+pub(crate) fn format_share_for_signing(
+    scheme: &str,
+    threshold: u8,
+    shares_count: u8,
+    id: u8,
+    share_set_id: Option<ShareSetId>,
+    data: &[u8],
+    metadata: &Option<MetaData>,
+) -> String {
+    let share_set_id = share_set_id
+        .map(|share_set_id| share_set_id.to_string())
+        .unwrap_or_default();
+    let metadata = metadata
+        .as_ref()
+        .map(|metadata| {
+            metadata
+                .tags
+                .iter()
+                .map(|(tag, value)| {
+                    format!(
+                        "{}:{}",
+                        base64::encode_config(tag, BASE64_CONFIG),
+                        base64::encode_config(value, BASE64_CONFIG)
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(",")
+        })
+        .unwrap_or_default();
+
+    format!(
+        "{}-{}-{}-{}-{}-{}-{}",
+        scheme,
+        threshold,
+        shares_count,
+        id,
+        share_set_id,
+        base64::encode_config(data, BASE64_CONFIG),
+        metadata
+    )
+}
+
+/// Records the signature of a share, along with the hash function it is built on.
+pub(crate) fn set_signature_proto(proto: &mut ShareProto, signature: Signature) {
+    proto.set_signature(RepeatedField::from_vec(signature.signature));
+    proto.set_proof(signature.proof);
+    proto.set_signature_hash_algorithm(signature.hash_algorithm.to_signature_proto());
+}
+
+/// Takes the signature of a share, if it is signed. Shares signed before the hash function
+/// was recorded in them leave it unset, and were signed with SHA-512.
+pub(crate) fn take_signature_proto(proto: &mut ShareProto) -> Option<Signature> {
+    if proto.get_proof().is_empty() {
+        return None;
+    }
+
+    let hash_algorithm = HashAlgorithm::from_signature_proto(proto.get_signature_hash_algorithm());
+
+    Some(Signature {
+        signature: proto.take_signature().into_vec(),
+        proof: proto.take_proof(),
+        hash_algorithm,
+    })
+}
+
 pub(crate) fn parse_share_protobuf(raw: &str) -> Result<ShareProto> {
     let (threshold, id, base64_data) = parse_raw_share(raw)?;
 
@@ -1,8 +1,9 @@
-use ring::digest;
+use hash::Hasher;
 use std::collections::BTreeMap;
 
 /// A share's public metadata.
 #[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
 pub struct MetaData {
     /// The tags associated with the share
     pub tags: BTreeMap<String, String>,
@@ -21,7 +22,7 @@ impl MetaData {
         Self { tags }
     }
 
-    pub(crate) fn hash_into(&self, ctx: &mut digest::Context) {
+    pub(crate) fn hash_into(&self, ctx: &mut Hasher) {
         for (tag, value) in &self.tags {
             ctx.update(tag.as_bytes());
             ctx.update(b":");
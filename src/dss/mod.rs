@@ -26,13 +26,29 @@
 //! **Auth2**    | A share obtained even from a dishonest dealer commits it to a single underlying secret: that and only that value might be recovered. Implies Auth1.
 //! **ErrDet**   | An inauthentic set of shares produced by an adversary will be flagged as such when fed to the recovery algorithm.
 //! **Repro**    | Share reproducible: The scheme can produce shares in a deterministic way.
+//!
+//! # Signatures
+//!
+//! The shares of both schemes can optionally be signed by the dealer, and their signatures
+//! verified upon recovery, in which case all the shares must have been signed by the same dealer.
+//! This protects against an adversary replacing a whole set of shares, which neither scheme
+//! detects on its own. Note that the signatures of reproducible `SS1` shares differ between
+//! two splits, even though the shares themselves do not.
+//!
+//! Shares are signed with SHA-512 by default, and the builders of both schemes allow selecting
+//! another fixed-length hash function, which is recorded in the signatures.
 
 pub mod ss1;
 pub mod thss;
 
 mod metadata;
 
+mod signature;
+pub use self::signature::Signature;
+
 mod format;
+pub(crate) use self::format::parse_share_protobuf;
+
 mod random;
 mod utils;
 
@@ -48,7 +64,8 @@ mod utils;
 ///     shares_count: 10,
 /// };
 /// ```
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
 pub struct AccessStructure {
     /// The minimum amount of shares required to recover the secret.
     pub threshold: u8,
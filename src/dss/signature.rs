@@ -0,0 +1,123 @@
+//! Dealer signatures for the shares of the deterministic schemes.
+//!
+//! As with the `sss` scheme, shares are signed with a Lamport one-time signature scheme,
+//! whose public keys are the leaves of a Merkle tree. The root hash of that tree identifies
+//! the dealer, so that a whole set of shares swapped by an adversary gets detected.
+//! The hash function on which the keys and the tree are built is recorded in the signature.
+
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+
+use merkle_sigs::{sign_data_vec, verify_data_vec_signature, MerklePublicKey, Proof, PublicKey};
+
+use errors::*;
+use hash::HashAlgorithm;
+
+/// Holds the signature of a share, along with the serialized proof of inclusion
+/// of its public key in the underlying Merkle tree used in the Lamport signature scheme.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
+pub struct Signature {
+    /// The signature
+    #[cfg_attr(feature = "serialization", serde(with = "::serialization::base64_bytes_vec"))]
+    pub signature: Vec<Vec<u8>>,
+    /// The proof of inclusion
+    #[cfg_attr(feature = "serialization", serde(with = "::serialization::base64_bytes"))]
+    pub proof: Vec<u8>,
+    /// The hash function on which the signature is built, which is SHA-512
+    /// for signatures serialized before it was recorded in them
+    #[cfg_attr(
+        feature = "serialization",
+        serde(default = "::serialization::default_signature_hash_algorithm")
+    )]
+    pub hash_algorithm: HashAlgorithm,
+}
+
+impl Signature {
+    fn to_pair(&self, id: u8) -> Result<(Vec<Vec<u8>>, Proof<MerklePublicKey>)> {
+        let invalid = |reason: &str| ErrorKind::InvalidSignature(id, reason.to_string());
+        let algorithm = self.hash_algorithm.signature_algorithm()?;
+
+        let proof = Proof::parse_from_bytes(&self.proof, algorithm)
+            .map_err(|e| invalid(e.description()))?
+            .ok_or_else(|| invalid("Missing proof of inclusion"))?;
+        let public_key = PublicKey::from_vec(proof.value, algorithm)
+            .ok_or_else(|| invalid("Invalid public key"))?;
+
+        let proof = Proof {
+            algorithm,
+            lemma: proof.lemma,
+            root_hash: proof.root_hash,
+            value: MerklePublicKey::new(public_key),
+        };
+
+        Ok((self.signature.clone(), proof))
+    }
+}
+
+/// The shares of the deterministic schemes, which the dealer can sign.
+pub(crate) trait SignableShare {
+    /// Returns the message signed by the dealer.
+    fn format_for_signing(&self) -> Vec<u8>;
+
+    /// Sets the signature of the share.
+    fn set_signature(&mut self, signature: Signature);
+}
+
+/// Signs the given shares, with keys from a single Merkle tree
+/// built on top of the given hash function.
+pub(crate) fn sign_shares<S: SignableShare>(
+    shares: &mut [S],
+    hash_algorithm: HashAlgorithm,
+) -> Result<()> {
+    let messages = shares
+        .iter()
+        .map(SignableShare::format_for_signing)
+        .collect::<Vec<_>>();
+    let signatures = sign_data_vec(&messages, hash_algorithm.signature_algorithm()?)
+        .map_err(|_| ErrorKind::CannotGenerateRandomNumbers)?;
+
+    for (share, (signature, proof)) in shares.iter_mut().zip(signatures) {
+        share.set_signature(Signature {
+            signature,
+            proof: proof.write_to_bytes().unwrap(),
+            hash_algorithm,
+        });
+    }
+
+    Ok(())
+}
+
+/// Verifies the signatures of a batch of shares, given as their identifier, the message
+/// which was signed, and their signature. All the shares must be signed by the same dealer.
+pub(crate) fn verify_signatures(shares: &[(u8, Vec<u8>, Option<&Signature>)]) -> Result<()> {
+    let mut rh_compatibility_sets = HashMap::new();
+
+    for &(id, ref message, signature) in shares {
+        let signature = match signature {
+            Some(signature) => signature,
+            None => bail!(ErrorKind::MissingSignature(id)),
+        };
+
+        let pair = signature.to_pair(id)?;
+        let root_hash = pair.1.root_hash.clone();
+
+        verify_data_vec_signature(message.clone(), &pair, &root_hash)
+            .map_err(|e| ErrorKind::InvalidSignature(id, String::from(e.description())))?;
+
+        rh_compatibility_sets
+            .entry(root_hash)
+            .or_insert_with(HashSet::new)
+            .insert(id);
+    }
+
+    match rh_compatibility_sets.len() {
+        0 => bail!(ErrorKind::EmptyShares),
+        1 => {} // All shares have the same roothash.
+        _ => bail!(ErrorKind::IncompatibleSets(
+            rh_compatibility_sets.into_iter().map(|(_, ids)| ids).collect(),
+        )),
+    }
+
+    Ok(())
+}
@@ -22,6 +22,7 @@
 //! - *New Directions in Secret Sharing* (TODO: Full reference)
 
 use errors::*;
+use share_set::SortedShares;
 
 mod serialize;
 
@@ -29,13 +30,18 @@ mod share;
 pub use self::share::*;
 
 mod scheme;
-pub use self::scheme::Reproducibility;
-use self::scheme::SS1;
+pub use self::scheme::{Reproducibility, SS1, SS1Builder};
+pub(crate) use self::scheme::validate_share_parameters;
+pub use self::scheme::{DEFAULT_HASH_LEN, DEFAULT_RANDOM_PADDING_LEN, MIN_HASH_LEN,
+                       MIN_RANDOM_PADDING_LEN};
 
 use dss::AccessStructure;
 
 /// Performs threshold k-out-of-n deterministic secret sharing.
 ///
+/// The shares are not signed, use a scheme built with `SS1::builder().sign_shares(true)`
+/// to sign them.
+///
 /// # Examples
 ///
 /// ```
@@ -69,6 +75,8 @@ pub fn split_secret(
 /// Recovers the secret from a k-out-of-n deterministic secret sharing scheme (`SS1`).
 ///
 /// At least `k` distinct shares need to be provided to recover the secret.
+/// Signatures are not verified, use a scheme built with
+/// `SS1::builder().verify_signatures(true)` to verify them.
 ///
 /// # Examples
 ///
@@ -102,6 +110,31 @@ pub fn recover_secret(shares: &[Share]) -> Result<(Vec<u8>, AccessStructure, Opt
     SS1::default().recover_secret(shares)
 }
 
+/// Parses a pile of shares coming from several splits, sorts them by share set,
+/// reports how many shares each set is missing, and recovers the secret of every
+/// set for which enough shares were provided (`SS1`).
+///
+/// # Examples
+///
+/// ```rust
+/// use rusty_secrets::dss::ss1;
+///
+/// let shares = ss1::split_secret(3, 5, b"secret", ss1::Reproducibility::none(), &None).unwrap();
+/// let raws = shares
+///     .into_iter()
+///     .take(2)
+///     .map(|share| share.into_string())
+///     .collect::<Vec<_>>();
+///
+/// let sorted = ss1::sort_shares(&raws);
+/// assert_eq!(sorted.sets[0].missing, 1);
+/// ```
+pub fn sort_shares(
+    shares: &[String],
+) -> SortedShares<(Vec<u8>, AccessStructure, Option<MetaData>)> {
+    SS1::default().sort_shares(shares)
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -191,4 +224,70 @@ mod tests {
         assert_eq!(shares_1, shares_2);
     }
 
+    #[test]
+    fn custom_parameters_are_recorded_in_shares() {
+        let secret = "Hello, World!".to_string().into_bytes();
+
+        let scheme = SS1::builder()
+            .random_padding_len(MIN_RANDOM_PADDING_LEN)
+            .hash_len(384)
+            .build()
+            .unwrap();
+        let shares = scheme
+            .split_secret(2, 3, &secret, Reproducibility::none(), &None)
+            .unwrap()
+            .into_iter()
+            .map(|share| Share::from_string(&share.into_string()).unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(shares[0].parameters, scheme.parameters());
+        assert_eq!(shares[0].parameters.hash_len, 384);
+
+        // The default recoverer follows the parameters found in the shares.
+        let (recovered, _, _) = recover_secret(&shares[1..]).unwrap();
+        assert_eq!(secret, recovered);
+    }
+
+    #[test]
+    fn legacy_shares_use_default_parameters() {
+        use super::serialize::share_to_protobuf;
+        use dss::format::format_share_protobuf;
+
+        let secret = "Hello, World!".to_string().into_bytes();
+        let shares = split_secret(2, 3, &secret, Reproducibility::none(), &None).unwrap();
+
+        // Shares dealt before the parameters were recorded do not carry them.
+        let legacy = shares
+            .into_iter()
+            .map(|share| {
+                let mut proto = share_to_protobuf(share);
+                proto.clear_random_padding_len();
+                proto.clear_hash_len();
+                Share::from_string(&format_share_protobuf(&proto)).unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(legacy[0].parameters, Parameters::default());
+        let (recovered, _, _) = recover_secret(&legacy[..2]).unwrap();
+        assert_eq!(secret, recovered);
+    }
+
+    #[test]
+    fn inconsistent_parameters_are_rejected() {
+        let secret = "Hello, World!".to_string().into_bytes();
+        let mut shares = split_secret(2, 3, &secret, Reproducibility::none(), &None).unwrap();
+
+        shares[1].parameters.random_padding_len = 1024;
+        match *recover_secret(&shares[..2]).unwrap_err().kind() {
+            ErrorKind::InconsistentSS1Parameters(2, _, _, _) => {}
+            ref kind => panic!("Unexpected error: {:?}", kind),
+        }
+
+        shares[0].parameters.random_padding_len = 64;
+        shares[1].parameters.random_padding_len = 64;
+        match *recover_secret(&shares[..2]).unwrap_err().kind() {
+            ErrorKind::InvalidSS1Parameters(64, _) => {}
+            ref kind => panic!("Unexpected error: {:?}", kind),
+        }
+    }
 }
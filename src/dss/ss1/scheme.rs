@@ -1,17 +1,21 @@
 use std::collections::HashSet;
+use std::fmt;
 
 use rand::{ChaChaRng, Rng, SeedableRng};
-use ring::digest::{Context, SHA256};
 use ring::rand::{SecureRandom, SystemRandom};
 use ring::{hkdf, hmac};
 
 use super::share::*;
 use dss::random::{random_bytes_count, FixedRandom, MAX_MESSAGE_SIZE};
+use dss::signature;
 use dss::thss::{MetaData, ThSS};
 use dss::utils;
 use dss::{thss, AccessStructure};
 use errors::*;
-use share::validation::{validate_share_count, validate_shares};
+use hash::{Hasher, DEFAULT_SIGNATURE_HASH_ALGORITHM};
+use scheme::{SchemeKind, SecretSharingScheme};
+use share::validation::{validate_share_count, validate_signed_shares};
+use share_set::{self, ShareSetId, SortedShares, SHARE_SET_ID_LEN};
 use vol_hash::VOLHash;
 
 /// We bound the message size at about 16MB to avoid overflow in `random_bytes_count`.
@@ -31,6 +35,7 @@ const DEFAULT_PRESEED: &[u8] = b"rusty_secrets::dss::ss1";
 /// M0 or M1, in a share-reproducible scheme, acquiring a single share
 /// will probably let you decide which of the two possibilities it was.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
 pub enum Reproducibility {
     /// Shares will be produced in a deterministic way, using
     /// a default, fixed seed for the internal random number generator
@@ -42,11 +47,17 @@ pub enum Reproducibility {
     /// Shares will be produced in a deterministic way, using
     /// the given seed for the internal random number generator used to
     /// generate entropy.
-    Seeded(Vec<u8>),
+    Seeded(
+        #[cfg_attr(feature = "serialization", serde(with = "::serialization::base64_bytes"))]
+        Vec<u8>,
+    ),
     /// Shares will be produced in a deterministic way, using
     /// the given byte vector as the entropy source.
     /// *Warning: Never use this variant unless you are sure of what you are doing*
-    WithEntropy(Vec<u8>),
+    WithEntropy(
+        #[cfg_attr(feature = "serialization", serde(with = "::serialization::base64_bytes"))]
+        Vec<u8>,
+    ),
 }
 
 impl Reproducibility {
@@ -87,48 +98,250 @@ impl Reproducibility {
 ///
 /// This scheme is implemented as the *T2 transform* over the ThSS threshold sharing scheme.
 /// found in the `rusty_secrets::dss::thss` module.
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub(crate) struct SS1 {
-    /// How many random bytes to read from `random` to use as
-    /// padding to the hash function (param `r` from the paper)
-    /// and to the message in the underlying ThSS scheme.
-    pub random_padding_len: usize,
-    /// The length of the hash used for all shares (param `s` from the paper)
-    pub hash_len: usize,
+///
+/// A scheme with the default parameters, which neither signs shares nor verifies their
+/// signatures, is obtained with `SS1::default()`, while `SS1::builder()` allows tuning them,
+/// choosing the source of randomness, and signing shares, with SHA-512 unless another
+/// hash function is chosen for the signatures.
+/// The parameters are recorded in every share, so that the secret can be recovered
+/// by any `SS1` scheme, whatever its own parameters.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_secrets::dss::ss1::{Reproducibility, SS1};
+/// use rusty_secrets::hash::HashAlgorithm;
+///
+/// let scheme = SS1::builder()
+///     .random_padding_len(1024)
+///     .hash_len(512)
+///     .hash_algorithm(HashAlgorithm::SHAKE256)
+///     .sign_shares(true)
+///     .verify_signatures(true)
+///     .build()
+///     .unwrap();
+///
+/// let shares = scheme
+///     .split_secret(2, 3, b"Hello", Reproducibility::none(), &None)
+///     .unwrap();
+/// let (secret, _, _) = scheme.recover_secret(&shares[1..]).unwrap();
+///
+/// assert_eq!(secret, b"Hello");
+/// assert!(shares[0].is_signed());
+/// ```
+pub struct SS1 {
+    /// The parameters of the scheme, recorded in every share
+    parameters: Parameters,
+    /// The randomness source, used when shares are not reproducible
+    random: Box<SecureRandom>,
+    /// Whether the dealt shares are signed
+    sign_shares: bool,
+    /// Whether the signatures of the shares are verified during recovery
+    verify_signatures: bool,
+    /// The hash function on which the signatures of the shares are built
+    signature_hash_algorithm: HashAlgorithm,
+}
+
+impl fmt::Debug for SS1 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SS1")
+            .field("parameters", &self.parameters)
+            .field("sign_shares", &self.sign_shares)
+            .field("verify_signatures", &self.verify_signatures)
+            .field("signature_hash_algorithm", &self.signature_hash_algorithm)
+            .finish()
+    }
 }
 
 // TODO: Are those good parameters?
 // TODO: Add max length ?
-static DEFAULT_RANDOM_PADDING_LEN: usize = 512; // r
-static MIN_RANDOM_PADDING_LEN: usize = 128; // r min
-static DEFAULT_HASH_LEN: usize = 256; // s
-static MIN_HASH_LEN: usize = 128; // s min
+/// The default length of the random padding (param `r` from the paper).
+pub const DEFAULT_RANDOM_PADDING_LEN: usize = 512; // r
+/// The minimum length of the random padding (param `r` from the paper).
+pub const MIN_RANDOM_PADDING_LEN: usize = 128; // r min
+/// The default length of the hash (param `s` from the paper).
+pub const DEFAULT_HASH_LEN: usize = 256; // s
+/// The minimum length of the hash (param `s` from the paper).
+pub const MIN_HASH_LEN: usize = 128; // s min
 
 impl Default for SS1 {
     fn default() -> Self {
-        Self::new(DEFAULT_RANDOM_PADDING_LEN, DEFAULT_HASH_LEN).unwrap()
+        Self::builder().build().unwrap()
+    }
+}
+
+/// Builds a `SS1` scheme with custom parameters.
+///
+/// The parameters which are not set keep their default value,
+/// and the system's random number generator is used unless another one is given.
+pub struct SS1Builder {
+    parameters: Parameters,
+    random: Option<Box<SecureRandom>>,
+    sign_shares: bool,
+    verify_signatures: bool,
+    signature_hash_algorithm: HashAlgorithm,
+}
+
+impl fmt::Debug for SS1Builder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SS1Builder")
+            .field("parameters", &self.parameters)
+            .field("sign_shares", &self.sign_shares)
+            .field("verify_signatures", &self.verify_signatures)
+            .field("signature_hash_algorithm", &self.signature_hash_algorithm)
+            .finish()
+    }
+}
+
+impl SS1Builder {
+    /// Sets the length in bytes of the random padding (param `r` from the paper),
+    /// which must be at least `MIN_RANDOM_PADDING_LEN`.
+    pub fn random_padding_len(mut self, random_padding_len: usize) -> Self {
+        self.parameters.random_padding_len = random_padding_len;
+        self
+    }
+
+    /// Sets the length in bytes of the hash (param `s` from the paper),
+    /// which must be at least `MIN_HASH_LEN`.
+    pub fn hash_len(mut self, hash_len: usize) -> Self {
+        self.parameters.hash_len = hash_len;
+        self
+    }
+
+    /// Sets the hash function used to compute the hash, and to derive the seed
+    /// of reproducible shares. It may be an extendable-output function, such as `SHAKE256`.
+    pub fn hash_algorithm(mut self, hash_algorithm: HashAlgorithm) -> Self {
+        self.parameters.hash_algorithm = hash_algorithm;
+        self
+    }
+
+    /// Sets whether every share is signed by the dealer, which it is not by default.
+    pub fn sign_shares(mut self, sign_shares: bool) -> Self {
+        self.sign_shares = sign_shares;
+        self
+    }
+
+    /// Sets whether recovery requires all the shares to be signed by the same dealer,
+    /// which it does not by default.
+    pub fn verify_signatures(mut self, verify_signatures: bool) -> Self {
+        self.verify_signatures = verify_signatures;
+        self
+    }
+
+    /// Sets the hash function on which the signatures of the shares are built,
+    /// which must be a fixed-length one, and defaults to SHA-512.
+    pub fn signature_hash_algorithm(mut self, signature_hash_algorithm: HashAlgorithm) -> Self {
+        self.signature_hash_algorithm = signature_hash_algorithm;
+        self
+    }
+
+    /// Sets all the parameters at once, such as those recorded in a share.
+    pub fn parameters(mut self, parameters: Parameters) -> Self {
+        self.parameters = parameters;
+        self
+    }
+
+    /// Sets the randomness source used to generate the random padding
+    /// when shares are not reproducible.
+    pub fn random(mut self, random: Box<SecureRandom>) -> Self {
+        self.random = Some(random);
+        self
+    }
+
+    /// Builds the scheme, or returns an `InvalidSS1Parameters` error
+    /// if the parameters are below their minimum value.
+    pub fn build(self) -> Result<SS1> {
+        validate_parameters(&self.parameters)?;
+
+        Ok(SS1 {
+            parameters: self.parameters,
+            random: self.random.unwrap_or_else(|| Box::new(SystemRandom::new())),
+            sign_shares: self.sign_shares,
+            verify_signatures: self.verify_signatures,
+            signature_hash_algorithm: self.signature_hash_algorithm,
+        })
     }
 }
 
+fn validate_parameters(parameters: &Parameters) -> Result<()> {
+    if parameters.random_padding_len < MIN_RANDOM_PADDING_LEN
+        || parameters.hash_len < MIN_HASH_LEN
+    {
+        bail!(ErrorKind::InvalidSS1Parameters(
+            parameters.random_padding_len,
+            parameters.hash_len,
+        ));
+    }
+
+    Ok(())
+}
+
+/// Checks that the given shares were all dealt with the same parameters,
+/// and that those are acceptable, and returns them.
+pub(crate) fn validate_share_parameters(shares: &[Share]) -> Result<Parameters> {
+    let mut ids = Vec::with_capacity(shares.len());
+    let mut parameters = None;
+
+    for share in shares {
+        match parameters {
+            Some(parameters) if parameters != share.parameters => {
+                bail!(ErrorKind::InconsistentSS1Parameters(
+                    share.id,
+                    share.parameters,
+                    ids,
+                    parameters
+                ))
+            }
+            _ => parameters = Some(share.parameters),
+        }
+        ids.push(share.id);
+    }
+
+    let parameters = parameters.ok_or(ErrorKind::EmptyShares)?;
+    validate_parameters(&parameters)?;
+
+    Ok(parameters)
+}
+
 impl SS1 {
     /// Constructs a new sharing scheme
     pub fn new(random_padding_len: usize, hash_len: usize) -> Result<Self> {
-        if random_padding_len < MIN_RANDOM_PADDING_LEN || hash_len < MIN_HASH_LEN {
-            bail!(ErrorKind::InvalidSS1Parameters(
-                random_padding_len,
-                hash_len,
-            ));
+        Self::builder()
+            .random_padding_len(random_padding_len)
+            .hash_len(hash_len)
+            .build()
+    }
+
+    /// Returns a builder for a sharing scheme, starting from the default parameters.
+    pub fn builder() -> SS1Builder {
+        SS1Builder {
+            parameters: Parameters::default(),
+            random: None,
+            sign_shares: false,
+            verify_signatures: false,
+            signature_hash_algorithm: DEFAULT_SIGNATURE_HASH_ALGORITHM,
         }
+    }
 
-        Ok(Self {
-            random_padding_len,
-            hash_len,
-        })
+    /// Returns the parameters of the scheme.
+    pub fn parameters(&self) -> Parameters {
+        self.parameters
+    }
+
+    /// Returns the length in bytes of the random padding (param `r` from the paper).
+    pub fn random_padding_len(&self) -> usize {
+        self.parameters.random_padding_len
+    }
+
+    /// Returns the length in bytes of the hash (param `s` from the paper).
+    pub fn hash_len(&self) -> usize {
+        self.parameters.hash_len
     }
 
     /// Split a secret following a given sharing `scheme`,
     /// with `threshold` being the number of shares necessary to recover the secret,
     /// and `shares_count` the total number of shares to be dealt.
+    /// The shares are signed if the scheme was built to sign them.
     pub fn split_secret(
         &self,
         threshold: u8,
@@ -136,6 +349,26 @@ impl SS1 {
         secret: &[u8],
         reproducibility: Reproducibility,
         metadata: &Option<MetaData>,
+    ) -> Result<Vec<Share>> {
+        self.split_secret_signing(
+            threshold,
+            shares_count,
+            secret,
+            reproducibility,
+            metadata,
+            self.sign_shares,
+        )
+    }
+
+    /// Split a secret as `split_secret` does, signing the shares if `sign_shares` is set.
+    fn split_secret_signing(
+        &self,
+        threshold: u8,
+        shares_count: u8,
+        secret: &[u8],
+        reproducibility: Reproducibility,
+        metadata: &Option<MetaData>,
+        sign_shares: bool,
     ) -> Result<Vec<Share>> {
         let (threshold, shares_count) = validate_share_count(threshold, shares_count)?;
         let secret_len = secret.len();
@@ -149,25 +382,36 @@ impl SS1 {
 
         let random_padding = self.generate_random_padding(reproducibility, secret, metadata)?;
 
-        let mut vol_hash = VOLHash::new(&SHA256);
+        let randomness_len =
+            random_bytes_count(threshold, secret.len() + self.random_padding_len());
+        let total_hash_len = self.hash_len() + randomness_len;
+
+        let mut vol_hash = VOLHash::new(self.parameters.hash_algorithm, total_hash_len);
         vol_hash.process(&[0]);
         vol_hash.process(&[threshold, shares_count]);
         vol_hash.process(secret);
         vol_hash.process(&random_padding);
 
-        let randomness_len = random_bytes_count(threshold, secret.len() + self.random_padding_len);
-        let total_hash_len = self.hash_len + randomness_len;
         let mut full_hash = vec![0; total_hash_len];
-
         vol_hash.finish(&mut full_hash);
-        let (hash, randomness) = full_hash.split_at(self.hash_len);
+        let (hash, randomness) = full_hash.split_at(self.hash_len());
+
+        // The share-set identifier is derived from the hash, so that
+        // reproducible splits also yield the same identifier.
+        let share_set_id = ShareSetId::from_bytes(&hash[..SHARE_SET_ID_LEN])?;
 
         let underlying = ThSS::new(Box::new(FixedRandom::new(randomness.to_vec())));
 
         let message = [secret, &random_padding].concat();
-        let shares = underlying.split_secret(threshold, shares_count, &message, metadata)?;
+        let shares = underlying.split_secret_with_share_set_id(
+            threshold,
+            shares_count,
+            &message,
+            metadata,
+            Some(share_set_id),
+        )?;
 
-        let res = shares
+        let mut res = shares
             .into_iter()
             .map(|share| Share {
                 id: share.id,
@@ -176,8 +420,15 @@ impl SS1 {
                 data: share.data,
                 hash: hash.to_vec(),
                 metadata: share.metadata.clone(),
+                share_set_id: share.share_set_id,
+                parameters: self.parameters,
+                signature: None,
             })
-            .collect();
+            .collect::<Vec<_>>();
+
+        if sign_shares {
+            signature::sign_shares(&mut res, self.signature_hash_algorithm)?;
+        }
 
         Ok(res)
     }
@@ -190,23 +441,23 @@ impl SS1 {
     ) -> Result<Vec<u8>> {
         match reproducibility {
             Reproducibility::None => {
-                let rng = SystemRandom::new();
-                let mut result = vec![0u8; self.random_padding_len];
-                rng.fill(&mut result)
+                let mut result = vec![0u8; self.random_padding_len()];
+                self.random
+                    .fill(&mut result)
                     .chain_err(|| ErrorKind::CannotGenerateRandomNumbers)?;
                 Ok(result)
             }
             Reproducibility::Reproducible => {
                 let seed = self.generate_seed(DEFAULT_PRESEED, secret, metadata);
                 let mut rng = ChaChaRng::from_seed(&seed);
-                let mut result = vec![0u8; self.random_padding_len];
+                let mut result = vec![0u8; self.random_padding_len()];
                 rng.fill_bytes(result.as_mut_slice());
                 Ok(result)
             }
             Reproducibility::Seeded(preseed) => {
                 let seed = self.generate_seed(&preseed, secret, metadata);
                 let mut rng = ChaChaRng::from_seed(&seed);
-                let mut result = vec![0u8; self.random_padding_len];
+                let mut result = vec![0u8; self.random_padding_len()];
                 rng.fill_bytes(result.as_mut_slice());
                 Ok(result)
             }
@@ -217,38 +468,66 @@ impl SS1 {
     /// Generate a seed of 8 32-bits word for the ChaCha20 PRNG by hashing
     /// together the preseed, secret, and metadata, in order to obtain a salt
     /// for performing HKDF over the preseed.
+    ///
+    /// An extendable-output function is used as a key derivation function
+    /// on its own, and directly outputs the seed.
     fn generate_seed(
         &self,
         preseed: &[u8],
         secret: &[u8],
         metadata: &Option<MetaData>,
     ) -> Vec<u32> {
-        let mut ctx = Context::new(&SHA256);
-        ctx.update(preseed);
-        ctx.update(secret);
+        let hash_algorithm = self.parameters.hash_algorithm;
+
+        let mut hasher = Hasher::new(hash_algorithm);
+        hasher.update(preseed);
+        hasher.update(secret);
         for md in metadata {
-            md.hash_into(&mut ctx);
+            md.hash_into(&mut hasher);
         }
-        let preseed_hash = ctx.finish();
 
-        let salt = hmac::SigningKey::new(&SHA256, &[]);
         let mut seed_bytes = vec![0u8; 32];
-        hkdf::extract_and_expand(&salt, preseed_hash.as_ref(), &[], &mut seed_bytes);
+        match hash_algorithm.digest_algorithm() {
+            Some(digest_algorithm) => {
+                let mut preseed_hash = vec![0u8; digest_algorithm.output_len];
+                hasher.finish(&mut preseed_hash);
+
+                let salt = hmac::SigningKey::new(digest_algorithm, &[]);
+                hkdf::extract_and_expand(&salt, &preseed_hash, &[], &mut seed_bytes);
+            }
+            None => hasher.finish(&mut seed_bytes),
+        }
 
         // We can safely call `utils::slice_u8_to_slice_u32` because
-        // the `digest` produced with `SHA256` is 256 bits long, as is
-        // `seed_bytes`, and the latter can thus be represented both as a
+        // `seed_bytes` is 256 bits long, and can thus be represented both as a
         // slice of 32 bytes or as a slice of 8 32-bit words.
         utils::slice_u8_to_slice_u32(&seed_bytes).to_vec()
     }
 
-    /// Recover the secret from the given set of shares
+    /// Recover the secret from the given set of shares.
+    /// If the scheme was built to verify signatures, all the shares must be signed
+    /// by the same dealer.
+    ///
+    /// The secret is recovered with the parameters recorded in the shares,
+    /// which must all agree, rather than with the parameters of this scheme.
     pub fn recover_secret(
         &self,
         shares: &[Share],
+    ) -> Result<(Vec<u8>, AccessStructure, Option<MetaData>)> {
+        self.recover_secret_verifying(shares, self.verify_signatures)
+    }
+
+    /// Recover the secret as `recover_secret` does, verifying the signatures of the shares
+    /// if `verify_signatures` is set.
+    fn recover_secret_verifying(
+        &self,
+        shares: &[Share],
+        verify_signatures: bool,
     ) -> Result<(Vec<u8>, AccessStructure, Option<MetaData>)> {
         let shares = shares.to_vec();
-        validate_shares(&shares)?;
+        validate_signed_shares(&shares, verify_signatures)?;
+        let parameters = validate_share_parameters(&shares)?;
+        let scheme = Self::builder().parameters(parameters).build()?;
 
         let underlying_shares = shares
             .iter()
@@ -258,18 +537,21 @@ impl SS1 {
                 shares_count: share.shares_count,
                 data: share.data.clone(),
                 metadata: share.metadata.clone(),
+                share_set_id: share.share_set_id,
+                signature: None,
             })
             .collect::<Vec<_>>();
 
         let underlying = ThSS::default();
         let (mut secret, _, metadata) = underlying.recover_secret(&underlying_shares)?;
-        let secret_len = secret.len() - self.random_padding_len;
+        if secret.len() <= scheme.random_padding_len() {
+            bail!(ErrorKind::InconsistentShares);
+        }
+        let secret_len = secret.len() - scheme.random_padding_len();
         let random_padding = secret.split_off(secret_len);
         // `secret` nows holds the secret
 
-        let sub_scheme = Self::new(self.random_padding_len, self.hash_len)?;
-
-        let test_shares = sub_scheme.split_secret(
+        let test_shares = scheme.split_secret(
             shares[0].threshold,
             shares[0].shares_count,
             &secret,
@@ -305,14 +587,57 @@ impl SS1 {
         let matching_shares = shares.iter().zip(relevant_test_shares);
 
         for (share, test_share) in matching_shares {
-            if share != test_share {
-                bail!(ErrorKind::MismatchingShares(
-                    share.clone(),
-                    test_share.clone(),
-                ));
+            let mut test_share = test_share.clone();
+            // Shares dealt before the introduction of share-set identifiers do not carry one.
+            if share.share_set_id.is_none() {
+                test_share.share_set_id = None;
+            }
+            // Signatures are checked separately, since they are not reproducible.
+            test_share.signature = share.signature.clone();
+            if *share != test_share {
+                bail!(ErrorKind::MismatchingShares(share.clone(), test_share));
             }
         }
 
         Ok(())
     }
+
+    /// Parses a pile of shares coming from several splits, sorts them by share set,
+    /// and recovers the secret of every set which holds enough shares,
+    /// verifying their signatures if the scheme was built to.
+    pub fn sort_shares(
+        &self,
+        shares: &[String],
+    ) -> SortedShares<(Vec<u8>, AccessStructure, Option<MetaData>)> {
+        share_set::sort_shares(shares, |raw| Share::from_string(raw), |shares| {
+            self.recover_secret(&shares)
+        })
+    }
+}
+
+impl SecretSharingScheme for SS1 {
+    type Share = Share;
+
+    fn kind(&self) -> SchemeKind {
+        SchemeKind::SS1
+    }
+
+    /// Splits the secret without any metadata, nor reproducibility.
+    /// Use `SS1::split_secret` to deal reproducible shares.
+    fn split(&self, k: u8, n: u8, secret: &[u8], sign_shares: bool) -> Result<Vec<Share>> {
+        self.split_secret_signing(k, n, secret, Reproducibility::none(), &None, sign_shares)
+    }
+
+    fn recover(&self, shares: &[Share], verify_signatures: bool) -> Result<Vec<u8>> {
+        self.recover_secret_verifying(shares, verify_signatures)
+            .map(|(secret, _, _)| secret)
+    }
+
+    fn parse_share(&self, raw: &str) -> Result<Share> {
+        Share::from_string(raw)
+    }
+
+    fn format_share(&self, share: &Share) -> String {
+        share.clone().into_string()
+    }
 }
@@ -1,8 +1,10 @@
-use super::{MetaData, Share};
-use dss::format::{format_share_protobuf, parse_share_protobuf};
+use super::{HashAlgorithm, MetaData, Parameters, Share};
+use dss::format::{format_share_protobuf, parse_share_protobuf, set_signature_proto,
+                  take_signature_proto};
 use dss::utils::{btreemap_to_hashmap, hashmap_to_btreemap};
 use errors::*;
-use proto::dss::{MetaDataProto, ShareProto};
+use proto::dss::{HashAlgorithmProto, MetaDataProto, ShareProto};
+use share_set::ShareSetId;
 
 pub(crate) fn share_to_string(share: Share) -> String {
     let proto = share_to_protobuf(share);
@@ -21,6 +23,8 @@ pub(crate) fn share_from_string(raw: &str) -> Result<Share> {
     let i = proto.get_id() as u8;
     let k = proto.get_threshold() as u8;
     let n = proto.get_shares_count() as u8;
+    let share_set_id = ShareSetId::from_proto(proto.get_share_set_id())?;
+    let signature = take_signature_proto(&mut proto);
 
     if k < 1 || i < 1 {
         bail! {
@@ -38,13 +42,19 @@ pub(crate) fn share_from_string(raw: &str) -> Result<Share> {
         }
     }
 
+    let hash = proto.take_hash();
+    let parameters = parameters_from_proto(&proto, hash.len())?;
+
     let share = Share {
         id: i,
         threshold: k,
         shares_count: n,
         data: proto.take_data(),
-        hash: proto.take_hash(),
+        hash,
         metadata: metadata_proto,
+        share_set_id,
+        parameters,
+        signature,
     };
 
     Ok(share)
@@ -58,6 +68,17 @@ pub(crate) fn share_to_protobuf(share: Share) -> ShareProto {
     proto.set_shares_count(share.shares_count.into());
     proto.set_data(share.data);
     proto.set_hash(share.hash);
+    proto.set_random_padding_len(share.parameters.random_padding_len as u32);
+    proto.set_hash_len(share.parameters.hash_len as u32);
+    proto.set_hash_algorithm(hash_algorithm_to_proto(share.parameters.hash_algorithm));
+
+    if let Some(share_set_id) = share.share_set_id {
+        proto.set_share_set_id(share_set_id.as_bytes().to_vec());
+    }
+
+    if let Some(signature) = share.signature {
+        set_signature_proto(&mut proto, signature);
+    }
 
     if let Some(meta_data) = share.metadata {
         let metadata_proto = metadata_to_proto(meta_data);
@@ -67,6 +88,48 @@ pub(crate) fn share_to_protobuf(share: Share) -> ShareProto {
     proto
 }
 
+/// Shares dealt before the parameters were recorded in them
+/// leave them unset, and were dealt with the default ones.
+fn parameters_from_proto(proto: &ShareProto, hash_len: usize) -> Result<Parameters> {
+    let mut parameters = Parameters::default();
+
+    if proto.get_random_padding_len() != 0 {
+        parameters.random_padding_len = proto.get_random_padding_len() as usize;
+    }
+    if proto.get_hash_len() != 0 {
+        parameters.hash_len = proto.get_hash_len() as usize;
+    }
+    parameters.hash_algorithm = hash_algorithm_from_proto(proto.get_hash_algorithm());
+
+    if parameters.hash_len != hash_len {
+        bail! {
+            ErrorKind::ShareParsingError(
+                format!("Found a hash of {} bytes, while the share records s = {}.", hash_len, parameters.hash_len),
+            )
+        }
+    }
+
+    Ok(parameters)
+}
+
+fn hash_algorithm_to_proto(hash_algorithm: HashAlgorithm) -> HashAlgorithmProto {
+    match hash_algorithm {
+        HashAlgorithm::SHA256 => HashAlgorithmProto::SHA256,
+        HashAlgorithm::SHA384 => HashAlgorithmProto::SHA384,
+        HashAlgorithm::SHA512 => HashAlgorithmProto::SHA512,
+        HashAlgorithm::SHAKE256 => HashAlgorithmProto::SHAKE256,
+    }
+}
+
+fn hash_algorithm_from_proto(proto: HashAlgorithmProto) -> HashAlgorithm {
+    match proto {
+        HashAlgorithmProto::SHA256 => HashAlgorithm::SHA256,
+        HashAlgorithmProto::SHA384 => HashAlgorithm::SHA384,
+        HashAlgorithmProto::SHA512 => HashAlgorithm::SHA512,
+        HashAlgorithmProto::SHAKE256 => HashAlgorithm::SHAKE256,
+    }
+}
+
 fn metadata_to_proto(meta_data: MetaData) -> MetaDataProto {
     let mut proto = MetaDataProto::new();
     proto.set_tags(btreemap_to_hashmap(meta_data.tags));
@@ -1,12 +1,57 @@
+use std::fmt;
+
+use base64;
+
+use super::scheme::{DEFAULT_HASH_LEN, DEFAULT_RANDOM_PADDING_LEN};
 use super::serialize::{share_from_string, share_to_string};
+use dss::format::format_share_for_signing;
+use dss::signature::{self, SignableShare, Signature};
 use errors::*;
-use share::IsShare;
+use share::{IsShare, IsSignedShare};
+use share_set::ShareSetId;
 
 pub use dss::metadata::MetaData;
+pub use hash::HashAlgorithm;
+
+/// The parameters of the `SS1` scheme which dealt a share,
+/// which are needed to recover the secret from it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
+pub struct Parameters {
+    /// The length in bytes of the random padding (param `r` from the paper)
+    pub random_padding_len: usize,
+    /// The length in bytes of the hash (param `s` from the paper)
+    pub hash_len: usize,
+    /// The hash function used to commit to the secret and to derive the randomness
+    /// of the underlying ThSS scheme. Shares dealt before the algorithm was recorded
+    /// in them used `SHA256`.
+    pub hash_algorithm: HashAlgorithm,
+}
+
+impl Default for Parameters {
+    fn default() -> Self {
+        Parameters {
+            random_padding_len: DEFAULT_RANDOM_PADDING_LEN,
+            hash_len: DEFAULT_HASH_LEN,
+            hash_algorithm: HashAlgorithm::SHA256,
+        }
+    }
+}
+
+impl fmt::Display for Parameters {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "r = {}, s = {}, hash = {}",
+            self.random_padding_len, self.hash_len, self.hash_algorithm
+        )
+    }
+}
 
 /// A share identified by an `id`, a threshold `k`, a number of total shares `n`,
 /// the `data` held in the share, and the share's `metadata`.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
 pub struct Share {
     /// The identifier of the share (varies between 1 and n where n is the total number of generated shares)
     pub id: u8,
@@ -15,11 +60,21 @@ pub struct Share {
     /// The total number of shares that have been dealt
     pub shares_count: u8,
     /// The share data itself
+    #[cfg_attr(feature = "serialization", serde(with = "::serialization::base64_bytes"))]
     pub data: Vec<u8>,
     /// The hash value common to the whole deal
+    #[cfg_attr(feature = "serialization", serde(with = "::serialization::base64_bytes"))]
     pub hash: Vec<u8>,
     /// The metadata associated with this share
     pub metadata: Option<MetaData>,
+    /// The identifier of the share set this share belongs to, if any
+    pub share_set_id: Option<ShareSetId>,
+    /// The parameters of the scheme which dealt this share,
+    /// which are the default ones for shares serialized before they were recorded
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub parameters: Parameters,
+    /// The signature of the dealer, if the share is signed
+    pub signature: Option<Signature>,
 }
 
 impl Share {
@@ -36,6 +91,35 @@ impl Share {
     pub fn from_string(raw: &str) -> Result<Self> {
         share_from_string(raw)
     }
+
+    /// Returns whether this share is signed.
+    pub fn is_signed(&self) -> bool {
+        self.signature.is_some()
+    }
+}
+
+impl SignableShare for Share {
+    fn format_for_signing(&self) -> Vec<u8> {
+        let common = format_share_for_signing(
+            "ss1",
+            self.threshold,
+            self.shares_count,
+            self.id,
+            self.share_set_id,
+            &self.data,
+            &self.metadata,
+        );
+        format!(
+            "{}-{}-{}",
+            common,
+            base64::encode_config(&self.hash, base64::STANDARD_NO_PAD),
+            self.parameters
+        ).into_bytes()
+    }
+
+    fn set_signature(&mut self, signature: Signature) {
+        self.signature = Some(signature);
+    }
 }
 
 impl IsShare for Share {
@@ -54,4 +138,28 @@ impl IsShare for Share {
     fn get_shares_count(&self) -> Option<u8> {
         Some(self.shares_count)
     }
+
+    fn get_share_set_id(&self) -> Option<ShareSetId> {
+        self.share_set_id
+    }
+}
+
+impl IsSignedShare for Share {
+    type Signature = Option<Signature>;
+
+    fn is_signed(&self) -> bool {
+        self.signature.is_some()
+    }
+
+    fn get_signature(&self) -> &Self::Signature {
+        &self.signature
+    }
+
+    fn verify_signatures(shares: &[Self]) -> Result<()> {
+        let messages = shares
+            .iter()
+            .map(|share| (share.id, share.format_for_signing(), share.signature.as_ref()))
+            .collect::<Vec<_>>();
+        signature::verify_signatures(&messages)
+    }
 }
@@ -1,5 +1,4 @@
-use gf256::Gf256;
-use poly::Poly;
+use field::{Gf256, Poly};
 
 /// Encode the given `secret` using the `ThSS[N].Share` algorithm described
 /// in the *New directions in Secret Sharing* paper.
@@ -28,10 +27,7 @@ pub(crate) fn encode_secret(secret: &[u8], k: u8, share_id: u8, rands: &[u8]) ->
 ///
 /// Reference: Figure 7 from the *New Directions in Secret Sharing* paper.
 pub(crate) fn encode_secret_byte(m: u8, j: u8, poly: &Poly) -> u8 {
-    let mut acc = Gf256::from_byte(m);
-    for (l, &r) in poly.coeffs.iter().enumerate() {
-        let s = Gf256::from_byte(j).pow(l as u8 + 1);
-        acc += r * s;
-    }
-    acc.to_byte()
+    // The coefficients of `poly` start at degree 1, hence the extra factor of `j`.
+    let j = Gf256::from_byte(j);
+    (Gf256::from_byte(m) + j * poly.evaluate_at(j)).to_byte()
 }
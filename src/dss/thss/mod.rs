@@ -13,6 +13,7 @@
 //! **Repro**    | No | Share reproducible: The scheme can produce shares in a deterministic way.
 
 use errors::*;
+use share_set::SortedShares;
 
 mod encode;
 mod serialize;
@@ -21,12 +22,15 @@ mod share;
 pub use self::share::*;
 
 mod scheme;
-pub(crate) use self::scheme::ThSS;
+pub use self::scheme::{ThSS, ThSSBuilder};
 
 use dss::AccessStructure;
 
 /// Performs threshold k-out-of-n secret sharing using the `ThSS` scheme.
 ///
+/// The shares are not signed, use a scheme built with `ThSS::builder().sign_shares(true)`
+/// to sign them.
+///
 /// # Examples
 ///
 /// ```rust
@@ -67,6 +71,8 @@ pub fn split_secret(
 /// Recovers the secret from a k-out-of-n secret sharing scheme (`ThSS`).
 ///
 /// At least `k` distinct shares need to be provided to recover the secret.
+/// Signatures are not verified, use a scheme built with
+/// `ThSS::builder().verify_signatures(true)` to verify them.
 ///
 /// # Examples
 ///
@@ -99,6 +105,56 @@ pub fn recover_secret(shares: &[Share]) -> Result<(Vec<u8>, AccessStructure, Opt
     ThSS::default().recover_secret(shares)
 }
 
+/// Recovers the secret from a k-out-of-n secret sharing scheme (`ThSS`), retrying with
+/// other subsets of `k` shares should some of the shares be corrupted.
+///
+/// Along with the secret, returns the identifiers of the corrupted shares,
+/// and the offsets of the bytes at which they disagree with the secret.
+/// See `ThSS::recover_secret_with_retries` for details.
+///
+/// # Examples
+///
+/// ```rust
+/// use rusty_secrets::dss::thss;
+///
+/// let mut shares = thss::split_secret(2, 5, b"secret", &None).unwrap();
+/// shares[0].data[3] ^= 1;
+///
+/// let (secret, _, _, culprits) = thss::recover_secret_with_retries(&shares).unwrap();
+/// assert_eq!(secret, b"secret");
+/// assert_eq!(culprits, vec![(1, vec![3])]);
+/// ```
+pub fn recover_secret_with_retries(
+    shares: &[Share],
+) -> Result<(Vec<u8>, AccessStructure, Option<MetaData>, Vec<(u8, Vec<usize>)>)> {
+    ThSS::default().recover_secret_with_retries(shares)
+}
+
+/// Parses a pile of shares coming from several splits, sorts them by share set,
+/// reports how many shares each set is missing, and recovers the secret of every
+/// set for which enough shares were provided (`ThSS`).
+///
+/// # Examples
+///
+/// ```rust
+/// use rusty_secrets::dss::thss;
+///
+/// let shares = thss::split_secret(3, 5, b"secret", &None).unwrap();
+/// let raws = shares
+///     .into_iter()
+///     .take(2)
+///     .map(|share| share.into_string())
+///     .collect::<Vec<_>>();
+///
+/// let sorted = thss::sort_shares(&raws);
+/// assert_eq!(sorted.sets[0].missing, 1);
+/// ```
+pub fn sort_shares(
+    shares: &[String],
+) -> SortedShares<(Vec<u8>, AccessStructure, Option<MetaData>)> {
+    ThSS::default().sort_shares(shares)
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -119,4 +175,56 @@ mod tests {
         assert_eq!(None, metadata);
     }
 
+    #[test]
+    fn extra_shares_are_checked_at_their_own_id() {
+        let secret = "Hello, World!".to_string().into_bytes();
+
+        let shares = split_secret(3, 6, &secret, &None).unwrap();
+        let picked = vec![
+            shares[4].clone(),
+            shares[0].clone(),
+            shares[2].clone(),
+            shares[5].clone(),
+        ];
+
+        let (recovered, _, _) = recover_secret(&picked).unwrap();
+        assert_eq!(secret, recovered);
+    }
+
+    #[test]
+    fn corrupted_shares_are_reported() {
+        let secret = "Hello, World!".to_string().into_bytes();
+
+        let mut shares = split_secret(2, 4, &secret, &None).unwrap();
+        shares[3].data[1] ^= 1;
+        shares[3].data[5] ^= 1;
+
+        match *recover_secret(&shares).unwrap_err().kind() {
+            ErrorKind::CorruptedShares(ref culprits) => {
+                assert_eq!(*culprits, vec![(4, vec![1, 5])]);
+            }
+            ref kind => panic!("Unexpected error: {:?}", kind),
+        }
+    }
+
+    #[test]
+    fn corrupted_shares_are_skipped_when_retrying() {
+        let secret = "Hello, World!".to_string().into_bytes();
+
+        let mut shares = split_secret(3, 7, &secret, &None).unwrap();
+        shares[0].data[0] ^= 1;
+        shares[2].data[7] ^= 1;
+
+        let (recovered, _, _, culprits) = recover_secret_with_retries(&shares).unwrap();
+        assert_eq!(secret, recovered);
+        assert_eq!(culprits, vec![(1, vec![0]), (3, vec![7])]);
+
+        // Too many corrupted shares to single them out.
+        shares[1].data[0] ^= 1;
+        match *recover_secret_with_retries(&shares).unwrap_err().kind() {
+            ErrorKind::CorruptedShares(_) => {}
+            ref kind => panic!("Unexpected error: {:?}", kind),
+        }
+    }
+
 }
@@ -1,14 +1,19 @@
 //! Simple threshold secret sharing scheme
 
 use std::fmt;
+use std::iter;
 
 use ring::rand::{SecureRandom, SystemRandom};
 
 use dss::random::{random_bytes, random_bytes_count, MAX_MESSAGE_SIZE};
+use dss::signature;
 use errors::*;
-use gf256::Gf256;
+use field::Gf256;
+use hash::{HashAlgorithm, DEFAULT_SIGNATURE_HASH_ALGORITHM};
 use lagrange;
-use share::validation::{validate_share_count, validate_shares};
+use scheme::{SchemeKind, SecretSharingScheme};
+use share::validation::{validate_share_count, validate_signed_shares};
+use share_set::{self, ShareSetId, SortedShares};
 
 use super::AccessStructure;
 use super::encode::encode_secret;
@@ -19,40 +24,190 @@ use super::share::*;
 /// the sharing scheme on message larger than that.
 const MAX_SECRET_SIZE: usize = MAX_MESSAGE_SIZE;
 
+/// The number of subsets of `k` shares `recover_secret_with_retries` interpolates
+/// the secret from, at most, before giving up on singling out the corrupted shares.
+/// Trying all of the `C(n, k)` subsets quickly gets out of hand as `n` grows.
+const MAX_RECOVERY_ATTEMPTS: usize = 1 << 12;
+
 /// A simple threshold sharing scheme
-#[allow(missing_debug_implementations)]
-pub(crate) struct ThSS {
+///
+/// `ThSS::default()` draws its randomness from the system's random number generator,
+/// and neither signs shares nor verifies their signatures, while `ThSS::builder()` allows
+/// using another source of randomness, and signing shares, with SHA-512 unless another
+/// hash function is chosen for the signatures.
+///
+/// # Examples
+///
+/// ```
+/// # extern crate ring;
+/// # extern crate rusty_secrets;
+/// use ring::rand::SystemRandom;
+/// use rusty_secrets::dss::thss::ThSS;
+///
+/// # fn main() {
+/// let scheme = ThSS::builder()
+///     .random(Box::new(SystemRandom::new()))
+///     .sign_shares(true)
+///     .verify_signatures(true)
+///     .build();
+///
+/// let shares = scheme.split_secret(2, 3, b"Hello", &None).unwrap();
+/// let (secret, _, _) = scheme.recover_secret(&shares[..2]).unwrap();
+///
+/// assert_eq!(secret, b"Hello");
+/// assert!(shares[0].is_signed());
+/// # }
+/// ```
+pub struct ThSS {
     /// The randomness source
     random: Box<SecureRandom>,
+    /// Whether the dealt shares are signed
+    sign_shares: bool,
+    /// Whether the signatures of the shares are verified during recovery
+    verify_signatures: bool,
+    /// The hash function on which the signatures of the shares are built
+    signature_hash_algorithm: HashAlgorithm,
 }
 
 impl fmt::Debug for ThSS {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "ThSS")
+        f.debug_struct("ThSS")
+            .field("sign_shares", &self.sign_shares)
+            .field("verify_signatures", &self.verify_signatures)
+            .field("signature_hash_algorithm", &self.signature_hash_algorithm)
+            .finish()
     }
 }
 
 impl Default for ThSS {
     fn default() -> Self {
-        Self::new(Box::new(SystemRandom::new()))
+        Self::builder().build()
+    }
+}
+
+/// Builds a `ThSS` scheme with a custom source of randomness,
+/// or which signs shares, or verifies their signatures.
+pub struct ThSSBuilder {
+    random: Option<Box<SecureRandom>>,
+    sign_shares: bool,
+    verify_signatures: bool,
+    signature_hash_algorithm: HashAlgorithm,
+}
+
+impl fmt::Debug for ThSSBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ThSSBuilder")
+            .field("sign_shares", &self.sign_shares)
+            .field("verify_signatures", &self.verify_signatures)
+            .field("signature_hash_algorithm", &self.signature_hash_algorithm)
+            .finish()
+    }
+}
+
+impl ThSSBuilder {
+    /// Sets the randomness source used to generate the shares.
+    pub fn random(mut self, random: Box<SecureRandom>) -> Self {
+        self.random = Some(random);
+        self
+    }
+
+    /// Sets whether every share is signed by the dealer, which it is not by default.
+    pub fn sign_shares(mut self, sign_shares: bool) -> Self {
+        self.sign_shares = sign_shares;
+        self
+    }
+
+    /// Sets whether recovery requires all the shares to be signed by the same dealer,
+    /// which it does not by default.
+    pub fn verify_signatures(mut self, verify_signatures: bool) -> Self {
+        self.verify_signatures = verify_signatures;
+        self
+    }
+
+    /// Sets the hash function on which the signatures of the shares are built,
+    /// which must be a fixed-length one, and defaults to SHA-512.
+    pub fn signature_hash_algorithm(mut self, signature_hash_algorithm: HashAlgorithm) -> Self {
+        self.signature_hash_algorithm = signature_hash_algorithm;
+        self
+    }
+
+    /// Builds the scheme, using the system's random number generator
+    /// if no other source of randomness was given.
+    pub fn build(self) -> ThSS {
+        ThSS {
+            random: self.random
+                .unwrap_or_else(|| Box::new(SystemRandom::new())),
+            sign_shares: self.sign_shares,
+            verify_signatures: self.verify_signatures,
+            signature_hash_algorithm: self.signature_hash_algorithm,
+        }
     }
 }
 
 impl ThSS {
     /// Constructs a new sharing scheme
     pub fn new(random: Box<SecureRandom>) -> Self {
-        Self { random }
+        Self::builder().random(random).build()
+    }
+
+    /// Returns a builder for a sharing scheme.
+    pub fn builder() -> ThSSBuilder {
+        ThSSBuilder {
+            random: None,
+            sign_shares: false,
+            verify_signatures: false,
+            signature_hash_algorithm: DEFAULT_SIGNATURE_HASH_ALGORITHM,
+        }
     }
 
     /// Split a secret following a given sharing `scheme`,
     /// with `threshold` being the number of shares necessary to recover the secret,
     /// and `shares_count` the total number of shares to be dealt.
+    /// The shares are signed if the scheme was built to sign them.
     pub fn split_secret(
         &self,
         threshold: u8,
         shares_count: u8,
         secret: &[u8],
         metadata: &Option<MetaData>,
+    ) -> Result<Vec<Share>> {
+        self.split_secret_signing(threshold, shares_count, secret, metadata, self.sign_shares)
+    }
+
+    /// Split a secret as `split_secret` does, signing the shares if `sign_shares` is set.
+    fn split_secret_signing(
+        &self,
+        threshold: u8,
+        shares_count: u8,
+        secret: &[u8],
+        metadata: &Option<MetaData>,
+        sign_shares: bool,
+    ) -> Result<Vec<Share>> {
+        let share_set_id = ShareSetId::random_secure(self.random.as_ref())?;
+        let mut shares = self.split_secret_with_share_set_id(
+            threshold,
+            shares_count,
+            secret,
+            metadata,
+            Some(share_set_id),
+        )?;
+
+        if sign_shares {
+            signature::sign_shares(&mut shares, self.signature_hash_algorithm)?;
+        }
+
+        Ok(shares)
+    }
+
+    /// Split a secret as `split_secret` does, but tag the shares with the given
+    /// share-set identifier instead of a random one.
+    pub(crate) fn split_secret_with_share_set_id(
+        &self,
+        threshold: u8,
+        shares_count: u8,
+        secret: &[u8],
+        metadata: &Option<MetaData>,
+        share_set_id: Option<ShareSetId>,
     ) -> Result<Vec<Share>> {
         let (threshold, shares_count) = validate_share_count(threshold, shares_count)?;
         let secret_len = secret.len();
@@ -77,6 +232,8 @@ impl ThSS {
                     shares_count,
                     data,
                     metadata: metadata.clone(),
+                    share_set_id,
+                    signature: None,
                 }
             })
             .collect();
@@ -84,50 +241,203 @@ impl ThSS {
         Ok(shares)
     }
 
-    /// Recover the secret from the given set of shares
+    /// Recover the secret from the given set of shares.
+    /// If the scheme was built to verify signatures, all the shares must be signed
+    /// by the same dealer.
+    ///
+    /// The secret is interpolated from the first `k` shares, and the remaining shares
+    /// are checked against it. Should some of them disagree, the identifiers of those
+    /// shares are reported, along with the offsets of the bytes at which they disagree.
     pub fn recover_secret(
         &self,
         shares: &[Share],
     ) -> Result<(Vec<u8>, AccessStructure, Option<MetaData>)> {
-        let shares = shares.to_vec();
-        let (threshold, cypher_len) = validate_shares(&shares)?;
-
-        let polys = (0..cypher_len)
-            .map(|i| {
-                let points = shares
-                    .iter()
-                    .take(threshold as usize)
-                    .map(|share| (Gf256::from_byte(share.id), Gf256::from_byte(share.data[i])))
-                    .collect::<Vec<_>>();
-
-                lagrange::interpolate(&points)
-            })
-            .collect::<Vec<_>>();
+        self.recover_secret_verifying(shares, self.verify_signatures)
+    }
+
+    /// Recover the secret as `recover_secret` does, verifying the signatures of the shares
+    /// if `verify_signatures` is set.
+    fn recover_secret_verifying(
+        &self,
+        shares: &[Share],
+        verify_signatures: bool,
+    ) -> Result<(Vec<u8>, AccessStructure, Option<MetaData>)> {
+        let (threshold, cypher_len) = validate_signed_shares(shares, verify_signatures)?;
 
-        for (i, poly) in polys.iter().enumerate() {
-            // Check remaining shares for consistency.
-            // See Figure 7 of the paper
-            let remaining_shares = shares.iter().enumerate().skip(threshold as usize);
+        let refs = shares.iter().collect::<Vec<_>>();
+        let (chosen, others) = refs.split_at(threshold as usize);
+        let (secret, culprits) = interpolate_secret(chosen, others, cypher_len);
+        if !culprits.is_empty() {
+            bail!(ErrorKind::CorruptedShares(culprits));
+        }
 
-            for (u, share) in remaining_shares {
-                let value = poly.evaluate_at(Gf256::from_byte(u as u8 + 1)).to_byte();
-                if value != share.data[i] {
-                    bail!(ErrorKind::InconsistentShares);
-                }
+        Ok((
+            secret,
+            access_structure(shares, threshold),
+            shares[0].metadata.clone(),
+        ))
+    }
+
+    /// Recover the secret from the given set of shares as `recover_secret` does, except
+    /// that should some shares disagree with the others, the secret is interpolated
+    /// from other subsets of `k` shares until the disagreeing shares can be singled out.
+    ///
+    /// This succeeds as long as at most `(n - k) / 2` of the `n` given shares are
+    /// corrupted, in which case the secret is uniquely determined by the others.
+    /// Along with the secret, the identifiers of the corrupted shares are returned,
+    /// with the offsets of the bytes at which they disagree with the secret.
+    ///
+    /// Note that every subset of `k` shares may have to be tried, which gets costly
+    /// when many more than `k` shares are given. The secret is thus interpolated from
+    /// 4096 subsets at most, after which the recovery fails as `recover_secret` does.
+    pub fn recover_secret_with_retries(
+        &self,
+        shares: &[Share],
+    ) -> Result<(Vec<u8>, AccessStructure, Option<MetaData>, Vec<(u8, Vec<usize>)>)> {
+        let (threshold, cypher_len) = validate_signed_shares(shares, self.verify_signatures)?;
+        let threshold_ = threshold as usize;
+        let max_culprits = (shares.len() - threshold_) / 2;
+
+        let mut first_culprits = None;
+        let mut indices = (0..threshold_).collect::<Vec<_>>();
+
+        for _ in 0..MAX_RECOVERY_ATTEMPTS {
+            let (chosen, others): (Vec<_>, Vec<_>) = shares
+                .iter()
+                .enumerate()
+                .partition(|&(i, _)| indices.contains(&i));
+            let chosen = chosen.into_iter().map(|(_, s)| s).collect::<Vec<_>>();
+            let others = others.into_iter().map(|(_, s)| s).collect::<Vec<_>>();
+
+            let (secret, culprits) = interpolate_secret(&chosen, &others, cypher_len);
+            if culprits.len() <= max_culprits {
+                return Ok((
+                    secret,
+                    access_structure(shares, threshold),
+                    chosen[0].metadata.clone(),
+                    culprits,
+                ));
+            }
+
+            if first_culprits.is_none() {
+                first_culprits = Some(culprits);
+            }
+            if !next_combination(&mut indices, shares.len()) {
+                break;
             }
         }
 
-        let metadata = shares[0].metadata.clone();
-        let secret = polys
-            .iter()
-            .map(|p| p.evaluate_at_zero().to_byte())
-            .collect();
+        bail!(ErrorKind::CorruptedShares(
+            first_culprits.unwrap_or_else(Vec::new)
+        ))
+    }
 
-        let access_structure = AccessStructure {
-            threshold: threshold,
-            shares_count: shares.first().unwrap().shares_count,
-        };
+    /// Parses a pile of shares coming from several splits, sorts them by share set,
+    /// and recovers the secret of every set which holds enough shares,
+    /// verifying their signatures if the scheme was built to.
+    pub fn sort_shares(
+        &self,
+        shares: &[String],
+    ) -> SortedShares<(Vec<u8>, AccessStructure, Option<MetaData>)> {
+        share_set::sort_shares(shares, |raw| Share::from_string(raw), |shares| {
+            self.recover_secret(&shares)
+        })
+    }
+}
+
+impl SecretSharingScheme for ThSS {
+    type Share = Share;
+
+    fn kind(&self) -> SchemeKind {
+        SchemeKind::ThSS
+    }
 
-        Ok((secret, access_structure, metadata))
+    /// Splits the secret without any metadata.
+    fn split(&self, k: u8, n: u8, secret: &[u8], sign_shares: bool) -> Result<Vec<Share>> {
+        self.split_secret_signing(k, n, secret, &None, sign_shares)
     }
+
+    fn recover(&self, shares: &[Share], verify_signatures: bool) -> Result<Vec<u8>> {
+        self.recover_secret_verifying(shares, verify_signatures)
+            .map(|(secret, _, _)| secret)
+    }
+
+    fn parse_share(&self, raw: &str) -> Result<Share> {
+        Share::from_string(raw)
+    }
+
+    fn format_share(&self, share: &Share) -> String {
+        share.clone().into_string()
+    }
+}
+
+/// Interpolates the secret from the `chosen` shares, and checks the `others` against it.
+/// Returns the secret along with the identifiers of the shares which disagree with it,
+/// and the offsets of the bytes at which they do.
+fn interpolate_secret(
+    chosen: &[&Share],
+    others: &[&Share],
+    cypher_len: usize,
+) -> (Vec<u8>, Vec<(u8, Vec<usize>)>) {
+    // The Lagrange coefficients mapping the bytes of the chosen shares to the secret,
+    // in the first row, and to the bytes each other share should hold, in the next ones.
+    // They only depend on the share identifiers, so are computed once for all the bytes.
+    let ids = chosen
+        .iter()
+        .map(|share| Gf256::from_byte(share.id))
+        .collect::<Vec<_>>();
+    let targets = iter::once(Gf256::zero())
+        .chain(others.iter().map(|share| Gf256::from_byte(share.id)))
+        .collect::<Vec<_>>();
+    let matrix = lagrange::basis_matrix(&ids, &targets);
+
+    let interpolate = |row: &[Gf256], i: usize| {
+        lagrange::interpolate_with(row, chosen.iter().map(|share| share.data[i]))
+    };
+
+    let secret = (0..cypher_len).map(|i| interpolate(&matrix[0], i)).collect();
+
+    // Check remaining shares for consistency.
+    // See Figure 7 of the paper
+    let culprits = others
+        .iter()
+        .zip(&matrix[1..])
+        .filter_map(|(share, row)| {
+            let offsets = (0..cypher_len)
+                .filter(|&i| interpolate(row, i) != share.data[i])
+                .collect::<Vec<_>>();
+
+            if offsets.is_empty() {
+                None
+            } else {
+                Some((share.id, offsets))
+            }
+        })
+        .collect();
+
+    (secret, culprits)
+}
+
+fn access_structure(shares: &[Share], threshold: u8) -> AccessStructure {
+    AccessStructure {
+        threshold: threshold,
+        shares_count: shares.first().unwrap().shares_count,
+    }
+}
+
+/// Advances `indices` to the next `k`-combination of `0..n` in lexicographic order,
+/// returning `false` once all of them have been enumerated.
+fn next_combination(indices: &mut [usize], n: usize) -> bool {
+    let k = indices.len();
+    let pivot = match (0..k).rev().find(|&i| indices[i] < n - k + i) {
+        Some(pivot) => pivot,
+        None => return false,
+    };
+
+    indices[pivot] += 1;
+    for i in pivot + 1..k {
+        indices[i] = indices[i - 1] + 1;
+    }
+
+    true
 }
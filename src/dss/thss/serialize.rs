@@ -1,8 +1,10 @@
 use super::{MetaData, Share};
-use dss::format::{format_share_protobuf, parse_share_protobuf};
+use dss::format::{format_share_protobuf, parse_share_protobuf, set_signature_proto,
+                  take_signature_proto};
 use dss::utils::{btreemap_to_hashmap, hashmap_to_btreemap};
 use errors::*;
 use proto::dss::{MetaDataProto, ShareProto};
+use share_set::ShareSetId;
 
 pub(crate) fn share_to_string(share: Share) -> String {
     let proto = share_to_protobuf(share);
@@ -21,6 +23,8 @@ pub(crate) fn share_from_string(raw: &str) -> Result<Share> {
     let i = proto.get_id() as u8;
     let k = proto.get_threshold() as u8;
     let n = proto.get_shares_count() as u8;
+    let share_set_id = ShareSetId::from_proto(proto.get_share_set_id())?;
+    let signature = take_signature_proto(&mut proto);
 
     if k < 1 || i < 1 {
         bail! {
@@ -44,6 +48,8 @@ pub(crate) fn share_from_string(raw: &str) -> Result<Share> {
         shares_count: n,
         data: proto.take_data(),
         metadata: metadata_proto,
+        share_set_id,
+        signature,
     };
 
     Ok(share)
@@ -57,6 +63,14 @@ pub(crate) fn share_to_protobuf(share: Share) -> ShareProto {
     proto.set_shares_count(share.shares_count.into());
     proto.set_data(share.data);
 
+    if let Some(share_set_id) = share.share_set_id {
+        proto.set_share_set_id(share_set_id.as_bytes().to_vec());
+    }
+
+    if let Some(signature) = share.signature {
+        set_signature_proto(&mut proto, signature);
+    }
+
     if let Some(meta_data) = share.metadata {
         let metadata_proto = metadata_to_proto(meta_data);
         proto.set_meta_data(metadata_proto);
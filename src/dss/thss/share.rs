@@ -1,12 +1,16 @@
 use super::serialize::{share_from_string, share_to_string};
+use dss::format::format_share_for_signing;
+use dss::signature::{self, SignableShare, Signature};
 use errors::*;
-use share::IsShare;
+use share::{IsShare, IsSignedShare};
+use share_set::ShareSetId;
 
 pub use dss::metadata::MetaData;
 
 /// A share identified by an `id`, a threshold `k`, a number of total shares `n`,
 /// the `data` held in the share, and the share's `metadata`.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
 pub struct Share {
     /// The identifier of the share (varies between 1 and n where n is the total number of generated shares)
     pub id: u8,
@@ -15,9 +19,14 @@ pub struct Share {
     /// The total number of shares that have been dealt
     pub shares_count: u8,
     /// The share data itself
+    #[cfg_attr(feature = "serialization", serde(with = "::serialization::base64_bytes"))]
     pub data: Vec<u8>,
     /// The metadata associated with this share
     pub metadata: Option<MetaData>,
+    /// The identifier of the share set this share belongs to, if any
+    pub share_set_id: Option<ShareSetId>,
+    /// The signature of the dealer, if the share is signed
+    pub signature: Option<Signature>,
 }
 
 impl Share {
@@ -34,6 +43,29 @@ impl Share {
     pub fn from_string(raw: &str) -> Result<Self> {
         share_from_string(raw)
     }
+
+    /// Returns whether this share is signed.
+    pub fn is_signed(&self) -> bool {
+        self.signature.is_some()
+    }
+}
+
+impl SignableShare for Share {
+    fn format_for_signing(&self) -> Vec<u8> {
+        format_share_for_signing(
+            "thss",
+            self.threshold,
+            self.shares_count,
+            self.id,
+            self.share_set_id,
+            &self.data,
+            &self.metadata,
+        ).into_bytes()
+    }
+
+    fn set_signature(&mut self, signature: Signature) {
+        self.signature = Some(signature);
+    }
 }
 
 impl IsShare for Share {
@@ -52,4 +84,28 @@ impl IsShare for Share {
     fn get_shares_count(&self) -> Option<u8> {
         Some(self.shares_count)
     }
+
+    fn get_share_set_id(&self) -> Option<ShareSetId> {
+        self.share_set_id
+    }
+}
+
+impl IsSignedShare for Share {
+    type Signature = Option<Signature>;
+
+    fn is_signed(&self) -> bool {
+        self.signature.is_some()
+    }
+
+    fn get_signature(&self) -> &Self::Signature {
+        &self.signature
+    }
+
+    fn verify_signatures(shares: &[Self]) -> Result<()> {
+        let messages = shares
+            .iter()
+            .map(|share| (share.id, share.format_for_signing(), share.signature.as_ref()))
+            .collect::<Vec<_>>();
+        signature::verify_signatures(&messages)
+    }
 }
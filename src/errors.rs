@@ -7,6 +7,10 @@ use std::fmt;
 
 #[cfg(feature = "dss")]
 use dss::ss1;
+use hash::HashAlgorithm;
+use sealing::KeyFingerprint;
+use sealing::request::RequestId;
+use share_set::ShareSetId;
 
 /// Minimum allowed number of shares (n)
 pub(crate) static MIN_SHARES: u8 = 2;
@@ -35,7 +39,7 @@ error_chain! {
             display("The secret is too long, maximum allowed size = {} bytes, got {} bytes", max, len)
         }
 
-        InvalidShareCountMax(nb_shares: u8, max: u8) {
+        InvalidShareCountMax(nb_shares: usize, max: u8) {
             description("Number of shares is too big")
             display("Number of shares must be smaller than or equal {}, got: {} shares.", max, nb_shares)
         }
@@ -114,6 +118,11 @@ error_chain! {
             display("Share mismatch during verification of secret recovery.")
         }
 
+        UnsupportedHashAlgorithm(algorithm: HashAlgorithm, reason: String) {
+            description("The hash algorithm cannot be used for this purpose")
+            display("The hash algorithm {} cannot be used for this purpose: {}", algorithm, reason)
+        }
+
         CannotGenerateRandomNumbers {
             description("Cannot generate random numbers")
             display("Cannot generate random numbers.")
@@ -134,11 +143,87 @@ error_chain! {
             display("The shares are inconsistent")
         }
 
+        CorruptedShares(culprits: Vec<(u8, Vec<usize>)>) {
+            description("Some shares disagree with the secret recovered from the others.")
+            display("The share identifier(s) {} disagree with the secret recovered from the other shares.", no_more_than_five(&culprits.iter().map(|&(id, _)| id).collect::<Vec<_>>()))
+        }
+
         InconsistentThresholds(id: u8, k_: u8, ids: Vec<u8>, k: u8) {
             description("The shares are incompatible with each other because they do not all have the same threshold.")
             display("The share identifier {} had k = {}, while k = {} was found for share identifier(s): {}.", id, k_, k, no_more_than_five(ids))
         }
 
+        #[cfg(feature = "dss")]
+        InconsistentSS1Parameters(id: u8, parameters_: ss1::Parameters, ids: Vec<u8>, parameters: ss1::Parameters) {
+            description("The shares are incompatible with each other because they were not all dealt with the same SS1 parameters.")
+            display("The share identifier {} was dealt with {}, while {} was found for share identifier(s): {}.", id, parameters_, parameters, no_more_than_five(ids))
+        }
+
+        InconsistentShareSets(id: u8, set_: ShareSetId, ids: Vec<u8>, set: ShareSetId) {
+            description("The shares are incompatible with each other because they do not all belong to the same share set.")
+            display("The share identifier {} belongs to share set {}, while share set {} was found for share identifier(s): {}.", id, set_, set, no_more_than_five(ids))
+        }
+
+        InvalidArmor(reason: String) {
+            description("The armored share could not be parsed")
+            display("The armored share could not be parsed: {}", reason)
+        }
+
+        ArmorChecksumMismatch(expected: u32, found: u32) {
+            description("The checksum of the armored share does not match its content")
+            display("The checksum of the armored share does not match its content: expected {:06x}, found {:06x}.", expected, found)
+        }
+
+        InvalidKey(reason: String) {
+            description("The key is invalid")
+            display("The key is invalid: {}", reason)
+        }
+
+        WrongRecipient(recipient: KeyFingerprint, key: KeyFingerprint) {
+            description("The share is sealed to another key")
+            display("The share is sealed to the key {}, not to the key {}.", recipient, key)
+        }
+
+        CannotOpenShare {
+            description("The sealed share could not be opened")
+            display("The sealed share could not be opened, as it is corrupted or was tampered with.")
+        }
+
+        WrongPassphrase {
+            description("The passphrase is wrong")
+            display("The share could not be decrypted, as the passphrase is wrong or the share was tampered with.")
+        }
+
+        MissingPassphrase {
+            description("The share is encrypted, but no passphrase was given")
+            display("The share is encrypted, but no passphrase was given.")
+        }
+
+        WrongRequest(expected: RequestId, found: RequestId) {
+            description("The response answers another recovery request")
+            display("The response answers the recovery request {}, not the request {}.", found, expected)
+        }
+
+        ReplayedResponse {
+            description("The response was already received")
+            display("The response to the recovery request was already received, and was replayed.")
+        }
+
+        DuplicateContribution(set: ShareSetId) {
+            description("Two shares come from the same contribution to a joint secret")
+            display("Two shares come from the contribution {} to the joint secret, and would cancel each other out.", set)
+        }
+
+        InvalidXorThreshold(k: u8, n: u8) {
+            description("The threshold of the Xor scheme must be the number of shares")
+            display("The Xor scheme requires all the shares to recover the secret, so the threshold ({}) must be the number of shares ({}).", k, n)
+        }
+
+        UnknownScheme(name: String) {
+            description("Unknown secret sharing scheme")
+            display("Unknown secret sharing scheme '{}'.", name)
+        }
+
     }
 
     foreign_links {
@@ -147,6 +232,186 @@ error_chain! {
     }
 }
 
+/// The stable numeric codes identifying the kinds of errors, as returned by `ErrorKind::code`.
+///
+/// This is the single table of error codes, from which the error codes of the C bindings
+/// are generated. Generation errors are numbered from 10, parsing errors from 20, recovery
+/// errors from 30, signature errors from 40, I/O errors from 50, errors specific to the
+/// C bindings from 60, and errors of the sealing and passphrase protection of shares from 70.
+/// Kinds of errors added once the range of their kind was full take the next free code
+/// instead, such as the recovery error `CorruptedShares` (42), and codes from 77 onwards
+/// are assigned in the order the kinds of errors were added, whatever their kind.
+///
+/// A code is never reassigned to a different kind of error, and `0` is never used.
+#[repr(u8)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ErrorCode {
+    /// An error without a more specific code
+    Other = 1,
+    /// See `ErrorKind::ThresholdTooBig`
+    ThresholdTooBig = 10,
+    /// See `ErrorKind::ThresholdTooSmall`
+    ThresholdTooSmall = 11,
+    /// See `ErrorKind::SecretTooBig`
+    SecretTooBig = 12,
+    /// See `ErrorKind::InvalidShareCountMax`
+    InvalidShareCountMax = 13,
+    /// See `ErrorKind::InvalidShareCountMin`
+    InvalidShareCountMin = 14,
+    /// See `ErrorKind::EmptySecret`
+    EmptySecret = 15,
+    /// See `ErrorKind::InvalidSS1Parameters`
+    InvalidSs1Parameters = 16,
+    /// See `ErrorKind::InvalidSplitParametersZero`
+    InvalidSplitParametersZero = 17,
+    /// See `ErrorKind::CannotGenerateRandomNumbers`
+    CannotGenerateRandomNumbers = 18,
+    /// See `ErrorKind::UnsupportedHashAlgorithm`
+    UnsupportedHashAlgorithm = 19,
+
+    /// See `ErrorKind::ShareParsingError`
+    ShareParsing = 20,
+    /// See `ErrorKind::ShareParsingErrorEmptyShare`
+    EmptyShare = 21,
+    /// See `ErrorKind::ShareParsingInvalidShareId`
+    InvalidShareId = 22,
+    /// See `ErrorKind::ShareParsingInvalidShareThreshold`
+    InvalidShareThreshold = 23,
+    /// See `ErrorKind::IntegerParsingError`
+    IntegerParsing = 24,
+    /// See `ErrorKind::SecretDeserializationError`
+    SecretDeserialization = 25,
+    /// See `ErrorKind::InvalidArmor`
+    InvalidArmor = 26,
+    /// See `ErrorKind::ArmorChecksumMismatch`
+    ArmorChecksumMismatch = 27,
+
+    /// See `ErrorKind::EmptyShares`
+    EmptyShares = 30,
+    /// See `ErrorKind::MissingShares`
+    MissingShares = 31,
+    /// See `ErrorKind::DuplicateShareId`
+    DuplicateShareId = 32,
+    /// See `ErrorKind::InconsistentThresholds`
+    InconsistentThresholds = 33,
+    /// See `ErrorKind::InconsistentSecretLengths`
+    InconsistentSecretLengths = 34,
+    /// See `ErrorKind::InconsistentShares`
+    InconsistentShares = 35,
+    /// See `ErrorKind::InconsistentShareSets`
+    InconsistentShareSets = 36,
+    /// See `ErrorKind::IncompatibleSets`
+    IncompatibleSets = 37,
+    /// See `ErrorKind::MismatchingShares`
+    MismatchingShares = 38,
+    /// See `ErrorKind::InconsistentSS1Parameters`
+    InconsistentSs1Parameters = 39,
+    /// See `ErrorKind::InvalidSignature`
+    InvalidSignature = 40,
+    /// See `ErrorKind::MissingSignature`
+    MissingSignature = 41,
+    /// See `ErrorKind::CorruptedShares`
+    CorruptedShares = 42,
+
+    /// See `ErrorKind::Io`
+    Io = 50,
+
+    /// A required pointer argument was `NULL`, only reported by the C bindings
+    NullPointer = 60,
+    /// A string argument was not valid UTF-8, only reported by the C bindings
+    InvalidUtf8 = 61,
+    /// The library panicked, which is a bug, only reported by the C bindings
+    Panic = 62,
+
+    /// See `ErrorKind::InvalidKey`
+    InvalidKey = 70,
+    /// See `ErrorKind::WrongRecipient`
+    WrongRecipient = 71,
+    /// See `ErrorKind::CannotOpenShare`
+    CannotOpenShare = 72,
+    /// See `ErrorKind::WrongPassphrase`
+    WrongPassphrase = 73,
+    /// See `ErrorKind::MissingPassphrase`
+    MissingPassphrase = 74,
+    /// See `ErrorKind::WrongRequest`
+    WrongRequest = 75,
+    /// See `ErrorKind::ReplayedResponse`
+    ReplayedResponse = 76,
+    /// See `ErrorKind::DuplicateContribution`
+    DuplicateContribution = 77,
+    /// See `ErrorKind::InvalidXorThreshold`
+    InvalidXorThreshold = 78,
+    /// See `ErrorKind::UnknownScheme`
+    UnknownScheme = 79,
+}
+
+impl ErrorKind {
+    /// Returns a stable numeric code identifying this kind of error, suitable
+    /// for use as a process exit code, or as an error code returned to foreign code.
+    ///
+    /// A code is never reassigned to a different kind of error, and `0` is never returned.
+    pub fn code(&self) -> u8 {
+        self.error_code() as u8
+    }
+
+    /// Returns the entry of the table of error codes identifying this kind of error.
+    pub fn error_code(&self) -> ErrorCode {
+        match *self {
+            ErrorKind::ThresholdTooBig(..) => ErrorCode::ThresholdTooBig,
+            ErrorKind::ThresholdTooSmall(..) => ErrorCode::ThresholdTooSmall,
+            ErrorKind::SecretTooBig(..) => ErrorCode::SecretTooBig,
+            ErrorKind::InvalidShareCountMax(..) => ErrorCode::InvalidShareCountMax,
+            ErrorKind::InvalidShareCountMin(..) => ErrorCode::InvalidShareCountMin,
+            ErrorKind::EmptySecret => ErrorCode::EmptySecret,
+            ErrorKind::InvalidSS1Parameters(..) => ErrorCode::InvalidSs1Parameters,
+            ErrorKind::InvalidSplitParametersZero(..) => ErrorCode::InvalidSplitParametersZero,
+            ErrorKind::CannotGenerateRandomNumbers => ErrorCode::CannotGenerateRandomNumbers,
+            ErrorKind::UnsupportedHashAlgorithm(..) => ErrorCode::UnsupportedHashAlgorithm,
+
+            ErrorKind::ShareParsingError(..) => ErrorCode::ShareParsing,
+            ErrorKind::ShareParsingErrorEmptyShare(..) => ErrorCode::EmptyShare,
+            ErrorKind::ShareParsingInvalidShareId(..) => ErrorCode::InvalidShareId,
+            ErrorKind::ShareParsingInvalidShareThreshold(..) => ErrorCode::InvalidShareThreshold,
+            ErrorKind::IntegerParsingError(..) => ErrorCode::IntegerParsing,
+            ErrorKind::SecretDeserializationError => ErrorCode::SecretDeserialization,
+            ErrorKind::InvalidArmor(..) => ErrorCode::InvalidArmor,
+            ErrorKind::ArmorChecksumMismatch(..) => ErrorCode::ArmorChecksumMismatch,
+
+            ErrorKind::EmptyShares => ErrorCode::EmptyShares,
+            ErrorKind::MissingShares(..) => ErrorCode::MissingShares,
+            ErrorKind::DuplicateShareId(..) => ErrorCode::DuplicateShareId,
+            ErrorKind::InconsistentThresholds(..) => ErrorCode::InconsistentThresholds,
+            ErrorKind::InconsistentSecretLengths(..) => ErrorCode::InconsistentSecretLengths,
+            ErrorKind::InconsistentShares => ErrorCode::InconsistentShares,
+            ErrorKind::InconsistentShareSets(..) => ErrorCode::InconsistentShareSets,
+            ErrorKind::IncompatibleSets(..) => ErrorCode::IncompatibleSets,
+            #[cfg(feature = "dss")]
+            ErrorKind::MismatchingShares(..) => ErrorCode::MismatchingShares,
+            #[cfg(feature = "dss")]
+            ErrorKind::InconsistentSS1Parameters(..) => ErrorCode::InconsistentSs1Parameters,
+
+            ErrorKind::InvalidSignature(..) => ErrorCode::InvalidSignature,
+            ErrorKind::MissingSignature(..) => ErrorCode::MissingSignature,
+            ErrorKind::CorruptedShares(..) => ErrorCode::CorruptedShares,
+
+            ErrorKind::Io(..) => ErrorCode::Io,
+
+            ErrorKind::InvalidKey(..) => ErrorCode::InvalidKey,
+            ErrorKind::WrongRecipient(..) => ErrorCode::WrongRecipient,
+            ErrorKind::CannotOpenShare => ErrorCode::CannotOpenShare,
+            ErrorKind::WrongPassphrase => ErrorCode::WrongPassphrase,
+            ErrorKind::MissingPassphrase => ErrorCode::MissingPassphrase,
+            ErrorKind::WrongRequest(..) => ErrorCode::WrongRequest,
+            ErrorKind::ReplayedResponse => ErrorCode::ReplayedResponse,
+            ErrorKind::DuplicateContribution(..) => ErrorCode::DuplicateContribution,
+            ErrorKind::InvalidXorThreshold(..) => ErrorCode::InvalidXorThreshold,
+            ErrorKind::UnknownScheme(..) => ErrorCode::UnknownScheme,
+
+            _ => ErrorCode::Other,
+        }
+    }
+}
+
 /// Takes a `Vec<T>` and formats it like the normal `fmt::Debug` implementation, unless it has more
 //than five elements, in which case the rest are replaced by ellipsis.
 fn no_more_than_five<T: fmt::Debug + fmt::Display>(vec: &Vec<T>) -> String {
@@ -162,3 +427,44 @@ fn no_more_than_five<T: fmt::Debug + fmt::Display>(vec: &Vec<T>) -> String {
         format!("{:?}", vec)
     }
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn error_codes_match_error_kinds() {
+        let codes = vec![
+            (10, ErrorCode::ThresholdTooBig, ErrorKind::ThresholdTooBig(3, 2)),
+            (15, ErrorCode::EmptySecret, ErrorKind::EmptySecret),
+            (
+                19,
+                ErrorCode::UnsupportedHashAlgorithm,
+                ErrorKind::UnsupportedHashAlgorithm(HashAlgorithm::SHAKE256, String::new()),
+            ),
+            (20, ErrorCode::ShareParsing, ErrorKind::ShareParsingError(String::new())),
+            (26, ErrorCode::InvalidArmor, ErrorKind::InvalidArmor(String::new())),
+            (31, ErrorCode::MissingShares, ErrorKind::MissingShares(1, 2)),
+            (32, ErrorCode::DuplicateShareId, ErrorKind::DuplicateShareId(1)),
+            (35, ErrorCode::InconsistentShares, ErrorKind::InconsistentShares),
+            (40, ErrorCode::InvalidSignature, ErrorKind::InvalidSignature(1, String::new())),
+            (41, ErrorCode::MissingSignature, ErrorKind::MissingSignature(1)),
+            (42, ErrorCode::CorruptedShares, ErrorKind::CorruptedShares(vec![])),
+            (70, ErrorCode::InvalidKey, ErrorKind::InvalidKey(String::new())),
+            (72, ErrorCode::CannotOpenShare, ErrorKind::CannotOpenShare),
+            (73, ErrorCode::WrongPassphrase, ErrorKind::WrongPassphrase),
+            (74, ErrorCode::MissingPassphrase, ErrorKind::MissingPassphrase),
+            (76, ErrorCode::ReplayedResponse, ErrorKind::ReplayedResponse),
+            (78, ErrorCode::InvalidXorThreshold, ErrorKind::InvalidXorThreshold(2, 3)),
+            (79, ErrorCode::UnknownScheme, ErrorKind::UnknownScheme(String::new())),
+            (1, ErrorCode::Other, ErrorKind::Msg(String::new())),
+        ];
+        for (code, error_code, kind) in codes {
+            assert_eq!(kind.error_code(), error_code);
+            assert_eq!(kind.code(), code);
+        }
+        assert_eq!(ErrorCode::NullPointer as u8, 60);
+        assert_eq!(ErrorCode::Panic as u8, 62);
+    }
+}
@@ -10,8 +10,9 @@ fn get_tables() -> &'static Tables {
 }
 
 /// Type for elements of a finite field with 256 elements
-#[derive(Copy, Debug, Clone, PartialEq, Eq)]
+#[derive(Copy, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Gf256 {
+    /// The element as a polynomial over GF(2), one bit per coefficient
     pub poly: u8,
 }
 
@@ -26,18 +27,23 @@ impl Gf256 {
     pub fn one() -> Gf256 {
         Gf256 { poly: 1 }
     }
+    /// returns the element represented by the given byte
     #[inline]
     pub fn from_byte(b: u8) -> Gf256 {
         Gf256 { poly: b }
     }
+    /// returns the byte representing the element
     #[inline]
     pub fn to_byte(&self) -> u8 {
         self.poly
     }
+    /// returns the generator of the multiplicative group of the field raised to `power`
     pub fn exp(power: u8) -> Gf256 {
         let tabs = get_tables();
         Gf256::from_byte(tabs.exp[power as usize])
     }
+    /// returns the discrete logarithm of the element to the base of the generator,
+    /// or `None` for zero
     pub fn log(&self) -> Option<u8> {
         if self.poly == 0 {
             None
@@ -46,6 +52,7 @@ impl Gf256 {
             Some(tabs.log[self.poly as usize])
         }
     }
+    /// returns the element raised to the power `exp`
     pub fn pow(&self, mut exp: u8) -> Gf256 {
         let mut base = *self;
         let mut acc = Self::one();
@@ -64,6 +71,97 @@ impl Gf256 {
 
         acc
     }
+    /// returns the multiplicative inverse of the element, or `None` for zero
+    pub fn inverse(&self) -> Option<Gf256> {
+        self.log()
+            .map(|l| Gf256::exp(((255 - u16::from(l)) % 255) as u8))
+    }
+    /// returns the square root of the element.
+    ///
+    /// Squaring is a bijection in a field of characteristic 2,
+    /// so every element has exactly one square root.
+    pub fn sqrt(&self) -> Gf256 {
+        // a^256 = a, hence (a^128)^2 = a
+        self.pow(128)
+    }
+
+    /// returns the inverses of all the given `elems` at the cost of a single inversion,
+    /// or `None` if any of them is zero
+    pub fn batch_inverse(elems: &[Gf256]) -> Option<Vec<Gf256>> {
+        // Montgomery's trick: invert the product of all the elements,
+        // then peel off the inverse of each one by multiplying with the others.
+        let mut prefixes = Vec::with_capacity(elems.len());
+        let mut acc = Gf256::one();
+        for &elem in elems {
+            prefixes.push(acc);
+            acc *= elem;
+        }
+
+        let mut inv = acc.inverse()?;
+        let mut inverses = vec![Gf256::zero(); elems.len()];
+        for i in (0..elems.len()).rev() {
+            inverses[i] = inv * prefixes[i];
+            inv *= elems[i];
+        }
+        Some(inverses)
+    }
+    /// adds `src` to `dst`, element-wise
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slices do not have the same length.
+    pub fn add_slice(dst: &mut [Gf256], src: &[Gf256]) {
+        assert_eq!(dst.len(), src.len(), "Slices of different lengths");
+        for (d, &s) in dst.iter_mut().zip(src) {
+            d.poly ^= s.poly;
+        }
+    }
+    /// multiplies every element of `dst` by `c`
+    pub fn mul_slice(dst: &mut [Gf256], c: Gf256) {
+        let lc = match c.log() {
+            Some(lc) => u16::from(lc),
+            None => {
+                for d in dst.iter_mut() {
+                    *d = Gf256::zero();
+                }
+                return;
+            }
+        };
+        for d in dst.iter_mut() {
+            if let Some(ld) = d.log() {
+                *d = Gf256::exp(((u16::from(ld) + lc) % 255) as u8);
+            }
+        }
+    }
+    /// adds `c` times `src` to `dst`, element-wise
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slices do not have the same length.
+    pub fn mul_add_slice(dst: &mut [Gf256], c: Gf256, src: &[Gf256]) {
+        assert_eq!(dst.len(), src.len(), "Slices of different lengths");
+        let lc = match c.log() {
+            Some(lc) => u16::from(lc),
+            None => return,
+        };
+        for (d, s) in dst.iter_mut().zip(src) {
+            if let Some(ls) = s.log() {
+                d.poly ^= Gf256::exp(((u16::from(ls) + lc) % 255) as u8).poly;
+            }
+        }
+    }
+}
+
+impl From<u8> for Gf256 {
+    fn from(b: u8) -> Gf256 {
+        Gf256::from_byte(b)
+    }
+}
+
+impl From<Gf256> for u8 {
+    fn from(elem: Gf256) -> u8 {
+        elem.to_byte()
+    }
 }
 
 impl Add<Gf256> for Gf256 {
@@ -279,8 +377,68 @@ mod tests {
 
                 TestResult::from_bool(left && right)
             }
+
+            fn inverse_agrees_with_division(a: Gf256) -> bool {
+                match a.inverse() {
+                    Some(inv) => a != Gf256::zero() && inv == Gf256::one() / a,
+                    None => a == Gf256::zero(),
+                }
+            }
+        }
+
+    }
+
+    mod roots {
+        use super::*;
+
+        #[test]
+        fn every_element_has_a_single_square_root() {
+            let mut squares = (0..256)
+                .map(|a| {
+                    let a = Gf256::from_byte(a as u8);
+                    (a * a).to_byte()
+                })
+                .collect::<Vec<_>>();
+            squares.sort();
+            squares.dedup();
+            assert_eq!(squares.len(), 256);
         }
 
+        quickcheck! {
+            fn sqrt_squares_back(a: Gf256) -> bool {
+                let root = a.sqrt();
+                root * root == a && (a * a).sqrt() == a
+            }
+        }
+    }
+
+    mod batch {
+        use super::*;
+
+        quickcheck! {
+            fn batch_inverse_inverts_each(elems: Vec<Gf256>) -> bool {
+                let expected = elems.iter().map(Gf256::inverse).collect::<Option<Vec<_>>>();
+                Gf256::batch_inverse(&elems) == expected
+            }
+
+            fn mul_add_slice_is_elementwise(dst: Vec<Gf256>, c: Gf256) -> bool {
+                let src = dst.iter().rev().cloned().collect::<Vec<_>>();
+                let expected = dst.iter()
+                    .zip(&src)
+                    .map(|(&d, &s)| d + c * s)
+                    .collect::<Vec<_>>();
+
+                let mut found = dst.clone();
+                Gf256::mul_add_slice(&mut found, c, &src);
+
+                let mut scaled = src.clone();
+                Gf256::mul_slice(&mut scaled, c);
+                let mut added = dst;
+                Gf256::add_slice(&mut added, &scaled);
+
+                found == expected && added == expected
+            }
+        }
     }
 
 }
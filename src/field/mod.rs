@@ -0,0 +1,29 @@
+//! Arithmetic in GF(2^8), the finite field with 256 elements on which
+//! the schemes of this crate are built, and over polynomials with coefficients in it.
+//!
+//! Field elements are bytes, added with XOR and multiplied modulo the
+//! polynomial `x^8 + x^4 + x^3 + x^2 + 1`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use rusty_secrets::field::{Gf256, Poly};
+//!
+//! // Hide the secret byte 42 as the constant term of a polynomial of degree 2.
+//! let poly = Poly::new(vec![Gf256::from_byte(42), Gf256::from_byte(7), Gf256::from_byte(99)]);
+//!
+//! let points = (1..4)
+//!     .map(|x| (Gf256::from_byte(x), poly.evaluate_at(Gf256::from_byte(x))))
+//!     .collect::<Vec<_>>();
+//!
+//! // Any three points determine it.
+//! assert_eq!(Poly::interpolate(&points), poly);
+//! assert_eq!(Poly::interpolate(&points).evaluate_at_zero(), Gf256::from_byte(42));
+//! ```
+
+#[macro_use]
+mod gf256;
+mod poly;
+
+pub use self::gf256::Gf256;
+pub use self::poly::{interpolate_at, Poly};
@@ -0,0 +1,410 @@
+//! This module provides the Poly type which is used to represent
+//! polynomials with coefficients in GF(2^8).
+
+use std::ops::{Add, Mul, Sub};
+
+use super::Gf256;
+
+/// A polynomial with coefficients in GF(2^8).
+///
+/// Trailing zero coefficients are not significant: two polynomials
+/// which only differ by them are equal.
+#[derive(Clone, Debug)]
+pub struct Poly {
+    /// The coefficients of the polynomial, starting with the constant term
+    pub coeffs: Vec<Gf256>,
+}
+
+impl Poly {
+    /// Returns the polynomial with the given coefficients, starting with the constant term.
+    pub fn new(coeffs: Vec<Gf256>) -> Self {
+        Self { coeffs }
+    }
+
+    /// Returns the zero polynomial.
+    pub fn zero() -> Self {
+        Self::new(Vec::new())
+    }
+
+    /// Returns the constant polynomial `c`.
+    pub fn constant(c: Gf256) -> Self {
+        Self::new(vec![c])
+    }
+
+    /// Returns the degree of the polynomial, or `None` for the zero polynomial.
+    pub fn degree(&self) -> Option<usize> {
+        self.coeffs.iter().rposition(|&c| c != Gf256::zero())
+    }
+
+    /// Whether this is the zero polynomial.
+    pub fn is_zero(&self) -> bool {
+        self.degree().is_none()
+    }
+
+    /// Returns the significant coefficients, without the trailing zeros.
+    fn significant_coeffs(&self) -> &[Gf256] {
+        let len = self.degree().map_or(0, |degree| degree + 1);
+        &self.coeffs[..len]
+    }
+
+    /// Drops the trailing zero coefficients.
+    fn trimmed(mut self) -> Self {
+        let len = self.degree().map_or(0, |degree| degree + 1);
+        self.coeffs.truncate(len);
+        self
+    }
+
+    /// Evaluates the polynomial at x = 0, that is returns its constant term.
+    pub fn evaluate_at_zero(&self) -> Gf256 {
+        self.coeffs.first().cloned().unwrap_or_else(Gf256::zero)
+    }
+
+    /// Evaluates the polynomial at `x` using Horner's method.
+    pub fn evaluate_at(&self, x: Gf256) -> Gf256 {
+        self.coeffs
+            .iter()
+            .rev()
+            .fold(Gf256::zero(), |acc, &coeff| acc * x + coeff)
+    }
+
+    /// Evaluates the polynomial at each of the given `xs`.
+    ///
+    /// As there are at most 256 distinct points in the field,
+    /// evaluating each of them with Horner's method beats asymptotically faster algorithms.
+    pub fn evaluate_many(&self, xs: &[Gf256]) -> Vec<Gf256> {
+        xs.iter().map(|&x| self.evaluate_at(x)).collect()
+    }
+
+    /// Returns the polynomial multiplied by the constant `c`.
+    pub fn scale(&self, c: Gf256) -> Poly {
+        let mut coeffs = self.coeffs.clone();
+        Gf256::mul_slice(&mut coeffs, c);
+        Poly::new(coeffs).trimmed()
+    }
+
+    /// Divides the polynomial by `divisor`, and returns the quotient along with the remainder,
+    /// whose degree is lower than the one of `divisor`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `divisor` is the zero polynomial.
+    pub fn divmod(&self, divisor: &Poly) -> (Poly, Poly) {
+        let divisor = divisor.significant_coeffs();
+        let degree = divisor.len().checked_sub(1).expect("Division by zero");
+        let lead_inv = divisor[degree].inverse().unwrap();
+
+        let mut rem = self.significant_coeffs().to_vec();
+        if rem.len() <= degree {
+            return (Poly::zero(), Poly::new(rem));
+        }
+
+        let mut quotient = vec![Gf256::zero(); rem.len() - degree];
+        for i in (0..quotient.len()).rev() {
+            let coeff = rem[i + degree] * lead_inv;
+            quotient[i] = coeff;
+            Gf256::mul_add_slice(&mut rem[i..i + degree + 1], coeff, divisor);
+        }
+        rem.truncate(degree);
+
+        (Poly::new(quotient).trimmed(), Poly::new(rem).trimmed())
+    }
+
+    /// Returns the formal derivative of the polynomial.
+    ///
+    /// In characteristic 2, the terms of even degree vanish.
+    pub fn derivative(&self) -> Poly {
+        let coeffs = self.coeffs
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, &coeff)| if i % 2 == 1 { coeff } else { Gf256::zero() })
+            .collect();
+        Poly::new(coeffs).trimmed()
+    }
+
+    /// Returns the composition `self(other(x))`.
+    pub fn compose(&self, other: &Poly) -> Poly {
+        self.coeffs
+            .iter()
+            .rev()
+            .fold(Poly::zero(), |acc, &coeff| {
+                &(&acc * other) + &Poly::constant(coeff)
+            })
+    }
+
+    /// Returns the polynomial of lowest degree going through the given `points`,
+    /// using Lagrange interpolation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if two points share the same x coordinate.
+    pub fn interpolate(points: &[(Gf256, Gf256)]) -> Poly {
+        // The polynomial vanishing at each x coordinate,
+        // from which each Lagrange basis polynomial is derived.
+        let mut master = vec![Gf256::one()];
+        for &(x, _) in points {
+            let mut next = vec![Gf256::zero(); master.len() + 1];
+            next[1..].copy_from_slice(&master);
+            Gf256::mul_add_slice(&mut next[..master.len()], x, &master);
+            master = next;
+        }
+
+        let mut coeffs = vec![Gf256::zero(); points.len()];
+        let mut basis = vec![Gf256::zero(); points.len()];
+        for &(x, y) in points {
+            // Divide out (X - x) by synthetic division.
+            let mut carry = Gf256::zero();
+            for i in (0..points.len()).rev() {
+                carry = master[i + 1] + x * carry;
+                basis[i] = carry;
+            }
+
+            let denom = Poly::new(basis.clone()).evaluate_at(x);
+            assert_ne!(denom, Gf256::zero(), "Duplicate x coordinates");
+            Gf256::mul_add_slice(&mut coeffs, y / denom, &basis);
+        }
+
+        Poly::new(coeffs).trimmed()
+    }
+}
+
+/// Evaluates at `x` the polynomial of lowest degree going through the given `points`,
+/// without computing its coefficients.
+///
+/// This relies on the barycentric Lagrange interpolation algorithm from
+/// "Polynomial Interpolation: Langrange vs Newton" by Wilhelm Werner.
+///
+/// # Panics
+///
+/// Panics if `points` is empty or if two points share the same x coordinate.
+pub fn interpolate_at(points: &[(Gf256, Gf256)], x: Gf256) -> Gf256 {
+    assert!(!points.is_empty(), "No points to interpolate");
+
+    if let Some(&(_, y)) = points.iter().find(|&&(xi, _)| xi == x) {
+        return y;
+    }
+
+    // Compute the barycentric weights `w`.
+    let k = points.len();
+    let mut w = vec![Gf256::zero(); k];
+    w[0] = Gf256::one();
+
+    for i in 1..k {
+        for j in 0..i {
+            let delta = points[j].0 - points[i].0;
+            assert_ne!(delta, Gf256::zero(), "Duplicate x coordinates");
+            w[j] /= delta;
+            let wj = w[j];
+            w[i] -= wj;
+        }
+    }
+
+    // Evaluate the second or "true" form of the barycentric
+    // interpolation formula at `x`.
+    let (mut num, mut denom) = (Gf256::zero(), Gf256::zero());
+    for (&(xi, yi), &wi) in points.iter().zip(&w) {
+        let diff = wi / (x - xi);
+        num += diff * yi;
+        denom += diff;
+    }
+
+    num / denom
+}
+
+impl PartialEq for Poly {
+    fn eq(&self, other: &Poly) -> bool {
+        self.significant_coeffs() == other.significant_coeffs()
+    }
+}
+
+impl Eq for Poly {}
+
+impl<'a, 'b> Add<&'b Poly> for &'a Poly {
+    type Output = Poly;
+    fn add(self, rhs: &'b Poly) -> Poly {
+        let (long, short) = if self.coeffs.len() >= rhs.coeffs.len() {
+            (self, rhs)
+        } else {
+            (rhs, self)
+        };
+        let mut coeffs = long.coeffs.clone();
+        Gf256::add_slice(&mut coeffs[..short.coeffs.len()], &short.coeffs);
+        Poly::new(coeffs).trimmed()
+    }
+}
+
+impl Add<Poly> for Poly {
+    type Output = Poly;
+    fn add(self, rhs: Poly) -> Poly {
+        &self + &rhs
+    }
+}
+
+impl<'a, 'b> Sub<&'b Poly> for &'a Poly {
+    type Output = Poly;
+    /// In characteristic 2, subtraction is the same as addition.
+    fn sub(self, rhs: &'b Poly) -> Poly {
+        Add::add(self, rhs)
+    }
+}
+
+impl Sub<Poly> for Poly {
+    type Output = Poly;
+    fn sub(self, rhs: Poly) -> Poly {
+        &self - &rhs
+    }
+}
+
+impl<'a, 'b> Mul<&'b Poly> for &'a Poly {
+    type Output = Poly;
+    fn mul(self, rhs: &'b Poly) -> Poly {
+        let (lhs, rhs) = (self.significant_coeffs(), rhs.significant_coeffs());
+        if lhs.is_empty() || rhs.is_empty() {
+            return Poly::zero();
+        }
+
+        let mut coeffs = vec![Gf256::zero(); lhs.len() + rhs.len() - 1];
+        for (i, &coeff) in lhs.iter().enumerate() {
+            Gf256::mul_add_slice(&mut coeffs[i..i + rhs.len()], coeff, rhs);
+        }
+        Poly::new(coeffs)
+    }
+}
+
+impl Mul<Poly> for Poly {
+    type Output = Poly;
+    fn mul(self, rhs: Poly) -> Poly {
+        &self * &rhs
+    }
+}
+
+#[cfg(test)]
+#[allow(trivial_casts)]
+mod tests {
+
+    use super::*;
+    use quickcheck::*;
+
+    /// Keeps the arbitrary polynomials small enough for the
+    /// products and compositions to be computed quickly.
+    const MAX_ARBITRARY_COEFFS: usize = 16;
+
+    impl Arbitrary for Poly {
+        fn arbitrary<G: Gen>(gen: &mut G) -> Poly {
+            let mut coeffs = Vec::<Gf256>::arbitrary(gen);
+            coeffs.truncate(MAX_ARBITRARY_COEFFS);
+            Poly::new(coeffs)
+        }
+    }
+
+    /// Returns `ys.len()` points with distinct x coordinates, starting at `offset`.
+    fn points(ys: Vec<Gf256>, offset: u8) -> Vec<(Gf256, Gf256)> {
+        (0..256)
+            .map(|x| gf256!((x as u8).wrapping_add(offset)))
+            .zip(ys)
+            .collect()
+    }
+
+    #[test]
+    fn horner_matches_power_sum() {
+        let poly = Poly::new(gf256_vec![3, 0, 17, 255, 1]);
+        for x in 0..256 {
+            let x = gf256!(x as u8);
+            let expected = poly.coeffs
+                .iter()
+                .enumerate()
+                .fold(Gf256::zero(), |acc, (i, &c)| acc + c * x.pow(i as u8));
+            assert_eq!(poly.evaluate_at(x), expected);
+        }
+    }
+
+    #[test]
+    fn trailing_zeros_are_not_significant() {
+        let poly = Poly::new(gf256_vec![1, 2, 0, 0]);
+        assert_eq!(poly, Poly::new(gf256_vec![1, 2]));
+        assert_eq!(poly.degree(), Some(1));
+        assert!(Poly::new(gf256_vec![0, 0]).is_zero());
+        assert_eq!(Poly::zero().evaluate_at_zero(), Gf256::zero());
+    }
+
+    #[test]
+    #[should_panic(expected = "Division by zero")]
+    fn divmod_by_zero() {
+        Poly::new(gf256_vec![1, 2]).divmod(&Poly::new(gf256_vec![0]));
+    }
+
+    quickcheck! {
+        fn law_addition_associativity(a: Poly, b: Poly, c: Poly) -> bool {
+            &(&a + &b) + &c == &a + &(&b + &c)
+        }
+
+        fn law_addition_inverses(a: Poly) -> bool {
+            (&a - &a).is_zero()
+        }
+
+        fn law_multiplication_associativity(a: Poly, b: Poly, c: Poly) -> bool {
+            &(&a * &b) * &c == &a * &(&b * &c)
+        }
+
+        fn law_multiplication_commutativity(a: Poly, b: Poly) -> bool {
+            &a * &b == &b * &a
+        }
+
+        fn law_distributivity(a: Poly, b: Poly, c: Poly) -> bool {
+            &a * &(&b + &c) == &(&a * &b) + &(&a * &c)
+        }
+
+        fn operations_agree_with_evaluation(a: Poly, b: Poly, x: Gf256) -> bool {
+            (&a + &b).evaluate_at(x) == a.evaluate_at(x) + b.evaluate_at(x)
+                && (&a * &b).evaluate_at(x) == a.evaluate_at(x) * b.evaluate_at(x)
+                && a.compose(&b).evaluate_at(x) == a.evaluate_at(b.evaluate_at(x))
+        }
+
+        fn divmod_reconstructs(a: Poly, b: Poly) -> TestResult {
+            if b.is_zero() {
+                return TestResult::discard();
+            }
+
+            let (quotient, rem) = a.divmod(&b);
+            let reduced = rem.degree() < b.degree();
+
+            TestResult::from_bool(reduced && &(&quotient * &b) + &rem == a)
+        }
+
+        fn derivative_product_rule(a: Poly, b: Poly) -> bool {
+            (&a * &b).derivative() == &(&a.derivative() * &b) + &(&a * &b.derivative())
+        }
+
+        fn evaluate_many_agrees(a: Poly, xs: Vec<Gf256>) -> bool {
+            a.evaluate_many(&xs) == xs.iter().map(|&x| a.evaluate_at(x)).collect::<Vec<_>>()
+        }
+
+        fn interpolate_goes_through_points(ys: Vec<Gf256>, offset: u8) -> bool {
+            let points = points(ys, offset);
+            let poly = Poly::interpolate(&points);
+
+            poly.degree().map_or(0, |degree| degree + 1) <= points.len()
+                && points.iter().all(|&(x, y)| poly.evaluate_at(x) == y)
+        }
+
+        fn interpolate_recovers_poly(a: Poly, offset: u8) -> bool {
+            let len = a.coeffs.len();
+            let xs = (0..len).map(|x| gf256!((x as u8).wrapping_add(offset))).collect::<Vec<_>>();
+            let points = xs.iter().cloned().zip(a.evaluate_many(&xs)).collect::<Vec<_>>();
+
+            Poly::interpolate(&points) == a
+        }
+
+        fn interpolate_at_agrees(ys: Vec<Gf256>, offset: u8, x: Gf256) -> TestResult {
+            if ys.is_empty() {
+                return TestResult::discard();
+            }
+
+            let points = points(ys, offset);
+            let expected = Poly::interpolate(&points).evaluate_at(x);
+
+            TestResult::from_bool(interpolate_at(&points, x) == expected)
+        }
+    }
+
+}
@@ -0,0 +1,224 @@
+//! The hash functions which can be selected to deal shares.
+//!
+//! The `SS1` scheme uses the selected function to commit to the secret and to derive its
+//! randomness, while the dealer signatures of all the schemes use it to build their
+//! Lamport keys and Merkle tree. The choice is recorded in the shares, so that recovery
+//! uses the matching algorithm.
+
+use std::fmt;
+
+use ring::digest;
+use tiny_keccak::Keccak;
+
+use errors::*;
+use proto::SignatureHashAlgorithmProto;
+
+/// The hash function on which shares are signed by default,
+/// and by which all the shares signed before it was recorded in them were.
+pub const DEFAULT_SIGNATURE_HASH_ALGORITHM: HashAlgorithm = HashAlgorithm::SHA512;
+
+/// A hash function, or an extendable-output function (XOF) whose output
+/// can be as long as needed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
+pub enum HashAlgorithm {
+    /// SHA-256
+    SHA256,
+    /// SHA-384
+    SHA384,
+    /// SHA-512
+    SHA512,
+    /// SHAKE256, the extendable-output function of the SHA-3 family
+    SHAKE256,
+}
+
+impl HashAlgorithm {
+    /// Whether this is an extendable-output function, rather than a fixed-length hash.
+    pub fn is_xof(&self) -> bool {
+        *self == HashAlgorithm::SHAKE256
+    }
+
+    /// Returns the fixed-length hash implementing this algorithm,
+    /// or `None` for an extendable-output function.
+    pub(crate) fn digest_algorithm(&self) -> Option<&'static digest::Algorithm> {
+        match *self {
+            HashAlgorithm::SHA256 => Some(&digest::SHA256),
+            HashAlgorithm::SHA384 => Some(&digest::SHA384),
+            HashAlgorithm::SHA512 => Some(&digest::SHA512),
+            HashAlgorithm::SHAKE256 => None,
+        }
+    }
+
+    /// Returns the fixed-length hash used to sign shares, since the Lamport signatures
+    /// cannot be built on top of an extendable-output function.
+    pub(crate) fn signature_algorithm(&self) -> Result<&'static digest::Algorithm> {
+        self.digest_algorithm().ok_or_else(|| {
+            ErrorKind::UnsupportedHashAlgorithm(
+                *self,
+                "Shares can only be signed with a fixed-length hash.".to_string(),
+            ).into()
+        })
+    }
+
+    /// Returns the algorithm implemented by the given fixed-length hash,
+    /// as found in the proof of a signature.
+    pub(crate) fn from_digest_algorithm(algorithm: &digest::Algorithm) -> Option<Self> {
+        [
+            HashAlgorithm::SHA256,
+            HashAlgorithm::SHA384,
+            HashAlgorithm::SHA512,
+        ].iter()
+            .cloned()
+            .find(|hash_algorithm| {
+                hash_algorithm.digest_algorithm().unwrap().output_len == algorithm.output_len
+            })
+    }
+
+    /// Returns the protobuf value recording this algorithm as the one a signature
+    /// is built on, which must be a fixed-length hash.
+    pub(crate) fn to_signature_proto(&self) -> SignatureHashAlgorithmProto {
+        match *self {
+            HashAlgorithm::SHA256 => SignatureHashAlgorithmProto::SIGNATURE_SHA256,
+            HashAlgorithm::SHA384 => SignatureHashAlgorithmProto::SIGNATURE_SHA384,
+            HashAlgorithm::SHA512 => SignatureHashAlgorithmProto::SIGNATURE_SHA512,
+            HashAlgorithm::SHAKE256 => unreachable!("Signatures are built on a fixed-length hash."),
+        }
+    }
+
+    /// Returns the algorithm a signature is built on, as recorded in a share.
+    /// Shares signed before it was recorded in them leave it unset, and were signed
+    /// with SHA-512.
+    pub(crate) fn from_signature_proto(proto: SignatureHashAlgorithmProto) -> Self {
+        match proto {
+            SignatureHashAlgorithmProto::SIGNATURE_SHA256 => HashAlgorithm::SHA256,
+            SignatureHashAlgorithmProto::SIGNATURE_SHA384 => HashAlgorithm::SHA384,
+            SignatureHashAlgorithmProto::SIGNATURE_SHA512 => HashAlgorithm::SHA512,
+        }
+    }
+}
+
+impl fmt::Display for HashAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            HashAlgorithm::SHA256 => write!(f, "SHA-256"),
+            HashAlgorithm::SHA384 => write!(f, "SHA-384"),
+            HashAlgorithm::SHA512 => write!(f, "SHA-512"),
+            HashAlgorithm::SHAKE256 => write!(f, "SHAKE256"),
+        }
+    }
+}
+
+/// A running computation of any of the supported hash functions.
+#[derive(Clone)]
+pub(crate) enum Hasher {
+    Digest(digest::Context),
+    Xof(Keccak),
+}
+
+impl Hasher {
+    pub(crate) fn new(algorithm: HashAlgorithm) -> Self {
+        match algorithm.digest_algorithm() {
+            Some(digest_algorithm) => Hasher::Digest(digest::Context::new(digest_algorithm)),
+            None => Hasher::Xof(Keccak::new_shake256()),
+        }
+    }
+
+    pub(crate) fn update(&mut self, bytes: &[u8]) {
+        match *self {
+            Hasher::Digest(ref mut ctx) => ctx.update(bytes),
+            Hasher::Xof(ref mut shake) => shake.update(bytes),
+        }
+    }
+
+    /// Writes the output of the hash function to `dest`, which must have the length
+    /// of its output, unless it is an extendable-output function.
+    pub(crate) fn finish(self, dest: &mut [u8]) {
+        match self {
+            Hasher::Digest(ctx) => dest.copy_from_slice(ctx.finish().as_ref()),
+            Hasher::Xof(shake) => shake.finalize(dest),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn signature_algorithms_roundtrip() {
+        for algorithm in &[
+            HashAlgorithm::SHA256,
+            HashAlgorithm::SHA384,
+            HashAlgorithm::SHA512,
+        ] {
+            let digest_algorithm = algorithm.signature_algorithm().unwrap();
+            assert_eq!(
+                HashAlgorithm::from_digest_algorithm(digest_algorithm),
+                Some(*algorithm)
+            );
+        }
+        assert!(HashAlgorithm::SHAKE256.signature_algorithm().is_err());
+    }
+
+    #[test]
+    fn xof_output_has_any_length() {
+        let output = |len: usize| {
+            let mut hasher = Hasher::new(HashAlgorithm::SHAKE256);
+            hasher.update(b"Hello");
+            let mut dest = vec![0; len];
+            hasher.finish(&mut dest);
+            dest
+        };
+
+        let long = output(1000);
+        assert_eq!(output(17)[..], long[..17]);
+    }
+
+    fn shake256(chunks: &[&[u8]], len: usize) -> String {
+        let mut hasher = Hasher::new(HashAlgorithm::SHAKE256);
+        for chunk in chunks {
+            hasher.update(chunk);
+        }
+        let mut dest = vec![0; len];
+        hasher.finish(&mut dest);
+        dest.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    #[test]
+    fn shake256_matches_fips_202_vectors() {
+        assert_eq!(
+            shake256(&[&b""[..]], 32),
+            "46b9dd2b0ba88d13233b3feb743eeb243fcd52ea62b81b82b50c27646ed5762f"
+        );
+        assert_eq!(
+            shake256(&[&b"abc"[..]], 32),
+            "483366601360a8771c6863080cc4114d8db44530f8f1e1ee4f94ea37e78b5739"
+        );
+    }
+
+    #[test]
+    fn shake256_squeezes_several_blocks() {
+        let input = vec![0xa3; 200];
+        let expected = "cd8a920ed141aa0407a22d59288652e9d9f1a7ee0c1e7c1ca699424da84a904d\
+                        2d700caae7396ece96604440577da4f3aa22aeb8857f961c4cd8e06f0ae6610b\
+                        1048a7f64e1074cd629e85ad7566048efc4fb500b486a3309a8f26724c0ed628\
+                        001a1099422468de726f1061d99eb9e93604d5aa7467d4b1bd6484582a384317\
+                        d7f47d750b8f5499512bb85a226c4243556e696f6bd072c5aa2d9b69730244b5\
+                        6853d16970ad817e213e470618178001c9fb56c54fefa5fee67d2da524bb3b0b\
+                        61ef0e9114a92cdbb6cccb98615cfe76e3510dd88d1cc28ff99287512f24bfaf\
+                        a1a76877b6f37198e3a641c68a7c42d45fa7acc10dae5f3cefb7b735f12d4e58\
+                        9f7a456e78c0f5e4c4471fffa5e4fa0514ae974d8c2648513b5db494cea84715\
+                        6d277ad0e141c24c7839064cd08851bc2e7ca109fd4e251c35bb0a04fb05b364\
+                        ff8c4d8b59bc303e25328c09a882e952518e1a8ae0ff265d61c465896973d749\
+                        0499dc639fb8502b39456791b1b6ec5bcc5d9ac36a6df622a070d43fed781f5f\
+                        149f7b62675e7d1a4d6dec48c1c7164586eae06a51208c0b791244d307726505\
+                        c3ad4b26b6822377257aa152037560a739714a3ca79bd605547c9b78dd1f596f\
+                        2d4f1791bc689a0e9b799a37339c04275733740143ef5d2b58b96a363d4e0807\
+                        6a1a9d7846436e4dca5728b6f760eef0ca92bf0be5615e96959d767197a0beeb";
+        assert_eq!(shake256(&[&input[..]], 512), expected);
+
+        let chunks = input.chunks(7).collect::<Vec<_>>();
+        assert_eq!(shake256(&chunks, 512), expected);
+    }
+}
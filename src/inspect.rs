@@ -0,0 +1,426 @@
+//! Inspection of a single share, without knowing beforehand which scheme dealt it.
+//!
+//! `inspect_share` accepts a share in its plain `K-N-D` format, or ASCII-armored, decodes
+//! its protobuf payload, and reports what can be learnt from the share alone.
+//!
+//! The scheme is detected from the payload: the shares of the `SSS` and `WrappedSecrets`
+//! schemes share the same format, the ones of the `ThSS` and `SS1` schemes another one,
+//! in which only `SS1` shares carry a hash, and the ones of the `Xor` scheme a third one,
+//! whose protobuf fields are numbered apart from those of the other two. Since the
+//! `WrappedSecrets` scheme merely shares a wrapped secret with the `SSS` scheme, its shares
+//! cannot be told apart from `SSS` shares, unless they are armored.
+//!
+//! # Format versions
+//!
+//! Shares do not record the version of their format, which is thus inferred from the
+//! fields they hold:
+//!
+//! **Version** | **Description**
+//! :----------:|----------------
+//! **1**       | Shares dealt before share sets were introduced, without a share-set identifier.
+//! **2**       | Shares carrying a share-set identifier.
+//! **3**       | `SS1` shares which also record the parameters of the scheme which dealt them.
+
+use std::collections::BTreeMap;
+
+use armor::ArmoredShare;
+use errors::*;
+use passphrase::{self, EncryptedShare};
+use scheme::SchemeKind;
+use sealing::request::{self, RecoveryResponse};
+use sealing::{self, SealedShare};
+use share_set::ShareSetId;
+use sss;
+use xor;
+
+#[cfg(feature = "dss")]
+use dss::{self, ss1, thss};
+
+/// Marker identifying ASCII-armored input.
+const ARMOR_MARKER: &str = "-----BEGIN RUSTY SECRETS SHARE-----";
+
+/// What can be learnt from a single share.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShareInfo {
+    /// The schemes which could have dealt this share, most likely first
+    pub schemes: Vec<SchemeKind>,
+    /// Whether the share was ASCII-armored
+    pub armored: bool,
+    /// The version of the format of the share, as described in the module documentation
+    pub format_version: u8,
+    /// The identifier of the share
+    pub id: u8,
+    /// The number of shares necessary to recover the secret, aka the threshold
+    pub threshold: u8,
+    /// The total number of shares that have been dealt, if recorded in the share
+    pub shares_count: Option<u8>,
+    /// The identifier of the share set this share belongs to, if any
+    pub share_set_id: Option<ShareSetId>,
+    /// The length in bytes of the share data
+    pub data_len: usize,
+    /// Whether the share is signed by the dealer
+    pub signed: bool,
+    /// The metadata tags attached to the share
+    pub tags: BTreeMap<String, String>,
+    /// The parameters of the scheme which dealt the share, for `SS1` shares
+    #[cfg(feature = "dss")]
+    pub ss1_parameters: Option<ss1::Parameters>,
+}
+
+impl ShareInfo {
+    /// Returns the most likely scheme to have dealt this share.
+    pub fn scheme(&self) -> SchemeKind {
+        self.schemes[0]
+    }
+}
+
+/// Inspects the given share, plain or ASCII-armored, detecting which scheme dealt it.
+///
+/// Fails with a `ShareParsingError` explaining why, should the input hold several shares,
+/// or not decode as the share of any scheme, or decode as the shares of several schemes.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_secrets::inspect::inspect_share;
+/// use rusty_secrets::scheme::SchemeKind;
+/// use rusty_secrets::sss;
+///
+/// let shares = sss::split_secret(3, 5, b"The secret", true).unwrap();
+/// let info = inspect_share(&shares[1]).unwrap();
+///
+/// assert_eq!(info.schemes, vec![SchemeKind::SSS, SchemeKind::WrappedSecrets]);
+/// assert_eq!((info.id, info.threshold), (2, 3));
+/// assert!(info.signed);
+/// ```
+pub fn inspect_share(raw: &str) -> Result<ShareInfo> {
+    if raw.contains(ARMOR_MARKER) {
+        return inspect_armored(raw);
+    }
+
+    let lines = raw.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>();
+    match lines.len() {
+        0 => bail!(ErrorKind::ShareParsingError("No share was given.".to_string())),
+        1 => inspect_plain(lines[0]),
+        count => bail!(ErrorKind::ShareParsingError(format!(
+            "Found {} lines, while a single share was expected.",
+            count
+        ))),
+    }
+}
+
+fn inspect_armored(raw: &str) -> Result<ShareInfo> {
+    let mut armored = ArmoredShare::parse_all(raw)?;
+    if armored.len() != 1 {
+        bail!(ErrorKind::ShareParsingError(format!(
+            "Found {} armored shares, while a single one was expected.",
+            armored.len()
+        )));
+    }
+
+    let armored = armored.remove(0);
+    let mut info = inspect_plain(&armored.share)?;
+    if !info.schemes.contains(&armored.scheme) {
+        bail!(ErrorKind::ShareParsingError(format!(
+            "The armor announces a share of the {} scheme, but holds a share of the {} scheme.",
+            armored.scheme,
+            info.scheme()
+        )));
+    }
+
+    info.schemes = vec![armored.scheme];
+    info.armored = true;
+    Ok(info)
+}
+
+fn inspect_plain(raw: &str) -> Result<ShareInfo> {
+    if sealing::is_sealed_share(raw) {
+        let sealed = SealedShare::from_string(raw)?;
+        bail!(ErrorKind::ShareParsingError(format!(
+            "The share is sealed to the key {}, and must be opened before it can be inspected.",
+            sealed.recipient
+        )));
+    }
+
+    if request::is_recovery_response(raw) {
+        let response = RecoveryResponse::from_string(raw)?;
+        bail!(ErrorKind::ShareParsingError(format!(
+            "The share answers the recovery request {}, and must be opened by its recoverer before it can be inspected.",
+            response.request_id
+        )));
+    }
+
+    if passphrase::is_encrypted_share(raw) {
+        EncryptedShare::from_string(raw)?;
+        bail!(ErrorKind::ShareParsingError(
+            "The share is encrypted under a passphrase, and must be decrypted before it can be inspected."
+                .to_string()
+        ));
+    }
+
+    let candidates = vec![
+        ("SSS or WrappedSecrets schemes", inspect_sss(raw)),
+        ("ThSS or SS1 schemes", inspect_dss(raw)),
+        ("Xor scheme", inspect_xor(raw)),
+    ];
+    let (mut decoded, failed): (Vec<_>, Vec<_>) =
+        candidates.into_iter().partition(|&(_, ref result)| result.is_ok());
+
+    match decoded.len() {
+        1 => decoded.remove(0).1,
+        0 => {
+            let mut failed = failed
+                .into_iter()
+                .map(|(schemes, result)| (schemes, result.unwrap_err()))
+                .collect::<Vec<_>>();
+
+            // All formats share the `K-N-D` structure, which is checked first.
+            if failed.iter().all(|&(_, ref e)| reason(e) == reason(&failed[0].1)) {
+                return Err(failed.remove(0).1);
+            }
+            let reasons = failed
+                .iter()
+                .map(|&(schemes, ref e)| format!("As a share of the {}: {}", schemes, reason(e)))
+                .collect::<Vec<_>>();
+            bail!(ErrorKind::ShareParsingError(format!(
+                "The share does not decode as the share of any scheme. {}",
+                reasons.join(" ")
+            )))
+        }
+        _ => {
+            let schemes = decoded
+                .into_iter()
+                .map(|(_, result)| result.unwrap().scheme())
+                .collect::<Vec<_>>();
+            bail!(ErrorKind::ShareParsingError(format!(
+                "The share decodes both as a share of the {} scheme and of the {} scheme.",
+                schemes[0],
+                schemes[1]
+            )))
+        }
+    }
+}
+
+fn reason(e: &Error) -> String {
+    match *e.kind() {
+        ErrorKind::ShareParsingError(ref reason) => reason.clone(),
+        _ => e.to_string(),
+    }
+}
+
+fn inspect_sss(raw: &str) -> Result<ShareInfo> {
+    let (threshold, id, proto) = sss::share_protobuf_from_string(raw)?;
+    let share_set_id = ShareSetId::from_proto(proto.get_share_set_id())?;
+
+    if proto.get_shamir_data().is_empty() {
+        bail!(ErrorKind::ShareParsingErrorEmptyShare(id));
+    }
+
+    Ok(ShareInfo {
+        schemes: vec![SchemeKind::SSS, SchemeKind::WrappedSecrets],
+        armored: false,
+        format_version: if share_set_id.is_some() { 2 } else { 1 },
+        id,
+        threshold,
+        shares_count: None,
+        share_set_id,
+        data_len: proto.get_shamir_data().len(),
+        signed: !proto.get_proof().is_empty(),
+        tags: BTreeMap::new(),
+        #[cfg(feature = "dss")]
+        ss1_parameters: None,
+    })
+}
+
+fn inspect_xor(raw: &str) -> Result<ShareInfo> {
+    let (shares_count, id, proto) = xor::share_protobuf_from_string(raw)?;
+    let share_set_id = ShareSetId::from_proto(proto.get_share_set_id())?;
+
+    if proto.get_xor_data().is_empty() {
+        bail!(ErrorKind::ShareParsingErrorEmptyShare(id));
+    }
+
+    Ok(ShareInfo {
+        schemes: vec![SchemeKind::Xor],
+        armored: false,
+        format_version: if share_set_id.is_some() { 2 } else { 1 },
+        id,
+        threshold: shares_count,
+        shares_count: Some(shares_count),
+        share_set_id,
+        data_len: proto.get_xor_data().len(),
+        signed: !proto.get_proof().is_empty(),
+        tags: BTreeMap::new(),
+        #[cfg(feature = "dss")]
+        ss1_parameters: None,
+    })
+}
+
+#[cfg(feature = "dss")]
+fn inspect_dss(raw: &str) -> Result<ShareInfo> {
+    let proto = dss::parse_share_protobuf(raw)?;
+
+    if proto.get_hash().is_empty() {
+        let share = thss::Share::from_string(raw)?;
+        let format_version = if share.share_set_id.is_some() { 2 } else { 1 };
+
+        Ok(ShareInfo {
+            schemes: vec![SchemeKind::ThSS],
+            armored: false,
+            format_version,
+            id: share.id,
+            threshold: share.threshold,
+            shares_count: Some(share.shares_count),
+            share_set_id: share.share_set_id,
+            data_len: share.data.len(),
+            signed: share.is_signed(),
+            tags: share.metadata.map(|md| md.tags).unwrap_or_default(),
+            ss1_parameters: None,
+        })
+    } else {
+        let share = ss1::Share::from_string(raw)?;
+        let format_version = if proto.get_random_padding_len() != 0 {
+            3
+        } else if share.share_set_id.is_some() {
+            2
+        } else {
+            1
+        };
+
+        Ok(ShareInfo {
+            schemes: vec![SchemeKind::SS1],
+            armored: false,
+            format_version,
+            id: share.id,
+            threshold: share.threshold,
+            shares_count: Some(share.shares_count),
+            share_set_id: share.share_set_id,
+            data_len: share.data.len(),
+            signed: share.is_signed(),
+            tags: share.metadata.map(|md| md.tags).unwrap_or_default(),
+            ss1_parameters: Some(share.parameters),
+        })
+    }
+}
+
+#[cfg(not(feature = "dss"))]
+fn inspect_dss(_raw: &str) -> Result<ShareInfo> {
+    bail!(ErrorKind::ShareParsingError(
+        "Shares of the ThSS and SS1 schemes require the `dss` feature.".to_string()
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use armor::Scheme;
+
+    #[test]
+    fn sss_shares_are_detected() {
+        let shares = sss::split_secret(2, 3, b"Hello, World!", false).unwrap();
+        let info = inspect_share(&shares[2]).unwrap();
+
+        assert_eq!(info.scheme(), SchemeKind::SSS);
+        assert_eq!(info.id, 3);
+        assert_eq!(info.shares_count, None);
+        assert_eq!(info.data_len, 13);
+        assert_eq!(info.format_version, 2);
+        assert!(!info.signed && !info.armored);
+    }
+
+    #[test]
+    fn armored_shares_carry_their_scheme() {
+        let shares = sss::split_secret(2, 3, b"Hello, World!", false).unwrap();
+        let armored = ArmoredShare::new(Scheme::WrappedSecrets, &shares[0])
+            .unwrap()
+            .to_armor()
+            .unwrap();
+
+        let info = inspect_share(&format!("> {}", armored.replace('\n', "\n> "))).unwrap();
+        assert_eq!(info.schemes, vec![SchemeKind::WrappedSecrets]);
+        assert!(info.armored);
+    }
+
+    #[test]
+    fn xor_shares_are_detected() {
+        let shares = xor::split_secret(3, b"Hello, World!", true).unwrap();
+        let info = inspect_share(&shares[1]).unwrap();
+
+        assert_eq!(info.schemes, vec![SchemeKind::Xor]);
+        assert_eq!((info.id, info.threshold), (2, 3));
+        assert_eq!(info.shares_count, Some(3));
+        assert_eq!(info.data_len, 13);
+        assert!(info.signed && !info.armored);
+
+        let armored = ArmoredShare::new(Scheme::Xor, &shares[0])
+            .unwrap()
+            .to_armor()
+            .unwrap();
+        assert_eq!(inspect_share(&armored).unwrap().schemes, vec![SchemeKind::Xor]);
+    }
+
+    #[test]
+    fn legacy_shares_have_the_first_format_version() {
+        let info = inspect_share("2-1-Cha7s14Q/mSwWko0ittr+/Uf79RHQMIP").unwrap();
+        assert_eq!(info.format_version, 1);
+        assert_eq!(info.share_set_id, None);
+    }
+
+    #[test]
+    fn invalid_input_is_diagnosed() {
+        let shares = sss::split_secret(2, 3, b"Hello, World!", false).unwrap();
+
+        let diagnostic = |raw: &str| match *inspect_share(raw).unwrap_err().kind() {
+            ErrorKind::ShareParsingError(ref reason) => reason.clone(),
+            ref kind => panic!("Unexpected error: {:?}", kind),
+        };
+
+        assert!(diagnostic("\n  \n").contains("No share"));
+        assert!(diagnostic(&shares.join("\n")).contains("Found 3 lines"));
+        assert!(diagnostic("garbage").contains("Expected 3 parts"));
+        assert!(diagnostic("2-1-CgA").contains("any scheme"));
+
+        let recipient = sealing::PrivateKey::generate().unwrap().public_key();
+        let sealed = sealing::seal_share(&shares[0], &recipient).unwrap();
+        assert!(diagnostic(&sealed.into_string()).contains(&recipient.fingerprint().to_string()));
+
+        let key_derivation = passphrase::KeyDerivation::new(16, vec![0; 16]).unwrap();
+        let encrypted = passphrase::encrypt_share(&shares[0], "hunter2", &key_derivation).unwrap();
+        assert!(diagnostic(&encrypted.into_string()).contains("passphrase"));
+
+        let recoverer = request::Recoverer::new().unwrap();
+        let response = request::respond(recoverer.request(), &shares[0]).unwrap();
+        assert!(diagnostic(&response.into_string()).contains(&recoverer.request().id.to_string()));
+    }
+
+    #[cfg(feature = "dss")]
+    #[test]
+    fn dss_shares_are_detected() {
+        let mut metadata = dss::ss1::MetaData::new();
+        metadata
+            .tags
+            .insert("mime_type".to_string(), "text/plain".to_string());
+
+        let shares = thss::ThSS::builder()
+            .sign_shares(true)
+            .build()
+            .split_secret(2, 3, b"Hello", &Some(metadata.clone()))
+            .unwrap();
+        let info = inspect_share(&shares[0].clone().into_string()).unwrap();
+        assert_eq!(info.schemes, vec![SchemeKind::ThSS]);
+        assert_eq!(info.shares_count, Some(3));
+        assert_eq!(info.tags, metadata.tags);
+        assert!(info.signed);
+
+        let shares = ss1::split_secret(2, 3, b"Hello", ss1::Reproducibility::none(), &None).unwrap();
+        let info = inspect_share(&shares[1].clone().into_string()).unwrap();
+        assert_eq!(info.schemes, vec![SchemeKind::SS1]);
+        assert_eq!(info.format_version, 3);
+        assert_eq!(info.ss1_parameters, Some(ss1::Parameters::default()));
+        assert!(!info.signed);
+    }
+}
@@ -1,85 +1,70 @@
-use gf256::Gf256;
-use poly::Poly;
-
-/// Evaluates an interpolated polynomial at `Gf256::zero()` where
-/// the polynomial is determined using barycentric Lagrange
-/// interpolation based on the given `points` in
-/// the G(2^8) Galois field.
-pub(crate) fn interpolate_at(k: u8, points: &[(u8, u8)]) -> u8 {
-    barycentric_interpolate_at(k as usize, points)
+use field::Gf256;
+
+/// Evaluates at `x` each of the Lagrange basis polynomials of the given
+/// distinct `xs`, in the G(2^8) Galois field.
+///
+/// The value at `x` of the polynomial going through the points `(xs[i], ys[i])`
+/// is then the dot product of these coefficients with the `ys`, so that
+/// the coefficients only need to be computed once for a given set of shares,
+/// however many bytes are interpolated.
+pub(crate) fn basis_at(xs: &[Gf256], x: Gf256) -> Vec<Gf256> {
+    basis_matrix(xs, &[x]).pop().unwrap()
 }
 
-/// Barycentric Lagrange interpolation algorithm from "Polynomial
-/// Interpolation: Langrange vs Newton" by Wilhelm Werner. Evaluates
-/// the polynomial at `Gf256::zero()`.
-#[inline]
-fn barycentric_interpolate_at(k: usize, points: &[(u8, u8)]) -> u8 {
-    // Compute the barycentric weights `w`.
-    let mut w = vec![Gf256::zero(); k];
-    w[0] = Gf256::one();
-
-    let mut x = Vec::with_capacity(k);
-    x.push(Gf256::from_byte(points[0].0));
-
-    for i in 1..k {
-        x.push(Gf256::from_byte(points[i].0));
-        for j in 0..i {
-            let delta = x[j] - x[i];
-            assert_ne!(delta.poly, 0, "Duplicate shares");
-            w[j] /= delta;
-            w[i] -= w[j];
-        }
-    }
-
-    // Evaluate the second or "true" form of the barycentric
-    // interpolation formula at `Gf256::zero()`.
-    let (mut num, mut denom) = (Gf256::zero(), Gf256::zero());
-    for i in 0..k {
-        assert_ne!(x[i].poly, 0, "Invalid share x = 0");
-        let diff = w[i] / x[i];
-        num += diff * Gf256::from_byte(points[i].1);
-        denom += diff;
-    }
-
-    (num / denom).to_byte()
-}
-
-/// Computeds the coefficient of the Lagrange polynomial interpolated
-/// from the given `points`, in the G(2^8) Galois field.
-pub(crate) fn interpolate(points: &[(Gf256, Gf256)]) -> Poly {
-    let len = points.len();
-
-    let mut poly = vec![Gf256::zero(); len];
-
-    for &(x, y) in points {
-        assert_ne!(x.poly, 0, "Invalid share x = 0");
-        let mut coeffs = vec![Gf256::zero(); len];
-        coeffs[0] = y;
-
-        let mut prod = Gf256::one();
-        for &(x1, _) in points {
-            if x != x1 {
-                prod *= x - x1;
-
-                let mut prec = Gf256::zero();
-                coeffs = coeffs
-                    .into_iter()
-                    .map(|coeff| {
-                        let new_coeff = coeff * (-x1) + prec;
-                        prec = coeff;
-                        new_coeff
-                    })
-                    .collect();
+/// Evaluates the Lagrange basis polynomials of the given distinct `xs`
+/// at each of the `targets`, one row per target.
+///
+/// Each row maps the values of a polynomial at the `xs` to its value at the target,
+/// the barycentric weights being shared by all the rows.
+pub(crate) fn basis_matrix(xs: &[Gf256], targets: &[Gf256]) -> Vec<Vec<Gf256>> {
+    let weights = barycentric_weights(xs);
+
+    targets
+        .iter()
+        .map(|&x| match xs.iter().position(|&xi| xi == x) {
+            Some(i) => {
+                let mut row = vec![Gf256::zero(); xs.len()];
+                row[i] = Gf256::one();
+                row
             }
-        }
+            None => {
+                let vanishing = xs.iter().fold(Gf256::one(), |acc, &xj| acc * (x - xj));
+                xs.iter()
+                    .zip(&weights)
+                    .map(|(&xi, &wi)| vanishing * wi / (x - xi))
+                    .collect()
+            }
+        })
+        .collect()
+}
 
-        poly = poly.iter()
-            .zip(coeffs.iter())
-            .map(|(&old_coeff, &add)| old_coeff + add / prod)
-            .collect();
-    }
+/// Computes the barycentric weights `1 / prod_{j != i} (xs[i] - xs[j])`.
+fn barycentric_weights(xs: &[Gf256]) -> Vec<Gf256> {
+    let denoms = xs.iter()
+        .enumerate()
+        .map(|(i, &xi)| {
+            xs.iter()
+                .enumerate()
+                .filter(|&(j, _)| i != j)
+                .fold(Gf256::one(), |acc, (_, &xj)| acc * (xi - xj))
+        })
+        .collect::<Vec<_>>();
+
+    Gf256::batch_inverse(&denoms).expect("Duplicate shares")
+}
 
-    Poly::new(poly)
+/// Returns the dot product of the Lagrange basis `coeffs` with the bytes `ys`,
+/// that is the value of the interpolated polynomial at the point the `coeffs` were computed for.
+#[inline]
+pub(crate) fn interpolate_with<I>(coeffs: &[Gf256], ys: I) -> u8
+where
+    I: IntoIterator<Item = u8>,
+{
+    coeffs
+        .iter()
+        .zip(ys)
+        .fold(Gf256::zero(), |acc, (&c, y)| acc + c * Gf256::from_byte(y))
+        .to_byte()
 }
 
 #[cfg(test)]
@@ -87,7 +72,7 @@ pub(crate) fn interpolate(points: &[(Gf256, Gf256)]) -> Poly {
 mod tests {
 
     use super::*;
-    use gf256::*;
+    use field::{self, Poly};
     use quickcheck::*;
     use std;
 
@@ -102,7 +87,7 @@ mod tests {
                            .zip(1..std::u8::MAX)
                            .map(|(y, x)| (gf256!(x), y))
                            .collect::<Vec<_>>();
-            let poly = interpolate(&points);
+            let poly = Poly::interpolate(&points);
 
             for (x, y) in points {
                 if poly.evaluate_at(x) != y {
@@ -128,14 +113,56 @@ mod tests {
                 .map(|&(x, y)| (gf256!(x), gf256!(y)))
                 .collect::<Vec<_>>();
 
-            let poly = interpolate(&elems);
+            let poly = Poly::interpolate(&elems);
+
+            let xs = elems.iter().map(|&(x, _)| x).collect::<Vec<_>>();
+            let coeffs = basis_at(&xs, Gf256::zero());
 
             let equals = poly.evaluate_at(Gf256::zero()).to_byte()
-                == interpolate_at(points.len() as u8, points.as_slice());
+                == interpolate_with(&coeffs, points.iter().map(|&(_, y)| y));
 
             TestResult::from_bool(equals)
         }
 
+        fn basis_at_agrees_with_barycentric(ys: Vec<Gf256>, x: Gf256) -> TestResult {
+            if ys.is_empty() || ys.len() > std::u8::MAX as usize {
+                return TestResult::discard();
+            }
+
+            let points = ys.into_iter()
+                           .zip(1..std::u8::MAX)
+                           .map(|(y, x)| (gf256!(x), y))
+                           .collect::<Vec<_>>();
+
+            let xs = points.iter().map(|&(x, _)| x).collect::<Vec<_>>();
+            let coeffs = basis_at(&xs, x);
+            let found = interpolate_with(&coeffs, points.iter().map(|&(_, y)| y.to_byte()));
+
+            TestResult::from_bool(found == field::interpolate_at(&points, x).to_byte())
+        }
+
+        fn basis_matrix_maps_to_every_target(ys: Vec<Gf256>, targets: Vec<Gf256>) -> TestResult {
+            if ys.is_empty() || ys.len() > std::u8::MAX as usize {
+                return TestResult::discard();
+            }
+
+            let points = ys.into_iter()
+                           .zip(1..std::u8::MAX)
+                           .map(|(y, x)| (gf256!(x), y))
+                           .collect::<Vec<_>>();
+            let poly = Poly::interpolate(&points);
+
+            let xs = points.iter().map(|&(x, _)| x).collect::<Vec<_>>();
+            let matrix = basis_matrix(&xs, &targets);
+
+            let agree = targets.iter().zip(&matrix).all(|(&x, row)| {
+                interpolate_with(row, points.iter().map(|&(_, y)| y.to_byte()))
+                    == poly.evaluate_at(x).to_byte()
+            });
+
+            TestResult::from_bool(agree)
+        }
+
     }
 
 }
@@ -15,22 +15,48 @@ extern crate merkle_sigs;
 extern crate protobuf;
 extern crate rand;
 extern crate ring;
+extern crate tiny_keccak;
+
+// The `serde` dependency is optional, and can thus be enabled on its own, which would
+// silently leave out the `serde` implementations that the `serialization` feature provides.
+#[cfg(all(feature = "serde", not(feature = "serialization")))]
+compile_error!("Enable the `serialization` feature to get the `serde` implementations.");
+
+#[cfg(feature = "serialization")]
+extern crate serde;
+#[cfg(feature = "serialization")]
+#[macro_use]
+extern crate serde_derive;
 
 #[macro_use]
-mod gf256;
+pub mod field;
 mod lagrange;
-mod poly;
 mod share;
 mod vol_hash;
 
+pub mod armor;
 pub mod errors;
+pub mod hash;
+pub mod inspect;
+pub mod passphrase;
 pub mod proto;
+pub mod recovery;
+pub mod scheme;
+pub mod sealing;
+pub mod share_set;
 pub mod sss;
 pub mod wrapped_secrets;
+pub mod xor;
 
 #[cfg(feature = "dss")]
 pub mod dss;
 
+#[cfg(all(feature = "server", unix))]
+pub mod server;
+
+#[cfg(feature = "serialization")]
+mod serialization;
+
 #[cfg(test)]
 extern crate itertools;
 
@@ -40,3 +66,4 @@ extern crate flate2;
 #[cfg(test)]
 #[macro_use]
 extern crate quickcheck;
+
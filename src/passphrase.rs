@@ -0,0 +1,357 @@
+//! Encryption of individual shares under a passphrase chosen by their holder,
+//! so that a stolen share is useless on its own.
+//!
+//! The key is derived from the passphrase with PBKDF2-HMAC-SHA256, whose iteration count
+//! and salt are recorded in the encrypted share, and the share is encrypted with
+//! ChaCha20-Poly1305. An encrypted share is represented as `encrypted-` followed by
+//! the Base64 encoding of its protobuf.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use rusty_secrets::passphrase::{encrypt_share, recover_secret, KeyDerivation};
+//! use rusty_secrets::scheme::{SchemeKind, SecretSharingScheme};
+//!
+//! let scheme = SchemeKind::SSS;
+//! let shares = scheme.split(2, 3, b"The secret", false).unwrap();
+//!
+//! // The holder of the first share keeps it encrypted.
+//! let key_derivation = KeyDerivation::random().unwrap();
+//! let encrypted = encrypt_share(shares[0].as_str(), "correct horse", &key_derivation)
+//!     .unwrap()
+//!     .into_string();
+//!
+//! let secret = recover_secret(
+//!     &scheme,
+//!     &[(encrypted.as_str(), Some("correct horse")), (shares[2].as_str(), None)],
+//!     false,
+//! ).unwrap();
+//! assert_eq!(secret, b"The secret");
+//! ```
+
+use protobuf::{self, Message};
+use ring::rand::{SecureRandom, SystemRandom};
+use ring::{aead, digest, pbkdf2};
+
+use errors::*;
+use proto::sealed::PassphraseEncryptedProto;
+use scheme::SecretSharingScheme;
+use sealing::{decode_prefixed, encode_prefixed, seal_with};
+
+/// The number of PBKDF2 iterations used by `KeyDerivation::random`.
+pub const DEFAULT_ITERATIONS: u32 = 100_000;
+
+/// The largest number of PBKDF2 iterations accepted, so that a forged share
+/// cannot keep its holder busy deriving a key for hours.
+pub const MAX_ITERATIONS: u32 = 10_000_000;
+
+/// The length in bytes of the salts generated by `KeyDerivation::random`.
+pub const SALT_LEN: usize = 16;
+
+/// The shortest salt accepted, in bytes, as recommended by NIST SP 800-132.
+pub const MIN_SALT_LEN: usize = 16;
+
+const ENCRYPTED_SHARE_PREFIX: &str = "encrypted-";
+
+static PBKDF2_ALGORITHM: &digest::Algorithm = &digest::SHA256;
+
+static AEAD_ALGORITHM: &aead::Algorithm = &aead::CHACHA20_POLY1305;
+
+/// Binds the ciphertext of a share to its use.
+const SHARE_AD: &[u8] = b"rusty_secrets encrypted share";
+
+/// Binds the ciphertext of a secret protected by a recovery passphrase to its use.
+const SECRET_AD: &[u8] = b"rusty_secrets recovery passphrase";
+
+/// How a key is derived from a passphrase, with PBKDF2-HMAC-SHA256.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyDerivation {
+    /// The number of iterations, which makes guessing the passphrase proportionally slower
+    pub iterations: u32,
+    /// The salt, which should be unique to every passphrase-protected value
+    pub salt: Vec<u8>,
+}
+
+impl KeyDerivation {
+    /// Builds a key derivation with the given number of `iterations` and `salt`.
+    ///
+    /// Fails if `iterations` is zero or greater than `MAX_ITERATIONS`,
+    /// or if `salt` is shorter than `MIN_SALT_LEN` bytes.
+    pub fn new(iterations: u32, salt: Vec<u8>) -> Result<Self> {
+        let key_derivation = KeyDerivation { iterations, salt };
+        key_derivation.validate()?;
+        Ok(key_derivation)
+    }
+
+    /// Builds a key derivation with `DEFAULT_ITERATIONS` iterations and a random salt
+    /// of `SALT_LEN` bytes, using a `ring::rand::SystemRandom` as a source of entropy.
+    pub fn random() -> Result<Self> {
+        let salt = random_bytes(&SystemRandom::new(), SALT_LEN)?;
+        Self::new(DEFAULT_ITERATIONS, salt)
+    }
+
+    fn validate(&self) -> Result<()> {
+        if self.iterations == 0 || self.iterations > MAX_ITERATIONS {
+            bail!(ErrorKind::InvalidKey(format!(
+                "The number of PBKDF2 iterations must be between 1 and {}, got {}.",
+                MAX_ITERATIONS, self.iterations
+            )));
+        }
+        if self.salt.len() < MIN_SALT_LEN {
+            bail!(ErrorKind::InvalidKey(format!(
+                "The salt must be at least {} bytes long, got {} bytes.",
+                MIN_SALT_LEN,
+                self.salt.len()
+            )));
+        }
+        Ok(())
+    }
+
+    fn derive_key(&self, passphrase: &str) -> Vec<u8> {
+        let mut key = vec![0u8; AEAD_ALGORITHM.key_len()];
+        pbkdf2::derive(
+            PBKDF2_ALGORITHM,
+            self.iterations,
+            &self.salt,
+            passphrase.as_bytes(),
+            &mut key,
+        );
+        key
+    }
+}
+
+/// A share encrypted under the passphrase of its holder.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EncryptedShare {
+    /// How the key is derived from the passphrase
+    pub key_derivation: KeyDerivation,
+    /// The nonce the share was encrypted with
+    pub nonce: Vec<u8>,
+    /// The share, encrypted and authenticated
+    pub ciphertext: Vec<u8>,
+}
+
+impl EncryptedShare {
+    /// Parses an encrypted share from its string representation.
+    pub fn from_string(raw: &str) -> Result<Self> {
+        let proto: PassphraseEncryptedProto =
+            decode_prefixed(raw, ENCRYPTED_SHARE_PREFIX, "encrypted share")?;
+        from_proto(proto)
+    }
+
+    /// Formats the encrypted share into its string representation, as accepted by `from_string`.
+    pub fn into_string(self) -> String {
+        encode_prefixed(&to_proto(self), ENCRYPTED_SHARE_PREFIX)
+    }
+}
+
+/// Whether the given string looks like an encrypted share, rather than a plain one.
+pub fn is_encrypted_share(raw: &str) -> bool {
+    raw.trim().starts_with(ENCRYPTED_SHARE_PREFIX)
+}
+
+/// Encrypts the given share, in its string representation, under the given `passphrase`.
+pub fn encrypt_share(
+    share: &str,
+    passphrase: &str,
+    key_derivation: &KeyDerivation,
+) -> Result<EncryptedShare> {
+    encrypt(share.as_bytes(), passphrase, key_derivation, SHARE_AD)
+}
+
+/// Decrypts the given share with the `passphrase` of its holder, and returns the share
+/// in its string representation, as accepted by the scheme which dealt it.
+///
+/// Fails with `WrongPassphrase` if the passphrase is wrong, or the share was tampered with.
+pub fn decrypt_share(encrypted: &EncryptedShare, passphrase: &str) -> Result<String> {
+    let share = decrypt(encrypted, passphrase, SHARE_AD)?;
+    String::from_utf8(share).map_err(|_| ErrorKind::WrongPassphrase.into())
+}
+
+/// Returns the given share in its string representation, decrypting it with the given
+/// `passphrase` if it is encrypted.
+///
+/// Fails with `MissingPassphrase` if the share is encrypted, but no passphrase is given.
+pub fn unlock_share(raw: &str, passphrase: Option<&str>) -> Result<String> {
+    if !is_encrypted_share(raw) {
+        return Ok(raw.trim().to_string());
+    }
+
+    match passphrase {
+        Some(passphrase) => decrypt_share(&EncryptedShare::from_string(raw)?, passphrase),
+        None => bail!(ErrorKind::MissingPassphrase),
+    }
+}
+
+/// Recovers the secret from the given shares with the given `scheme`, each share
+/// coming along with the passphrase it is encrypted under, if any.
+pub fn recover_secret<S: SecretSharingScheme>(
+    scheme: &S,
+    shares: &[(&str, Option<&str>)],
+    verify_signatures: bool,
+) -> Result<Vec<u8>> {
+    let shares = shares
+        .iter()
+        .map(|&(raw, passphrase)| {
+            unlock_share(raw, passphrase).and_then(|share| scheme.parse_share(&share))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    scheme.recover(&shares, verify_signatures)
+}
+
+/// Encrypts a secret under a recovery passphrase before it is shared, and returns
+/// the protobuf of the encrypted secret, which is what actually gets split.
+pub(crate) fn encrypt_secret(
+    secret: &[u8],
+    passphrase: &str,
+    key_derivation: &KeyDerivation,
+) -> Result<Vec<u8>> {
+    let encrypted = encrypt(secret, passphrase, key_derivation, SECRET_AD)?;
+    Ok(to_proto(encrypted).write_to_bytes().unwrap())
+}
+
+/// Decrypts a secret encrypted by `encrypt_secret`, once it has been recovered from its shares.
+///
+/// Fails with `WrongPassphrase` if the passphrase is wrong, or the shares were tampered with.
+pub(crate) fn decrypt_secret(data: &[u8], passphrase: &str) -> Result<Vec<u8>> {
+    let proto = protobuf::parse_from_bytes::<PassphraseEncryptedProto>(data)
+        .chain_err(|| ErrorKind::SecretDeserializationError)?;
+    let encrypted = from_proto(proto).chain_err(|| ErrorKind::SecretDeserializationError)?;
+    decrypt(&encrypted, passphrase, SECRET_AD)
+}
+
+/// Encrypts `plaintext` under a key derived from `passphrase`, with a random nonce,
+/// authenticating the additional data `ad` along with it.
+pub(crate) fn encrypt(
+    plaintext: &[u8],
+    passphrase: &str,
+    key_derivation: &KeyDerivation,
+    ad: &[u8],
+) -> Result<EncryptedShare> {
+    let nonce = random_bytes(&SystemRandom::new(), AEAD_ALGORITHM.nonce_len())?;
+    let key = key_derivation.derive_key(passphrase);
+    let ciphertext = seal_with(AEAD_ALGORITHM, &key, &nonce, ad, plaintext);
+
+    Ok(EncryptedShare {
+        key_derivation: key_derivation.clone(),
+        nonce,
+        ciphertext,
+    })
+}
+
+/// Decrypts the given value, encrypted by `encrypt` with the same additional data `ad`.
+pub(crate) fn decrypt(encrypted: &EncryptedShare, passphrase: &str, ad: &[u8]) -> Result<Vec<u8>> {
+    if encrypted.key_derivation.validate().is_err()
+        || encrypted.nonce.len() != AEAD_ALGORITHM.nonce_len()
+    {
+        bail!(ErrorKind::ShareParsingError(
+            "Invalid key derivation or nonce.".to_string()
+        ));
+    }
+
+    let key = encrypted.key_derivation.derive_key(passphrase);
+    let opening_key = aead::OpeningKey::new(AEAD_ALGORITHM, &key).unwrap();
+
+    let mut in_out = encrypted.ciphertext.clone();
+    let plaintext = aead::open_in_place(&opening_key, &encrypted.nonce, ad, 0, &mut in_out)
+        .map_err(|_| ErrorKind::WrongPassphrase)?;
+    Ok(plaintext.to_vec())
+}
+
+fn to_proto(encrypted: EncryptedShare) -> PassphraseEncryptedProto {
+    let mut proto = PassphraseEncryptedProto::new();
+    proto.set_iterations(encrypted.key_derivation.iterations);
+    proto.set_salt(encrypted.key_derivation.salt);
+    proto.set_nonce(encrypted.nonce);
+    proto.set_ciphertext(encrypted.ciphertext);
+    proto
+}
+
+fn from_proto(mut proto: PassphraseEncryptedProto) -> Result<EncryptedShare> {
+    let key_derivation = KeyDerivation::new(proto.get_iterations(), proto.take_salt())
+        .chain_err(|| ErrorKind::ShareParsingError("Invalid key derivation.".to_string()))?;
+
+    Ok(EncryptedShare {
+        key_derivation,
+        nonce: proto.take_nonce(),
+        ciphertext: proto.take_ciphertext(),
+    })
+}
+
+fn random_bytes(random: &SecureRandom, len: usize) -> Result<Vec<u8>> {
+    let mut bytes = vec![0u8; len];
+    random
+        .fill(&mut bytes)
+        .chain_err(|| ErrorKind::CannotGenerateRandomNumbers)?;
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn key_derivation() -> KeyDerivation {
+        // Few iterations keep the tests fast.
+        KeyDerivation::new(16, b"NaCl, salty NaCl".to_vec()).unwrap()
+    }
+
+    #[test]
+    fn encrypted_share_roundtrips_through_strings() {
+        let encrypted = encrypt_share("2-1-CgmKQZHMO+5n5pU", "hunter2", &key_derivation()).unwrap();
+        let raw = encrypted.clone().into_string();
+
+        assert!(is_encrypted_share(&raw));
+        assert!(!is_encrypted_share("2-1-CgmKQZHMO+5n5pU"));
+        assert_eq!(EncryptedShare::from_string(&format!("{}\n", raw)).unwrap(), encrypted);
+        assert_eq!(decrypt_share(&encrypted, "hunter2").unwrap(), "2-1-CgmKQZHMO+5n5pU");
+    }
+
+    #[test]
+    fn same_passphrase_and_salt_give_different_ciphertexts() {
+        let first = encrypt_share("2-1-CgmKQZHMO+5n5pU", "hunter2", &key_derivation()).unwrap();
+        let second = encrypt_share("2-1-CgmKQZHMO+5n5pU", "hunter2", &key_derivation()).unwrap();
+        assert_ne!(first.ciphertext, second.ciphertext);
+    }
+
+    #[test]
+    fn wrong_passphrase_is_reported() {
+        let encrypted = encrypt_share("2-1-CgmKQZHMO+5n5pU", "hunter2", &key_derivation()).unwrap();
+        let raw = encrypted.clone().into_string();
+
+        let kind = |result: Result<String>| result.unwrap_err().kind().error_code();
+        assert_eq!(kind(decrypt_share(&encrypted, "hunter3")), ErrorCode::WrongPassphrase);
+        assert_eq!(kind(unlock_share(&raw, Some("hunter3"))), ErrorCode::WrongPassphrase);
+        assert_eq!(kind(unlock_share(&raw, None)), ErrorCode::MissingPassphrase);
+        assert_eq!(unlock_share(" 2-1-CgmKQZHMO+5n5pU\n", None).unwrap(), "2-1-CgmKQZHMO+5n5pU");
+
+        let mut tampered = encrypted;
+        tampered.key_derivation.iterations += 1;
+        assert_eq!(kind(decrypt_share(&tampered, "hunter2")), ErrorCode::WrongPassphrase);
+    }
+
+    #[test]
+    fn zero_iterations_are_rejected() {
+        assert!(KeyDerivation::new(0, vec![]).is_err());
+    }
+
+    #[test]
+    fn too_many_iterations_or_short_salts_are_rejected() {
+        assert!(KeyDerivation::new(MAX_ITERATIONS, vec![0; MIN_SALT_LEN]).is_ok());
+        assert!(KeyDerivation::new(MAX_ITERATIONS + 1, vec![0; MIN_SALT_LEN]).is_err());
+        assert!(KeyDerivation::new(16, vec![0; MIN_SALT_LEN - 1]).is_err());
+    }
+
+    #[test]
+    fn parsing_rejects_invalid_key_derivations() {
+        let encrypted = encrypt_share("2-1-CgmKQZHMO+5n5pU", "hunter2", &key_derivation()).unwrap();
+
+        let mut proto = to_proto(encrypted.clone());
+        proto.set_iterations(MAX_ITERATIONS + 1);
+        assert_eq!(from_proto(proto).unwrap_err().kind().error_code(), ErrorCode::ShareParsing);
+
+        let mut proto = to_proto(encrypted);
+        proto.set_salt(b"NaCl".to_vec());
+        assert_eq!(from_proto(proto).unwrap_err().kind().error_code(), ErrorCode::ShareParsing);
+    }
+}
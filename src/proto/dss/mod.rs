@@ -4,10 +4,10 @@ pub use self::secret::SecretProto;
 
 #[allow(unused_qualifications, deprecated, missing_docs)]
 mod share;
-pub use self::share::ShareProto;
+pub use self::share::{HashAlgorithmProto, ShareProto};
 
 #[allow(unused_qualifications, deprecated, missing_docs)]
 mod metadata;
 pub use self::metadata::MetaDataProto;
 
-use super::version;
+use super::{signature, version};
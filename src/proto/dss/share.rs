@@ -30,6 +30,13 @@ pub struct ShareProto {
     pub data: ::std::vec::Vec<u8>,
     pub hash: ::std::vec::Vec<u8>,
     pub meta_data: ::protobuf::SingularPtrField<super::metadata::MetaDataProto>,
+    pub share_set_id: ::std::vec::Vec<u8>,
+    pub random_padding_len: u32,
+    pub hash_len: u32,
+    pub hash_algorithm: HashAlgorithmProto,
+    pub signature: ::protobuf::RepeatedField<::std::vec::Vec<u8>>,
+    pub proof: ::std::vec::Vec<u8>,
+    pub signature_hash_algorithm: super::signature::SignatureHashAlgorithmProto,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
@@ -230,6 +237,199 @@ impl ShareProto {
     fn mut_meta_data_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<super::metadata::MetaDataProto> {
         &mut self.meta_data
     }
+
+    // bytes share_set_id = 7;
+
+    pub fn clear_share_set_id(&mut self) {
+        self.share_set_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_share_set_id(&mut self, v: ::std::vec::Vec<u8>) {
+        self.share_set_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_share_set_id(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.share_set_id
+    }
+
+    // Take field
+    pub fn take_share_set_id(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.share_set_id, ::std::vec::Vec::new())
+    }
+
+    pub fn get_share_set_id(&self) -> &[u8] {
+        &self.share_set_id
+    }
+
+    fn get_share_set_id_for_reflect(&self) -> &::std::vec::Vec<u8> {
+        &self.share_set_id
+    }
+
+    fn mut_share_set_id_for_reflect(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.share_set_id
+    }
+
+    // uint32 random_padding_len = 8;
+
+    pub fn clear_random_padding_len(&mut self) {
+        self.random_padding_len = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_random_padding_len(&mut self, v: u32) {
+        self.random_padding_len = v;
+    }
+
+    pub fn get_random_padding_len(&self) -> u32 {
+        self.random_padding_len
+    }
+
+    fn get_random_padding_len_for_reflect(&self) -> &u32 {
+        &self.random_padding_len
+    }
+
+    fn mut_random_padding_len_for_reflect(&mut self) -> &mut u32 {
+        &mut self.random_padding_len
+    }
+
+    // uint32 hash_len = 9;
+
+    pub fn clear_hash_len(&mut self) {
+        self.hash_len = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_hash_len(&mut self, v: u32) {
+        self.hash_len = v;
+    }
+
+    pub fn get_hash_len(&self) -> u32 {
+        self.hash_len
+    }
+
+    fn get_hash_len_for_reflect(&self) -> &u32 {
+        &self.hash_len
+    }
+
+    fn mut_hash_len_for_reflect(&mut self) -> &mut u32 {
+        &mut self.hash_len
+    }
+
+    // .dss.HashAlgorithmProto hash_algorithm = 10;
+
+    pub fn clear_hash_algorithm(&mut self) {
+        self.hash_algorithm = HashAlgorithmProto::SHA256;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_hash_algorithm(&mut self, v: HashAlgorithmProto) {
+        self.hash_algorithm = v;
+    }
+
+    pub fn get_hash_algorithm(&self) -> HashAlgorithmProto {
+        self.hash_algorithm
+    }
+
+    fn get_hash_algorithm_for_reflect(&self) -> &HashAlgorithmProto {
+        &self.hash_algorithm
+    }
+
+    fn mut_hash_algorithm_for_reflect(&mut self) -> &mut HashAlgorithmProto {
+        &mut self.hash_algorithm
+    }
+
+    // repeated bytes signature = 11;
+
+    pub fn clear_signature(&mut self) {
+        self.signature.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_signature(&mut self, v: ::protobuf::RepeatedField<::std::vec::Vec<u8>>) {
+        self.signature = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_signature(&mut self) -> &mut ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        &mut self.signature
+    }
+
+    // Take field
+    pub fn take_signature(&mut self) -> ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        ::std::mem::replace(&mut self.signature, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_signature(&self) -> &[::std::vec::Vec<u8>] {
+        &self.signature
+    }
+
+    fn get_signature_for_reflect(&self) -> &::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        &self.signature
+    }
+
+    fn mut_signature_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        &mut self.signature
+    }
+
+    // bytes proof = 12;
+
+    pub fn clear_proof(&mut self) {
+        self.proof.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_proof(&mut self, v: ::std::vec::Vec<u8>) {
+        self.proof = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_proof(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.proof
+    }
+
+    // Take field
+    pub fn take_proof(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.proof, ::std::vec::Vec::new())
+    }
+
+    pub fn get_proof(&self) -> &[u8] {
+        &self.proof
+    }
+
+    fn get_proof_for_reflect(&self) -> &::std::vec::Vec<u8> {
+        &self.proof
+    }
+
+    fn mut_proof_for_reflect(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.proof
+    }
+
+    // .SignatureHashAlgorithmProto signature_hash_algorithm = 13;
+
+    pub fn clear_signature_hash_algorithm(&mut self) {
+        self.signature_hash_algorithm = super::signature::SignatureHashAlgorithmProto::SIGNATURE_SHA512;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_signature_hash_algorithm(&mut self, v: super::signature::SignatureHashAlgorithmProto) {
+        self.signature_hash_algorithm = v;
+    }
+
+    pub fn get_signature_hash_algorithm(&self) -> super::signature::SignatureHashAlgorithmProto {
+        self.signature_hash_algorithm
+    }
+
+    fn get_signature_hash_algorithm_for_reflect(&self) -> &super::signature::SignatureHashAlgorithmProto {
+        &self.signature_hash_algorithm
+    }
+
+    fn mut_signature_hash_algorithm_for_reflect(&mut self) -> &mut super::signature::SignatureHashAlgorithmProto {
+        &mut self.signature_hash_algorithm
+    }
 }
 
 impl ::protobuf::Message for ShareProto {
@@ -276,6 +476,43 @@ impl ::protobuf::Message for ShareProto {
                 6 => {
                     ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.meta_data)?;
                 },
+                7 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.share_set_id)?;
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.random_padding_len = tmp;
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.hash_len = tmp;
+                },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_enum()?;
+                    self.hash_algorithm = tmp;
+                },
+                11 => {
+                    ::protobuf::rt::read_repeated_bytes_into(wire_type, is, &mut self.signature)?;
+                },
+                12 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.proof)?;
+                },
+                13 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_enum()?;
+                    self.signature_hash_algorithm = tmp;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -307,6 +544,27 @@ impl ::protobuf::Message for ShareProto {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
+        if !self.share_set_id.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(7, &self.share_set_id);
+        }
+        if self.random_padding_len != 0 {
+            my_size += ::protobuf::rt::value_size(8, self.random_padding_len, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.hash_len != 0 {
+            my_size += ::protobuf::rt::value_size(9, self.hash_len, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.hash_algorithm != HashAlgorithmProto::SHA256 {
+            my_size += ::protobuf::rt::enum_size(10, self.hash_algorithm);
+        }
+        for value in &self.signature {
+            my_size += ::protobuf::rt::bytes_size(11, &value);
+        };
+        if !self.proof.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(12, &self.proof);
+        }
+        if self.signature_hash_algorithm != super::signature::SignatureHashAlgorithmProto::SIGNATURE_SHA512 {
+            my_size += ::protobuf::rt::enum_size(13, self.signature_hash_algorithm);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -333,6 +591,27 @@ impl ::protobuf::Message for ShareProto {
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
         }
+        if !self.share_set_id.is_empty() {
+            os.write_bytes(7, &self.share_set_id)?;
+        }
+        if self.random_padding_len != 0 {
+            os.write_uint32(8, self.random_padding_len)?;
+        }
+        if self.hash_len != 0 {
+            os.write_uint32(9, self.hash_len)?;
+        }
+        if self.hash_algorithm != HashAlgorithmProto::SHA256 {
+            os.write_enum(10, self.hash_algorithm.value())?;
+        }
+        for v in &self.signature {
+            os.write_bytes(11, &v)?;
+        };
+        if !self.proof.is_empty() {
+            os.write_bytes(12, &self.proof)?;
+        }
+        if self.signature_hash_algorithm != super::signature::SignatureHashAlgorithmProto::SIGNATURE_SHA512 {
+            os.write_enum(13, self.signature_hash_algorithm.value())?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -407,6 +686,41 @@ impl ::protobuf::MessageStatic for ShareProto {
                     ShareProto::get_meta_data_for_reflect,
                     ShareProto::mut_meta_data_for_reflect,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "share_set_id",
+                    ShareProto::get_share_set_id_for_reflect,
+                    ShareProto::mut_share_set_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "random_padding_len",
+                    ShareProto::get_random_padding_len_for_reflect,
+                    ShareProto::mut_random_padding_len_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "hash_len",
+                    ShareProto::get_hash_len_for_reflect,
+                    ShareProto::mut_hash_len_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<HashAlgorithmProto>>(
+                    "hash_algorithm",
+                    ShareProto::get_hash_algorithm_for_reflect,
+                    ShareProto::mut_hash_algorithm_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "signature",
+                    ShareProto::get_signature_for_reflect,
+                    ShareProto::mut_signature_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "proof",
+                    ShareProto::get_proof_for_reflect,
+                    ShareProto::mut_proof_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::signature::SignatureHashAlgorithmProto>>(
+                    "signature_hash_algorithm",
+                    ShareProto::get_signature_hash_algorithm_for_reflect,
+                    ShareProto::mut_signature_hash_algorithm_for_reflect,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<ShareProto>(
                     "ShareProto",
                     fields,
@@ -425,6 +739,13 @@ impl ::protobuf::Clear for ShareProto {
         self.clear_data();
         self.clear_hash();
         self.clear_meta_data();
+        self.clear_share_set_id();
+        self.clear_random_padding_len();
+        self.clear_hash_len();
+        self.clear_hash_algorithm();
+        self.clear_signature();
+        self.clear_proof();
+        self.clear_signature_hash_algorithm();
         self.unknown_fields.clear();
     }
 }
@@ -441,37 +762,140 @@ impl ::protobuf::reflect::ProtobufValue for ShareProto {
     }
 }
 
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum HashAlgorithmProto {
+    SHA256 = 0,
+    SHA384 = 1,
+    SHA512 = 2,
+    SHAKE256 = 3,
+}
+
+impl ::protobuf::ProtobufEnum for HashAlgorithmProto {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<HashAlgorithmProto> {
+        match value {
+            0 => ::std::option::Option::Some(HashAlgorithmProto::SHA256),
+            1 => ::std::option::Option::Some(HashAlgorithmProto::SHA384),
+            2 => ::std::option::Option::Some(HashAlgorithmProto::SHA512),
+            3 => ::std::option::Option::Some(HashAlgorithmProto::SHAKE256),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [HashAlgorithmProto] = &[
+            HashAlgorithmProto::SHA256,
+            HashAlgorithmProto::SHA384,
+            HashAlgorithmProto::SHA512,
+            HashAlgorithmProto::SHAKE256,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static(_: ::std::option::Option<HashAlgorithmProto>) -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("HashAlgorithmProto", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for HashAlgorithmProto {
+}
+
+impl ::std::default::Default for HashAlgorithmProto {
+    fn default() -> Self {
+        HashAlgorithmProto::SHA256
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for HashAlgorithmProto {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x0fdss/share.proto\x12\x03dss\x1a\x12dss/metadata.proto\"\xb6\x01\n\n\
-    ShareProto\x12\x0e\n\x02id\x18\x01\x20\x01(\rR\x02id\x12\x1c\n\tthreshol\
-    d\x18\x02\x20\x01(\rR\tthreshold\x12!\n\x0cshares_count\x18\x03\x20\x01(\
-    \rR\x0bsharesCount\x12\x12\n\x04data\x18\x04\x20\x01(\x0cR\x04data\x12\
-    \x12\n\x04hash\x18\x05\x20\x01(\x0cR\x04hash\x12/\n\tmeta_data\x18\x06\
-    \x20\x01(\x0b2\x12.dss.MetaDataProtoR\x08metaDataJ\xe3\x03\n\x06\x12\x04\
-    \0\0\r\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\x08\n\x01\x02\x12\x03\x02\
-    \x08\x0b\n\t\n\x02\x03\0\x12\x03\x04\x07\x1b\n\n\n\x02\x04\0\x12\x04\x06\
-    \0\r\x01\n\n\n\x03\x04\0\x01\x12\x03\x06\x08\x12\n\x0b\n\x04\x04\0\x02\0\
-    \x12\x03\x07\x02\x10\n\r\n\x05\x04\0\x02\0\x04\x12\x04\x07\x02\x06\x14\n\
-    \x0c\n\x05\x04\0\x02\0\x05\x12\x03\x07\x02\x08\n\x0c\n\x05\x04\0\x02\0\
-    \x01\x12\x03\x07\t\x0b\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x07\x0e\x0f\n\
-    \x0b\n\x04\x04\0\x02\x01\x12\x03\x08\x02\x17\n\r\n\x05\x04\0\x02\x01\x04\
-    \x12\x04\x08\x02\x07\x10\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x08\x02\
-    \x08\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x08\t\x12\n\x0c\n\x05\x04\0\
-    \x02\x01\x03\x12\x03\x08\x15\x16\n\x0b\n\x04\x04\0\x02\x02\x12\x03\t\x02\
-    \x1a\n\r\n\x05\x04\0\x02\x02\x04\x12\x04\t\x02\x08\x17\n\x0c\n\x05\x04\0\
-    \x02\x02\x05\x12\x03\t\x02\x08\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\t\t\
-    \x15\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\t\x18\x19\n\x0b\n\x04\x04\0\
-    \x02\x03\x12\x03\n\x02\x11\n\r\n\x05\x04\0\x02\x03\x04\x12\x04\n\x02\t\
-    \x1a\n\x0c\n\x05\x04\0\x02\x03\x05\x12\x03\n\x02\x07\n\x0c\n\x05\x04\0\
-    \x02\x03\x01\x12\x03\n\x08\x0c\n\x0c\n\x05\x04\0\x02\x03\x03\x12\x03\n\
-    \x0f\x10\n\x0b\n\x04\x04\0\x02\x04\x12\x03\x0b\x02\x11\n\r\n\x05\x04\0\
-    \x02\x04\x04\x12\x04\x0b\x02\n\x11\n\x0c\n\x05\x04\0\x02\x04\x05\x12\x03\
-    \x0b\x02\x07\n\x0c\n\x05\x04\0\x02\x04\x01\x12\x03\x0b\x08\x0c\n\x0c\n\
-    \x05\x04\0\x02\x04\x03\x12\x03\x0b\x0f\x10\n\x0b\n\x04\x04\0\x02\x05\x12\
-    \x03\x0c\x02\"\n\r\n\x05\x04\0\x02\x05\x04\x12\x04\x0c\x02\x0b\x11\n\x0c\
-    \n\x05\x04\0\x02\x05\x06\x12\x03\x0c\x02\x13\n\x0c\n\x05\x04\0\x02\x05\
-    \x01\x12\x03\x0c\x14\x1d\n\x0c\n\x05\x04\0\x02\x05\x03\x12\x03\x0c\x20!b\
-    \x06proto3\
+    \n\x0fdss/share.proto\x12\x03dss\x1a\x12dss/metadata.proto\x1a\x0fsignat\
+    ure.proto\"\xed\x03\n\nShareProto\x12\x0e\n\x02id\x18\x01\x20\x01(\rR\
+    \x02id\x12\x1c\n\tthreshold\x18\x02\x20\x01(\rR\tthreshold\x12!\n\x0csha\
+    res_count\x18\x03\x20\x01(\rR\x0bsharesCount\x12\x12\n\x04data\x18\x04\
+    \x20\x01(\x0cR\x04data\x12\x12\n\x04hash\x18\x05\x20\x01(\x0cR\x04hash\
+    \x12/\n\tmeta_data\x18\x06\x20\x01(\x0b2\x12.dss.MetaDataProtoR\x08metaD\
+    ata\x12\x20\n\x0cshare_set_id\x18\x07\x20\x01(\x0cR\nshareSetId\x12,\n\
+    \x12random_padding_len\x18\x08\x20\x01(\rR\x10randomPaddingLen\x12\x19\n\
+    \x08hash_len\x18\t\x20\x01(\rR\x07hashLen\x12>\n\x0ehash_algorithm\x18\n\
+    \x20\x01(\x0e2\x17.dss.HashAlgorithmProtoR\rhashAlgorithm\x12\x1c\n\tsig\
+    nature\x18\x0b\x20\x03(\x0cR\tsignature\x12\x14\n\x05proof\x18\x0c\x20\
+    \x01(\x0cR\x05proof\x12V\n\x18signature_hash_algorithm\x18\r\x20\x01(\
+    \x0e2\x1c.SignatureHashAlgorithmProtoR\x16signatureHashAlgorithm*F\n\x12\
+    HashAlgorithmProto\x12\n\n\x06SHA256\x10\0\x12\n\n\x06SHA384\x10\x01\x12\
+    \n\n\x06SHA512\x10\x02\x12\x0c\n\x08SHAKE256\x10\x03J\x93\t\n\x06\x12\
+    \x04\0\0\x1c\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\x08\n\x01\x02\x12\x03\
+    \x02\x08\x0b\n\t\n\x02\x03\0\x12\x03\x04\x07\x1b\n\t\n\x02\x03\x01\x12\
+    \x03\x05\x07\x18\n\n\n\x02\x05\0\x12\x04\x07\0\x0c\x01\n\n\n\x03\x05\0\
+    \x01\x12\x03\x07\x05\x17\n\x0b\n\x04\x05\0\x02\0\x12\x03\x08\x02\r\n\x0c\
+    \n\x05\x05\0\x02\0\x01\x12\x03\x08\x02\x08\n\x0c\n\x05\x05\0\x02\0\x02\
+    \x12\x03\x08\x0b\x0c\n\x0b\n\x04\x05\0\x02\x01\x12\x03\t\x02\r\n\x0c\n\
+    \x05\x05\0\x02\x01\x01\x12\x03\t\x02\x08\n\x0c\n\x05\x05\0\x02\x01\x02\
+    \x12\x03\t\x0b\x0c\n\x0b\n\x04\x05\0\x02\x02\x12\x03\n\x02\r\n\x0c\n\x05\
+    \x05\0\x02\x02\x01\x12\x03\n\x02\x08\n\x0c\n\x05\x05\0\x02\x02\x02\x12\
+    \x03\n\x0b\x0c\n\x0b\n\x04\x05\0\x02\x03\x12\x03\x0b\x02\x0f\n\x0c\n\x05\
+    \x05\0\x02\x03\x01\x12\x03\x0b\x02\n\n\x0c\n\x05\x05\0\x02\x03\x02\x12\
+    \x03\x0b\r\x0e\n\n\n\x02\x04\0\x12\x04\x0e\0\x1c\x01\n\n\n\x03\x04\0\x01\
+    \x12\x03\x0e\x08\x12\n\x0b\n\x04\x04\0\x02\0\x12\x03\x0f\x02\x10\n\r\n\
+    \x05\x04\0\x02\0\x04\x12\x04\x0f\x02\x0e\x14\n\x0c\n\x05\x04\0\x02\0\x05\
+    \x12\x03\x0f\x02\x08\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x0f\t\x0b\n\x0c\
+    \n\x05\x04\0\x02\0\x03\x12\x03\x0f\x0e\x0f\n\x0b\n\x04\x04\0\x02\x01\x12\
+    \x03\x10\x02\x17\n\r\n\x05\x04\0\x02\x01\x04\x12\x04\x10\x02\x0f\x10\n\
+    \x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x10\x02\x08\n\x0c\n\x05\x04\0\x02\
+    \x01\x01\x12\x03\x10\t\x12\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x10\x15\
+    \x16\n\x0b\n\x04\x04\0\x02\x02\x12\x03\x11\x02\x1a\n\r\n\x05\x04\0\x02\
+    \x02\x04\x12\x04\x11\x02\x10\x17\n\x0c\n\x05\x04\0\x02\x02\x05\x12\x03\
+    \x11\x02\x08\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\x11\t\x15\n\x0c\n\x05\
+    \x04\0\x02\x02\x03\x12\x03\x11\x18\x19\n\x0b\n\x04\x04\0\x02\x03\x12\x03\
+    \x12\x02\x11\n\r\n\x05\x04\0\x02\x03\x04\x12\x04\x12\x02\x11\x1a\n\x0c\n\
+    \x05\x04\0\x02\x03\x05\x12\x03\x12\x02\x07\n\x0c\n\x05\x04\0\x02\x03\x01\
+    \x12\x03\x12\x08\x0c\n\x0c\n\x05\x04\0\x02\x03\x03\x12\x03\x12\x0f\x10\n\
+    \x0b\n\x04\x04\0\x02\x04\x12\x03\x13\x02\x11\n\r\n\x05\x04\0\x02\x04\x04\
+    \x12\x04\x13\x02\x12\x11\n\x0c\n\x05\x04\0\x02\x04\x05\x12\x03\x13\x02\
+    \x07\n\x0c\n\x05\x04\0\x02\x04\x01\x12\x03\x13\x08\x0c\n\x0c\n\x05\x04\0\
+    \x02\x04\x03\x12\x03\x13\x0f\x10\n\x0b\n\x04\x04\0\x02\x05\x12\x03\x14\
+    \x02\"\n\r\n\x05\x04\0\x02\x05\x04\x12\x04\x14\x02\x13\x11\n\x0c\n\x05\
+    \x04\0\x02\x05\x06\x12\x03\x14\x02\x13\n\x0c\n\x05\x04\0\x02\x05\x01\x12\
+    \x03\x14\x14\x1d\n\x0c\n\x05\x04\0\x02\x05\x03\x12\x03\x14\x20!\n\x0b\n\
+    \x04\x04\0\x02\x06\x12\x03\x15\x02\x19\n\r\n\x05\x04\0\x02\x06\x04\x12\
+    \x04\x15\x02\x14\"\n\x0c\n\x05\x04\0\x02\x06\x05\x12\x03\x15\x02\x07\n\
+    \x0c\n\x05\x04\0\x02\x06\x01\x12\x03\x15\x08\x14\n\x0c\n\x05\x04\0\x02\
+    \x06\x03\x12\x03\x15\x17\x18\n\x0b\n\x04\x04\0\x02\x07\x12\x03\x16\x02\
+    \x20\n\r\n\x05\x04\0\x02\x07\x04\x12\x04\x16\x02\x15\x19\n\x0c\n\x05\x04\
+    \0\x02\x07\x05\x12\x03\x16\x02\x08\n\x0c\n\x05\x04\0\x02\x07\x01\x12\x03\
+    \x16\t\x1b\n\x0c\n\x05\x04\0\x02\x07\x03\x12\x03\x16\x1e\x1f\n\x0b\n\x04\
+    \x04\0\x02\x08\x12\x03\x17\x02\x16\n\r\n\x05\x04\0\x02\x08\x04\x12\x04\
+    \x17\x02\x16\x20\n\x0c\n\x05\x04\0\x02\x08\x05\x12\x03\x17\x02\x08\n\x0c\
+    \n\x05\x04\0\x02\x08\x01\x12\x03\x17\t\x11\n\x0c\n\x05\x04\0\x02\x08\x03\
+    \x12\x03\x17\x14\x15\n\x0b\n\x04\x04\0\x02\t\x12\x03\x18\x02)\n\r\n\x05\
+    \x04\0\x02\t\x04\x12\x04\x18\x02\x17\x16\n\x0c\n\x05\x04\0\x02\t\x06\x12\
+    \x03\x18\x02\x14\n\x0c\n\x05\x04\0\x02\t\x01\x12\x03\x18\x15#\n\x0c\n\
+    \x05\x04\0\x02\t\x03\x12\x03\x18&(\n\x0b\n\x04\x04\0\x02\n\x12\x03\x19\
+    \x02\x20\n\x0c\n\x05\x04\0\x02\n\x04\x12\x03\x19\x02\n\n\x0c\n\x05\x04\0\
+    \x02\n\x05\x12\x03\x19\x0b\x10\n\x0c\n\x05\x04\0\x02\n\x01\x12\x03\x19\
+    \x11\x1a\n\x0c\n\x05\x04\0\x02\n\x03\x12\x03\x19\x1d\x1f\n\x0b\n\x04\x04\
+    \0\x02\x0b\x12\x03\x1a\x02\x13\n\r\n\x05\x04\0\x02\x0b\x04\x12\x04\x1a\
+    \x02\x19\x20\n\x0c\n\x05\x04\0\x02\x0b\x05\x12\x03\x1a\x02\x07\n\x0c\n\
+    \x05\x04\0\x02\x0b\x01\x12\x03\x1a\x08\r\n\x0c\n\x05\x04\0\x02\x0b\x03\
+    \x12\x03\x1a\x10\x12\n\x0b\n\x04\x04\0\x02\x0c\x12\x03\x1b\x02<\n\r\n\
+    \x05\x04\0\x02\x0c\x04\x12\x04\x1b\x02\x1a\x13\n\x0c\n\x05\x04\0\x02\x0c\
+    \x06\x12\x03\x1b\x02\x1d\n\x0c\n\x05\x04\0\x02\x0c\x01\x12\x03\x1b\x1e6\
+    \n\x0c\n\x05\x04\0\x02\x0c\x03\x12\x03\x1b9;b\x06proto3\
 ";
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
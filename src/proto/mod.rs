@@ -7,7 +7,17 @@ pub mod wrapped;
 #[allow(unused_qualifications, deprecated, missing_docs)]
 pub mod dss;
 
+#[allow(unused_qualifications, deprecated, missing_docs)]
+pub mod sealed;
+
+#[allow(unused_qualifications, deprecated, missing_docs)]
+pub mod xor;
+
 #[allow(unused_qualifications, deprecated, missing_docs)]
 mod version;
 
+#[allow(unused_qualifications, deprecated, missing_docs)]
+mod signature;
+
+pub use self::signature::SignatureHashAlgorithmProto;
 pub use self::version::VersionProto;
@@ -0,0 +1,11 @@
+#[allow(unused_qualifications, deprecated, missing_docs)]
+mod share;
+pub use self::share::SealedShareProto;
+
+#[allow(unused_qualifications, deprecated, missing_docs)]
+mod passphrase;
+pub use self::passphrase::PassphraseEncryptedProto;
+
+#[allow(unused_qualifications, deprecated, missing_docs)]
+mod request;
+pub use self::request::{RecoveryRequestProto, RecoveryResponseProto};
@@ -0,0 +1,382 @@
+// This file is generated. Do not edit
+// @generated
+
+// https://github.com/Manishearth/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy)]
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unsafe_code)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+
+use protobuf::Message as Message_imported_for_functions;
+use protobuf::ProtobufEnum as ProtobufEnum_imported_for_functions;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct PassphraseEncryptedProto {
+    // message fields
+    pub iterations: u32,
+    pub salt: ::std::vec::Vec<u8>,
+    pub nonce: ::std::vec::Vec<u8>,
+    pub ciphertext: ::std::vec::Vec<u8>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for PassphraseEncryptedProto {}
+
+impl PassphraseEncryptedProto {
+    pub fn new() -> PassphraseEncryptedProto {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static PassphraseEncryptedProto {
+        static mut instance: ::protobuf::lazy::Lazy<PassphraseEncryptedProto> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const PassphraseEncryptedProto,
+        };
+        unsafe {
+            instance.get(PassphraseEncryptedProto::new)
+        }
+    }
+
+    // uint32 iterations = 1;
+
+    pub fn clear_iterations(&mut self) {
+        self.iterations = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_iterations(&mut self, v: u32) {
+        self.iterations = v;
+    }
+
+    pub fn get_iterations(&self) -> u32 {
+        self.iterations
+    }
+
+    fn get_iterations_for_reflect(&self) -> &u32 {
+        &self.iterations
+    }
+
+    fn mut_iterations_for_reflect(&mut self) -> &mut u32 {
+        &mut self.iterations
+    }
+
+    // bytes salt = 2;
+
+    pub fn clear_salt(&mut self) {
+        self.salt.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_salt(&mut self, v: ::std::vec::Vec<u8>) {
+        self.salt = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_salt(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.salt
+    }
+
+    // Take field
+    pub fn take_salt(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.salt, ::std::vec::Vec::new())
+    }
+
+    pub fn get_salt(&self) -> &[u8] {
+        &self.salt
+    }
+
+    fn get_salt_for_reflect(&self) -> &::std::vec::Vec<u8> {
+        &self.salt
+    }
+
+    fn mut_salt_for_reflect(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.salt
+    }
+
+    // bytes nonce = 3;
+
+    pub fn clear_nonce(&mut self) {
+        self.nonce.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nonce(&mut self, v: ::std::vec::Vec<u8>) {
+        self.nonce = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_nonce(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.nonce
+    }
+
+    // Take field
+    pub fn take_nonce(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.nonce, ::std::vec::Vec::new())
+    }
+
+    pub fn get_nonce(&self) -> &[u8] {
+        &self.nonce
+    }
+
+    fn get_nonce_for_reflect(&self) -> &::std::vec::Vec<u8> {
+        &self.nonce
+    }
+
+    fn mut_nonce_for_reflect(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.nonce
+    }
+
+    // bytes ciphertext = 4;
+
+    pub fn clear_ciphertext(&mut self) {
+        self.ciphertext.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ciphertext(&mut self, v: ::std::vec::Vec<u8>) {
+        self.ciphertext = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ciphertext(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.ciphertext
+    }
+
+    // Take field
+    pub fn take_ciphertext(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.ciphertext, ::std::vec::Vec::new())
+    }
+
+    pub fn get_ciphertext(&self) -> &[u8] {
+        &self.ciphertext
+    }
+
+    fn get_ciphertext_for_reflect(&self) -> &::std::vec::Vec<u8> {
+        &self.ciphertext
+    }
+
+    fn mut_ciphertext_for_reflect(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.ciphertext
+    }
+}
+
+impl ::protobuf::Message for PassphraseEncryptedProto {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.iterations = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.salt)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.nonce)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.ciphertext)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.iterations != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.iterations, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.salt.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.salt);
+        }
+        if !self.nonce.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.nonce);
+        }
+        if !self.ciphertext.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.ciphertext);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.iterations != 0 {
+            os.write_uint32(1, self.iterations)?;
+        }
+        if !self.salt.is_empty() {
+            os.write_bytes(2, &self.salt)?;
+        }
+        if !self.nonce.is_empty() {
+            os.write_bytes(3, &self.nonce)?;
+        }
+        if !self.ciphertext.is_empty() {
+            os.write_bytes(4, &self.ciphertext)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for PassphraseEncryptedProto {
+    fn new() -> PassphraseEncryptedProto {
+        PassphraseEncryptedProto::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<PassphraseEncryptedProto>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "iterations",
+                    PassphraseEncryptedProto::get_iterations_for_reflect,
+                    PassphraseEncryptedProto::mut_iterations_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "salt",
+                    PassphraseEncryptedProto::get_salt_for_reflect,
+                    PassphraseEncryptedProto::mut_salt_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "nonce",
+                    PassphraseEncryptedProto::get_nonce_for_reflect,
+                    PassphraseEncryptedProto::mut_nonce_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "ciphertext",
+                    PassphraseEncryptedProto::get_ciphertext_for_reflect,
+                    PassphraseEncryptedProto::mut_ciphertext_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<PassphraseEncryptedProto>(
+                    "PassphraseEncryptedProto",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for PassphraseEncryptedProto {
+    fn clear(&mut self) {
+        self.clear_iterations();
+        self.clear_salt();
+        self.clear_nonce();
+        self.clear_ciphertext();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PassphraseEncryptedProto {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PassphraseEncryptedProto {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x17sealed/passphrase.proto\x12\x06sealed\"\x84\x01\n\x18PassphraseEnc\
+    ryptedProto\x12\x1e\n\niterations\x18\x01\x20\x01(\rR\niterations\x12\
+    \x12\n\x04salt\x18\x02\x20\x01(\x0cR\x04salt\x12\x14\n\x05nonce\x18\x03\
+    \x20\x01(\x0cR\x05nonce\x12\x1e\n\nciphertext\x18\x04\x20\x01(\x0cR\ncip\
+    hertextJ\xcc\x02\n\x06\x12\x04\0\0\t\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\
+    \n\x08\n\x01\x02\x12\x03\x02\x08\x0e\n\n\n\x02\x04\0\x12\x04\x04\0\t\x01\
+    \n\n\n\x03\x04\0\x01\x12\x03\x04\x08\x20\n\x0b\n\x04\x04\0\x02\0\x12\x03\
+    \x05\x08\x1e\n\r\n\x05\x04\0\x02\0\x04\x12\x04\x05\x08\x04\"\n\x0c\n\x05\
+    \x04\0\x02\0\x05\x12\x03\x05\x08\x0e\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\
+    \x05\x0f\x19\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x05\x1c\x1d\n\x0b\n\x04\
+    \x04\0\x02\x01\x12\x03\x06\x08\x17\n\r\n\x05\x04\0\x02\x01\x04\x12\x04\
+    \x06\x08\x05\x1e\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x06\x08\r\n\x0c\n\
+    \x05\x04\0\x02\x01\x01\x12\x03\x06\x0e\x12\n\x0c\n\x05\x04\0\x02\x01\x03\
+    \x12\x03\x06\x15\x16\n\x0b\n\x04\x04\0\x02\x02\x12\x03\x07\x08\x18\n\r\n\
+    \x05\x04\0\x02\x02\x04\x12\x04\x07\x08\x06\x17\n\x0c\n\x05\x04\0\x02\x02\
+    \x05\x12\x03\x07\x08\r\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\x07\x0e\x13\
+    \n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\x07\x16\x17\n\x0b\n\x04\x04\0\x02\
+    \x03\x12\x03\x08\x08\x1d\n\r\n\x05\x04\0\x02\x03\x04\x12\x04\x08\x08\x07\
+    \x18\n\x0c\n\x05\x04\0\x02\x03\x05\x12\x03\x08\x08\r\n\x0c\n\x05\x04\0\
+    \x02\x03\x01\x12\x03\x08\x0e\x18\n\x0c\n\x05\x04\0\x02\x03\x03\x12\x03\
+    \x08\x1b\x1cb\x06proto3\
+";
+
+static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
+    lock: ::protobuf::lazy::ONCE_INIT,
+    ptr: 0 as *const ::protobuf::descriptor::FileDescriptorProto,
+};
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    unsafe {
+        file_descriptor_proto_lazy.get(|| {
+            parse_descriptor_proto()
+        })
+    }
+}
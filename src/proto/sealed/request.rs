@@ -0,0 +1,571 @@
+// This file is generated. Do not edit
+// @generated
+
+// https://github.com/Manishearth/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy)]
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unsafe_code)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+
+use protobuf::Message as Message_imported_for_functions;
+use protobuf::ProtobufEnum as ProtobufEnum_imported_for_functions;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RecoveryRequestProto {
+    // message fields
+    pub request_id: ::std::vec::Vec<u8>,
+    pub public_key: ::std::vec::Vec<u8>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for RecoveryRequestProto {}
+
+impl RecoveryRequestProto {
+    pub fn new() -> RecoveryRequestProto {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static RecoveryRequestProto {
+        static mut instance: ::protobuf::lazy::Lazy<RecoveryRequestProto> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const RecoveryRequestProto,
+        };
+        unsafe {
+            instance.get(RecoveryRequestProto::new)
+        }
+    }
+
+    // bytes request_id = 1;
+
+    pub fn clear_request_id(&mut self) {
+        self.request_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_request_id(&mut self, v: ::std::vec::Vec<u8>) {
+        self.request_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_request_id(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.request_id
+    }
+
+    // Take field
+    pub fn take_request_id(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.request_id, ::std::vec::Vec::new())
+    }
+
+    pub fn get_request_id(&self) -> &[u8] {
+        &self.request_id
+    }
+
+    fn get_request_id_for_reflect(&self) -> &::std::vec::Vec<u8> {
+        &self.request_id
+    }
+
+    fn mut_request_id_for_reflect(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.request_id
+    }
+
+    // bytes public_key = 2;
+
+    pub fn clear_public_key(&mut self) {
+        self.public_key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_public_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.public_key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_public_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.public_key
+    }
+
+    // Take field
+    pub fn take_public_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.public_key, ::std::vec::Vec::new())
+    }
+
+    pub fn get_public_key(&self) -> &[u8] {
+        &self.public_key
+    }
+
+    fn get_public_key_for_reflect(&self) -> &::std::vec::Vec<u8> {
+        &self.public_key
+    }
+
+    fn mut_public_key_for_reflect(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.public_key
+    }
+}
+
+impl ::protobuf::Message for RecoveryRequestProto {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.request_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.public_key)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.request_id.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.request_id);
+        }
+        if !self.public_key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.public_key);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.request_id.is_empty() {
+            os.write_bytes(1, &self.request_id)?;
+        }
+        if !self.public_key.is_empty() {
+            os.write_bytes(2, &self.public_key)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for RecoveryRequestProto {
+    fn new() -> RecoveryRequestProto {
+        RecoveryRequestProto::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<RecoveryRequestProto>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "request_id",
+                    RecoveryRequestProto::get_request_id_for_reflect,
+                    RecoveryRequestProto::mut_request_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "public_key",
+                    RecoveryRequestProto::get_public_key_for_reflect,
+                    RecoveryRequestProto::mut_public_key_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<RecoveryRequestProto>(
+                    "RecoveryRequestProto",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for RecoveryRequestProto {
+    fn clear(&mut self) {
+        self.clear_request_id();
+        self.clear_public_key();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RecoveryRequestProto {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RecoveryRequestProto {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RecoveryResponseProto {
+    // message fields
+    pub request_id: ::std::vec::Vec<u8>,
+    pub ephemeral_public_key: ::std::vec::Vec<u8>,
+    pub ciphertext: ::std::vec::Vec<u8>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for RecoveryResponseProto {}
+
+impl RecoveryResponseProto {
+    pub fn new() -> RecoveryResponseProto {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static RecoveryResponseProto {
+        static mut instance: ::protobuf::lazy::Lazy<RecoveryResponseProto> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const RecoveryResponseProto,
+        };
+        unsafe {
+            instance.get(RecoveryResponseProto::new)
+        }
+    }
+
+    // bytes request_id = 1;
+
+    pub fn clear_request_id(&mut self) {
+        self.request_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_request_id(&mut self, v: ::std::vec::Vec<u8>) {
+        self.request_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_request_id(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.request_id
+    }
+
+    // Take field
+    pub fn take_request_id(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.request_id, ::std::vec::Vec::new())
+    }
+
+    pub fn get_request_id(&self) -> &[u8] {
+        &self.request_id
+    }
+
+    fn get_request_id_for_reflect(&self) -> &::std::vec::Vec<u8> {
+        &self.request_id
+    }
+
+    fn mut_request_id_for_reflect(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.request_id
+    }
+
+    // bytes ephemeral_public_key = 2;
+
+    pub fn clear_ephemeral_public_key(&mut self) {
+        self.ephemeral_public_key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ephemeral_public_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.ephemeral_public_key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ephemeral_public_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.ephemeral_public_key
+    }
+
+    // Take field
+    pub fn take_ephemeral_public_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.ephemeral_public_key, ::std::vec::Vec::new())
+    }
+
+    pub fn get_ephemeral_public_key(&self) -> &[u8] {
+        &self.ephemeral_public_key
+    }
+
+    fn get_ephemeral_public_key_for_reflect(&self) -> &::std::vec::Vec<u8> {
+        &self.ephemeral_public_key
+    }
+
+    fn mut_ephemeral_public_key_for_reflect(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.ephemeral_public_key
+    }
+
+    // bytes ciphertext = 3;
+
+    pub fn clear_ciphertext(&mut self) {
+        self.ciphertext.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ciphertext(&mut self, v: ::std::vec::Vec<u8>) {
+        self.ciphertext = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ciphertext(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.ciphertext
+    }
+
+    // Take field
+    pub fn take_ciphertext(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.ciphertext, ::std::vec::Vec::new())
+    }
+
+    pub fn get_ciphertext(&self) -> &[u8] {
+        &self.ciphertext
+    }
+
+    fn get_ciphertext_for_reflect(&self) -> &::std::vec::Vec<u8> {
+        &self.ciphertext
+    }
+
+    fn mut_ciphertext_for_reflect(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.ciphertext
+    }
+}
+
+impl ::protobuf::Message for RecoveryResponseProto {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.request_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.ephemeral_public_key)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.ciphertext)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.request_id.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.request_id);
+        }
+        if !self.ephemeral_public_key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.ephemeral_public_key);
+        }
+        if !self.ciphertext.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.ciphertext);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.request_id.is_empty() {
+            os.write_bytes(1, &self.request_id)?;
+        }
+        if !self.ephemeral_public_key.is_empty() {
+            os.write_bytes(2, &self.ephemeral_public_key)?;
+        }
+        if !self.ciphertext.is_empty() {
+            os.write_bytes(3, &self.ciphertext)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for RecoveryResponseProto {
+    fn new() -> RecoveryResponseProto {
+        RecoveryResponseProto::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<RecoveryResponseProto>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "request_id",
+                    RecoveryResponseProto::get_request_id_for_reflect,
+                    RecoveryResponseProto::mut_request_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "ephemeral_public_key",
+                    RecoveryResponseProto::get_ephemeral_public_key_for_reflect,
+                    RecoveryResponseProto::mut_ephemeral_public_key_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "ciphertext",
+                    RecoveryResponseProto::get_ciphertext_for_reflect,
+                    RecoveryResponseProto::mut_ciphertext_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<RecoveryResponseProto>(
+                    "RecoveryResponseProto",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for RecoveryResponseProto {
+    fn clear(&mut self) {
+        self.clear_request_id();
+        self.clear_ephemeral_public_key();
+        self.clear_ciphertext();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RecoveryResponseProto {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RecoveryResponseProto {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x14sealed/request.proto\x12\x06sealed\"T\n\x14RecoveryRequestProto\
+    \x12\x1d\n\nrequest_id\x18\x01\x20\x01(\x0cR\trequestId\x12\x1d\n\npubli\
+    c_key\x18\x02\x20\x01(\x0cR\tpublicKey\"\x88\x01\n\x15RecoveryResponsePr\
+    oto\x12\x1d\n\nrequest_id\x18\x01\x20\x01(\x0cR\trequestId\x120\n\x14eph\
+    emeral_public_key\x18\x02\x20\x01(\x0cR\x12ephemeralPublicKey\x12\x1e\n\
+    \nciphertext\x18\x03\x20\x01(\x0cR\nciphertextJ\xaa\x03\n\x06\x12\x04\0\
+    \0\r\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\x08\n\x01\x02\x12\x03\x02\x08\
+    \x0e\n\n\n\x02\x04\0\x12\x04\x04\0\x07\x01\n\n\n\x03\x04\0\x01\x12\x03\
+    \x04\x08\x1c\n\x0b\n\x04\x04\0\x02\0\x12\x03\x05\x08\x1d\n\r\n\x05\x04\0\
+    \x02\0\x04\x12\x04\x05\x08\x04\x1e\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\
+    \x05\x08\r\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x05\x0e\x18\n\x0c\n\x05\
+    \x04\0\x02\0\x03\x12\x03\x05\x1b\x1c\n\x0b\n\x04\x04\0\x02\x01\x12\x03\
+    \x06\x08\x1d\n\r\n\x05\x04\0\x02\x01\x04\x12\x04\x06\x08\x05\x1d\n\x0c\n\
+    \x05\x04\0\x02\x01\x05\x12\x03\x06\x08\r\n\x0c\n\x05\x04\0\x02\x01\x01\
+    \x12\x03\x06\x0e\x18\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x06\x1b\x1c\n\
+    \n\n\x02\x04\x01\x12\x04\t\0\r\x01\n\n\n\x03\x04\x01\x01\x12\x03\t\x08\
+    \x1d\n\x0b\n\x04\x04\x01\x02\0\x12\x03\n\x08\x1d\n\r\n\x05\x04\x01\x02\0\
+    \x04\x12\x04\n\x08\t\x1f\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\n\x08\r\n\
+    \x0c\n\x05\x04\x01\x02\0\x01\x12\x03\n\x0e\x18\n\x0c\n\x05\x04\x01\x02\0\
+    \x03\x12\x03\n\x1b\x1c\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\x0b\x08'\n\r\
+    \n\x05\x04\x01\x02\x01\x04\x12\x04\x0b\x08\n\x1d\n\x0c\n\x05\x04\x01\x02\
+    \x01\x05\x12\x03\x0b\x08\r\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\x0b\
+    \x0e\"\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\x0b%&\n\x0b\n\x04\x04\x01\
+    \x02\x02\x12\x03\x0c\x08\x1d\n\r\n\x05\x04\x01\x02\x02\x04\x12\x04\x0c\
+    \x08\x0b'\n\x0c\n\x05\x04\x01\x02\x02\x05\x12\x03\x0c\x08\r\n\x0c\n\x05\
+    \x04\x01\x02\x02\x01\x12\x03\x0c\x0e\x18\n\x0c\n\x05\x04\x01\x02\x02\x03\
+    \x12\x03\x0c\x1b\x1cb\x06proto3\
+";
+
+static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
+    lock: ::protobuf::lazy::ONCE_INIT,
+    ptr: 0 as *const ::protobuf::descriptor::FileDescriptorProto,
+};
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    unsafe {
+        file_descriptor_proto_lazy.get(|| {
+            parse_descriptor_proto()
+        })
+    }
+}
@@ -0,0 +1,335 @@
+// This file is generated. Do not edit
+// @generated
+
+// https://github.com/Manishearth/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy)]
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unsafe_code)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+
+use protobuf::Message as Message_imported_for_functions;
+use protobuf::ProtobufEnum as ProtobufEnum_imported_for_functions;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SealedShareProto {
+    // message fields
+    pub recipient_fingerprint: ::std::vec::Vec<u8>,
+    pub ephemeral_public_key: ::std::vec::Vec<u8>,
+    pub ciphertext: ::std::vec::Vec<u8>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for SealedShareProto {}
+
+impl SealedShareProto {
+    pub fn new() -> SealedShareProto {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static SealedShareProto {
+        static mut instance: ::protobuf::lazy::Lazy<SealedShareProto> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const SealedShareProto,
+        };
+        unsafe {
+            instance.get(SealedShareProto::new)
+        }
+    }
+
+    // bytes recipient_fingerprint = 1;
+
+    pub fn clear_recipient_fingerprint(&mut self) {
+        self.recipient_fingerprint.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_recipient_fingerprint(&mut self, v: ::std::vec::Vec<u8>) {
+        self.recipient_fingerprint = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_recipient_fingerprint(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.recipient_fingerprint
+    }
+
+    // Take field
+    pub fn take_recipient_fingerprint(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.recipient_fingerprint, ::std::vec::Vec::new())
+    }
+
+    pub fn get_recipient_fingerprint(&self) -> &[u8] {
+        &self.recipient_fingerprint
+    }
+
+    fn get_recipient_fingerprint_for_reflect(&self) -> &::std::vec::Vec<u8> {
+        &self.recipient_fingerprint
+    }
+
+    fn mut_recipient_fingerprint_for_reflect(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.recipient_fingerprint
+    }
+
+    // bytes ephemeral_public_key = 2;
+
+    pub fn clear_ephemeral_public_key(&mut self) {
+        self.ephemeral_public_key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ephemeral_public_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.ephemeral_public_key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ephemeral_public_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.ephemeral_public_key
+    }
+
+    // Take field
+    pub fn take_ephemeral_public_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.ephemeral_public_key, ::std::vec::Vec::new())
+    }
+
+    pub fn get_ephemeral_public_key(&self) -> &[u8] {
+        &self.ephemeral_public_key
+    }
+
+    fn get_ephemeral_public_key_for_reflect(&self) -> &::std::vec::Vec<u8> {
+        &self.ephemeral_public_key
+    }
+
+    fn mut_ephemeral_public_key_for_reflect(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.ephemeral_public_key
+    }
+
+    // bytes ciphertext = 3;
+
+    pub fn clear_ciphertext(&mut self) {
+        self.ciphertext.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ciphertext(&mut self, v: ::std::vec::Vec<u8>) {
+        self.ciphertext = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ciphertext(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.ciphertext
+    }
+
+    // Take field
+    pub fn take_ciphertext(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.ciphertext, ::std::vec::Vec::new())
+    }
+
+    pub fn get_ciphertext(&self) -> &[u8] {
+        &self.ciphertext
+    }
+
+    fn get_ciphertext_for_reflect(&self) -> &::std::vec::Vec<u8> {
+        &self.ciphertext
+    }
+
+    fn mut_ciphertext_for_reflect(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.ciphertext
+    }
+}
+
+impl ::protobuf::Message for SealedShareProto {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.recipient_fingerprint)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.ephemeral_public_key)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.ciphertext)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.recipient_fingerprint.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.recipient_fingerprint);
+        }
+        if !self.ephemeral_public_key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.ephemeral_public_key);
+        }
+        if !self.ciphertext.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.ciphertext);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.recipient_fingerprint.is_empty() {
+            os.write_bytes(1, &self.recipient_fingerprint)?;
+        }
+        if !self.ephemeral_public_key.is_empty() {
+            os.write_bytes(2, &self.ephemeral_public_key)?;
+        }
+        if !self.ciphertext.is_empty() {
+            os.write_bytes(3, &self.ciphertext)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for SealedShareProto {
+    fn new() -> SealedShareProto {
+        SealedShareProto::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<SealedShareProto>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "recipient_fingerprint",
+                    SealedShareProto::get_recipient_fingerprint_for_reflect,
+                    SealedShareProto::mut_recipient_fingerprint_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "ephemeral_public_key",
+                    SealedShareProto::get_ephemeral_public_key_for_reflect,
+                    SealedShareProto::mut_ephemeral_public_key_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "ciphertext",
+                    SealedShareProto::get_ciphertext_for_reflect,
+                    SealedShareProto::mut_ciphertext_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<SealedShareProto>(
+                    "SealedShareProto",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for SealedShareProto {
+    fn clear(&mut self) {
+        self.clear_recipient_fingerprint();
+        self.clear_ephemeral_public_key();
+        self.clear_ciphertext();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SealedShareProto {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SealedShareProto {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x12sealed/share.proto\x12\x06sealed\"\x99\x01\n\x10SealedShareProto\
+    \x123\n\x15recipient_fingerprint\x18\x01\x20\x01(\x0cR\x14recipientFinge\
+    rprint\x120\n\x14ephemeral_public_key\x18\x02\x20\x01(\x0cR\x12ephemeral\
+    PublicKey\x12\x1e\n\nciphertext\x18\x03\x20\x01(\x0cR\nciphertextJ\x86\
+    \x02\n\x06\x12\x04\0\0\x08\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\x08\n\
+    \x01\x02\x12\x03\x02\x08\x0e\n\n\n\x02\x04\0\x12\x04\x04\0\x08\x01\n\n\n\
+    \x03\x04\0\x01\x12\x03\x04\x08\x18\n\x0b\n\x04\x04\0\x02\0\x12\x03\x05\
+    \x08(\n\r\n\x05\x04\0\x02\0\x04\x12\x04\x05\x08\x04\x1a\n\x0c\n\x05\x04\
+    \0\x02\0\x05\x12\x03\x05\x08\r\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x05\
+    \x0e#\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x05&'\n\x0b\n\x04\x04\0\x02\
+    \x01\x12\x03\x06\x08'\n\r\n\x05\x04\0\x02\x01\x04\x12\x04\x06\x08\x05(\n\
+    \x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x06\x08\r\n\x0c\n\x05\x04\0\x02\x01\
+    \x01\x12\x03\x06\x0e\"\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x06%&\n\x0b\
+    \n\x04\x04\0\x02\x02\x12\x03\x07\x08\x1d\n\r\n\x05\x04\0\x02\x02\x04\x12\
+    \x04\x07\x08\x06'\n\x0c\n\x05\x04\0\x02\x02\x05\x12\x03\x07\x08\r\n\x0c\
+    \n\x05\x04\0\x02\x02\x01\x12\x03\x07\x0e\x18\n\x0c\n\x05\x04\0\x02\x02\
+    \x03\x12\x03\x07\x1b\x1cb\x06proto3\
+";
+
+static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
+    lock: ::protobuf::lazy::ONCE_INIT,
+    ptr: 0 as *const ::protobuf::descriptor::FileDescriptorProto,
+};
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    unsafe {
+        file_descriptor_proto_lazy.get(|| {
+            parse_descriptor_proto()
+        })
+    }
+}
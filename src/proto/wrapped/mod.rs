@@ -6,4 +6,4 @@ pub use self::secret::SecretProto;
 mod share;
 pub use self::share::ShareProto;
 
-use super::version;
+use super::{signature, version};
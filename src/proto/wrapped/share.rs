@@ -27,6 +27,9 @@ pub struct ShareProto {
     pub shamir_data: ::std::vec::Vec<u8>,
     pub signature: ::protobuf::RepeatedField<::std::vec::Vec<u8>>,
     pub proof: ::std::vec::Vec<u8>,
+    pub share_set_id: ::std::vec::Vec<u8>,
+    pub signature_hash_algorithm: super::signature::SignatureHashAlgorithmProto,
+    pub passphrase_protected: bool,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
@@ -150,6 +153,86 @@ impl ShareProto {
     fn mut_proof_for_reflect(&mut self) -> &mut ::std::vec::Vec<u8> {
         &mut self.proof
     }
+
+    // bytes share_set_id = 4;
+
+    pub fn clear_share_set_id(&mut self) {
+        self.share_set_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_share_set_id(&mut self, v: ::std::vec::Vec<u8>) {
+        self.share_set_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_share_set_id(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.share_set_id
+    }
+
+    // Take field
+    pub fn take_share_set_id(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.share_set_id, ::std::vec::Vec::new())
+    }
+
+    pub fn get_share_set_id(&self) -> &[u8] {
+        &self.share_set_id
+    }
+
+    fn get_share_set_id_for_reflect(&self) -> &::std::vec::Vec<u8> {
+        &self.share_set_id
+    }
+
+    fn mut_share_set_id_for_reflect(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.share_set_id
+    }
+
+    // .SignatureHashAlgorithmProto signature_hash_algorithm = 5;
+
+    pub fn clear_signature_hash_algorithm(&mut self) {
+        self.signature_hash_algorithm = super::signature::SignatureHashAlgorithmProto::SIGNATURE_SHA512;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_signature_hash_algorithm(&mut self, v: super::signature::SignatureHashAlgorithmProto) {
+        self.signature_hash_algorithm = v;
+    }
+
+    pub fn get_signature_hash_algorithm(&self) -> super::signature::SignatureHashAlgorithmProto {
+        self.signature_hash_algorithm
+    }
+
+    fn get_signature_hash_algorithm_for_reflect(&self) -> &super::signature::SignatureHashAlgorithmProto {
+        &self.signature_hash_algorithm
+    }
+
+    fn mut_signature_hash_algorithm_for_reflect(&mut self) -> &mut super::signature::SignatureHashAlgorithmProto {
+        &mut self.signature_hash_algorithm
+    }
+
+    // bool passphrase_protected = 6;
+
+    pub fn clear_passphrase_protected(&mut self) {
+        self.passphrase_protected = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_passphrase_protected(&mut self, v: bool) {
+        self.passphrase_protected = v;
+    }
+
+    pub fn get_passphrase_protected(&self) -> bool {
+        self.passphrase_protected
+    }
+
+    fn get_passphrase_protected_for_reflect(&self) -> &bool {
+        &self.passphrase_protected
+    }
+
+    fn mut_passphrase_protected_for_reflect(&mut self) -> &mut bool {
+        &mut self.passphrase_protected
+    }
 }
 
 impl ::protobuf::Message for ShareProto {
@@ -170,6 +253,23 @@ impl ::protobuf::Message for ShareProto {
                 3 => {
                     ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.proof)?;
                 },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.share_set_id)?;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_enum()?;
+                    self.signature_hash_algorithm = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.passphrase_protected = tmp;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -191,6 +291,15 @@ impl ::protobuf::Message for ShareProto {
         if !self.proof.is_empty() {
             my_size += ::protobuf::rt::bytes_size(3, &self.proof);
         }
+        if !self.share_set_id.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.share_set_id);
+        }
+        if self.signature_hash_algorithm != super::signature::SignatureHashAlgorithmProto::SIGNATURE_SHA512 {
+            my_size += ::protobuf::rt::enum_size(5, self.signature_hash_algorithm);
+        }
+        if self.passphrase_protected != false {
+            my_size += 2;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -206,6 +315,15 @@ impl ::protobuf::Message for ShareProto {
         if !self.proof.is_empty() {
             os.write_bytes(3, &self.proof)?;
         }
+        if !self.share_set_id.is_empty() {
+            os.write_bytes(4, &self.share_set_id)?;
+        }
+        if self.signature_hash_algorithm != super::signature::SignatureHashAlgorithmProto::SIGNATURE_SHA512 {
+            os.write_enum(5, self.signature_hash_algorithm.value())?;
+        }
+        if self.passphrase_protected != false {
+            os.write_bool(6, self.passphrase_protected)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -265,6 +383,21 @@ impl ::protobuf::MessageStatic for ShareProto {
                     ShareProto::get_proof_for_reflect,
                     ShareProto::mut_proof_for_reflect,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "share_set_id",
+                    ShareProto::get_share_set_id_for_reflect,
+                    ShareProto::mut_share_set_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::signature::SignatureHashAlgorithmProto>>(
+                    "signature_hash_algorithm",
+                    ShareProto::get_signature_hash_algorithm_for_reflect,
+                    ShareProto::mut_signature_hash_algorithm_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "passphrase_protected",
+                    ShareProto::get_passphrase_protected_for_reflect,
+                    ShareProto::mut_passphrase_protected_for_reflect,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<ShareProto>(
                     "ShareProto",
                     fields,
@@ -280,6 +413,9 @@ impl ::protobuf::Clear for ShareProto {
         self.clear_shamir_data();
         self.clear_signature();
         self.clear_proof();
+        self.clear_share_set_id();
+        self.clear_signature_hash_algorithm();
+        self.clear_passphrase_protected();
         self.unknown_fields.clear();
     }
 }
@@ -297,22 +433,36 @@ impl ::protobuf::reflect::ProtobufValue for ShareProto {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x13wrapped/share.proto\x12\x07wrapped\"a\n\nShareProto\x12\x1f\n\x0bs\
-    hamir_data\x18\x01\x20\x01(\x0cR\nshamirData\x12\x1c\n\tsignature\x18\
-    \x02\x20\x03(\x0cR\tsignature\x12\x14\n\x05proof\x18\x03\x20\x01(\x0cR\
-    \x05proofJ\x85\x02\n\x06\x12\x04\0\0\x08\x01\n\x08\n\x01\x0c\x12\x03\0\0\
-    \x12\n\x08\n\x01\x02\x12\x03\x02\x08\x0f\n\n\n\x02\x04\0\x12\x04\x04\0\
-    \x08\x01\n\n\n\x03\x04\0\x01\x12\x03\x04\x08\x12\n\x0b\n\x04\x04\0\x02\0\
-    \x12\x03\x05\x08\x1e\n\r\n\x05\x04\0\x02\0\x04\x12\x04\x05\x08\x04\x14\n\
-    \x0c\n\x05\x04\0\x02\0\x05\x12\x03\x05\x08\r\n\x0c\n\x05\x04\0\x02\0\x01\
-    \x12\x03\x05\x0e\x19\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x05\x1c\x1d\n\
-    \x0b\n\x04\x04\0\x02\x01\x12\x03\x06\x08%\n\x0c\n\x05\x04\0\x02\x01\x04\
-    \x12\x03\x06\x08\x10\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x06\x11\x16\n\
-    \x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x06\x17\x20\n\x0c\n\x05\x04\0\x02\
-    \x01\x03\x12\x03\x06#$\n\x0b\n\x04\x04\0\x02\x02\x12\x03\x07\x08\x18\n\r\
-    \n\x05\x04\0\x02\x02\x04\x12\x04\x07\x08\x06%\n\x0c\n\x05\x04\0\x02\x02\
-    \x05\x12\x03\x07\x08\r\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\x07\x0e\x13\
-    \n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\x07\x16\x17b\x06proto3\
+    \n\x13wrapped/share.proto\x12\x07wrapped\x1a\x0fsignature.proto\"\x8e\
+    \x02\n\nShareProto\x12\x1f\n\x0bshamir_data\x18\x01\x20\x01(\x0cR\nshami\
+    rData\x12\x1c\n\tsignature\x18\x02\x20\x03(\x0cR\tsignature\x12\x14\n\
+    \x05proof\x18\x03\x20\x01(\x0cR\x05proof\x12\x20\n\x0cshare_set_id\x18\
+    \x04\x20\x01(\x0cR\nshareSetId\x12V\n\x18signature_hash_algorithm\x18\
+    \x05\x20\x01(\x0e2\x1c.SignatureHashAlgorithmProtoR\x16signatureHashAlgo\
+    rithm\x121\n\x14passphrase_protected\x18\x06\x20\x01(\x08R\x13passphrase\
+    ProtectedJ\xe2\x03\n\x06\x12\x04\0\0\r\x01\n\x08\n\x01\x0c\x12\x03\0\0\
+    \x12\n\x08\n\x01\x02\x12\x03\x02\x08\x0f\n\t\n\x02\x03\0\x12\x03\x04\x07\
+    \x18\n\n\n\x02\x04\0\x12\x04\x06\0\r\x01\n\n\n\x03\x04\0\x01\x12\x03\x06\
+    \x08\x12\n\x0b\n\x04\x04\0\x02\0\x12\x03\x07\x08\x1e\n\r\n\x05\x04\0\x02\
+    \0\x04\x12\x04\x07\x08\x06\x14\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x07\
+    \x08\r\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x07\x0e\x19\n\x0c\n\x05\x04\0\
+    \x02\0\x03\x12\x03\x07\x1c\x1d\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x08\x08\
+    %\n\x0c\n\x05\x04\0\x02\x01\x04\x12\x03\x08\x08\x10\n\x0c\n\x05\x04\0\
+    \x02\x01\x05\x12\x03\x08\x11\x16\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\
+    \x08\x17\x20\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x08#$\n\x0b\n\x04\x04\
+    \0\x02\x02\x12\x03\t\x08\x18\n\r\n\x05\x04\0\x02\x02\x04\x12\x04\t\x08\
+    \x08%\n\x0c\n\x05\x04\0\x02\x02\x05\x12\x03\t\x08\r\n\x0c\n\x05\x04\0\
+    \x02\x02\x01\x12\x03\t\x0e\x13\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\t\
+    \x16\x17\n\x0b\n\x04\x04\0\x02\x03\x12\x03\n\x08\x1f\n\r\n\x05\x04\0\x02\
+    \x03\x04\x12\x04\n\x08\t\x18\n\x0c\n\x05\x04\0\x02\x03\x05\x12\x03\n\x08\
+    \r\n\x0c\n\x05\x04\0\x02\x03\x01\x12\x03\n\x0e\x1a\n\x0c\n\x05\x04\0\x02\
+    \x03\x03\x12\x03\n\x1d\x1e\n\x0b\n\x04\x04\0\x02\x04\x12\x03\x0b\x08A\n\
+    \r\n\x05\x04\0\x02\x04\x04\x12\x04\x0b\x08\n\x1f\n\x0c\n\x05\x04\0\x02\
+    \x04\x06\x12\x03\x0b\x08#\n\x0c\n\x05\x04\0\x02\x04\x01\x12\x03\x0b$<\n\
+    \x0c\n\x05\x04\0\x02\x04\x03\x12\x03\x0b?@\n\x0b\n\x04\x04\0\x02\x05\x12\
+    \x03\x0c\x08&\n\r\n\x05\x04\0\x02\x05\x04\x12\x04\x0c\x08\x0bA\n\x0c\n\
+    \x05\x04\0\x02\x05\x05\x12\x03\x0c\x08\x0c\n\x0c\n\x05\x04\0\x02\x05\x01\
+    \x12\x03\x0c\r!\n\x0c\n\x05\x04\0\x02\x05\x03\x12\x03\x0c$%b\x06proto3\
 ";
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
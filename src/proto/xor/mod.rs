@@ -0,0 +1,5 @@
+#[allow(unused_qualifications, deprecated, missing_docs)]
+mod share;
+pub use self::share::ShareProto;
+
+use super::signature;
@@ -0,0 +1,436 @@
+// This file is generated. Do not edit
+// @generated
+
+// https://github.com/Manishearth/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy)]
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unsafe_code)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+
+use protobuf::Message as Message_imported_for_functions;
+use protobuf::ProtobufEnum as ProtobufEnum_imported_for_functions;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ShareProto {
+    // message fields
+    pub xor_data: ::std::vec::Vec<u8>,
+    pub signature: ::protobuf::RepeatedField<::std::vec::Vec<u8>>,
+    pub proof: ::std::vec::Vec<u8>,
+    pub share_set_id: ::std::vec::Vec<u8>,
+    pub signature_hash_algorithm: super::signature::SignatureHashAlgorithmProto,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for ShareProto {}
+
+impl ShareProto {
+    pub fn new() -> ShareProto {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static ShareProto {
+        static mut instance: ::protobuf::lazy::Lazy<ShareProto> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ShareProto,
+        };
+        unsafe {
+            instance.get(ShareProto::new)
+        }
+    }
+
+    // bytes xor_data = 16;
+
+    pub fn clear_xor_data(&mut self) {
+        self.xor_data.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_xor_data(&mut self, v: ::std::vec::Vec<u8>) {
+        self.xor_data = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_xor_data(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.xor_data
+    }
+
+    // Take field
+    pub fn take_xor_data(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.xor_data, ::std::vec::Vec::new())
+    }
+
+    pub fn get_xor_data(&self) -> &[u8] {
+        &self.xor_data
+    }
+
+    fn get_xor_data_for_reflect(&self) -> &::std::vec::Vec<u8> {
+        &self.xor_data
+    }
+
+    fn mut_xor_data_for_reflect(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.xor_data
+    }
+
+    // repeated bytes signature = 17;
+
+    pub fn clear_signature(&mut self) {
+        self.signature.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_signature(&mut self, v: ::protobuf::RepeatedField<::std::vec::Vec<u8>>) {
+        self.signature = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_signature(&mut self) -> &mut ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        &mut self.signature
+    }
+
+    // Take field
+    pub fn take_signature(&mut self) -> ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        ::std::mem::replace(&mut self.signature, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_signature(&self) -> &[::std::vec::Vec<u8>] {
+        &self.signature
+    }
+
+    fn get_signature_for_reflect(&self) -> &::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        &self.signature
+    }
+
+    fn mut_signature_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        &mut self.signature
+    }
+
+    // bytes proof = 18;
+
+    pub fn clear_proof(&mut self) {
+        self.proof.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_proof(&mut self, v: ::std::vec::Vec<u8>) {
+        self.proof = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_proof(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.proof
+    }
+
+    // Take field
+    pub fn take_proof(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.proof, ::std::vec::Vec::new())
+    }
+
+    pub fn get_proof(&self) -> &[u8] {
+        &self.proof
+    }
+
+    fn get_proof_for_reflect(&self) -> &::std::vec::Vec<u8> {
+        &self.proof
+    }
+
+    fn mut_proof_for_reflect(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.proof
+    }
+
+    // bytes share_set_id = 19;
+
+    pub fn clear_share_set_id(&mut self) {
+        self.share_set_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_share_set_id(&mut self, v: ::std::vec::Vec<u8>) {
+        self.share_set_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_share_set_id(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.share_set_id
+    }
+
+    // Take field
+    pub fn take_share_set_id(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.share_set_id, ::std::vec::Vec::new())
+    }
+
+    pub fn get_share_set_id(&self) -> &[u8] {
+        &self.share_set_id
+    }
+
+    fn get_share_set_id_for_reflect(&self) -> &::std::vec::Vec<u8> {
+        &self.share_set_id
+    }
+
+    fn mut_share_set_id_for_reflect(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.share_set_id
+    }
+
+    // .SignatureHashAlgorithmProto signature_hash_algorithm = 20;
+
+    pub fn clear_signature_hash_algorithm(&mut self) {
+        self.signature_hash_algorithm = super::signature::SignatureHashAlgorithmProto::SIGNATURE_SHA512;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_signature_hash_algorithm(&mut self, v: super::signature::SignatureHashAlgorithmProto) {
+        self.signature_hash_algorithm = v;
+    }
+
+    pub fn get_signature_hash_algorithm(&self) -> super::signature::SignatureHashAlgorithmProto {
+        self.signature_hash_algorithm
+    }
+
+    fn get_signature_hash_algorithm_for_reflect(&self) -> &super::signature::SignatureHashAlgorithmProto {
+        &self.signature_hash_algorithm
+    }
+
+    fn mut_signature_hash_algorithm_for_reflect(&mut self) -> &mut super::signature::SignatureHashAlgorithmProto {
+        &mut self.signature_hash_algorithm
+    }
+}
+
+impl ::protobuf::Message for ShareProto {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                16 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.xor_data)?;
+                },
+                17 => {
+                    ::protobuf::rt::read_repeated_bytes_into(wire_type, is, &mut self.signature)?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.proof)?;
+                },
+                19 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.share_set_id)?;
+                },
+                20 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_enum()?;
+                    self.signature_hash_algorithm = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.xor_data.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(16, &self.xor_data);
+        }
+        for value in &self.signature {
+            my_size += ::protobuf::rt::bytes_size(17, &value);
+        };
+        if !self.proof.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(18, &self.proof);
+        }
+        if !self.share_set_id.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(19, &self.share_set_id);
+        }
+        if self.signature_hash_algorithm != super::signature::SignatureHashAlgorithmProto::SIGNATURE_SHA512 {
+            my_size += ::protobuf::rt::enum_size(20, self.signature_hash_algorithm);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.xor_data.is_empty() {
+            os.write_bytes(16, &self.xor_data)?;
+        }
+        for v in &self.signature {
+            os.write_bytes(17, &v)?;
+        };
+        if !self.proof.is_empty() {
+            os.write_bytes(18, &self.proof)?;
+        }
+        if !self.share_set_id.is_empty() {
+            os.write_bytes(19, &self.share_set_id)?;
+        }
+        if self.signature_hash_algorithm != super::signature::SignatureHashAlgorithmProto::SIGNATURE_SHA512 {
+            os.write_enum(20, self.signature_hash_algorithm.value())?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for ShareProto {
+    fn new() -> ShareProto {
+        ShareProto::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<ShareProto>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "xor_data",
+                    ShareProto::get_xor_data_for_reflect,
+                    ShareProto::mut_xor_data_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "signature",
+                    ShareProto::get_signature_for_reflect,
+                    ShareProto::mut_signature_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "proof",
+                    ShareProto::get_proof_for_reflect,
+                    ShareProto::mut_proof_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "share_set_id",
+                    ShareProto::get_share_set_id_for_reflect,
+                    ShareProto::mut_share_set_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::signature::SignatureHashAlgorithmProto>>(
+                    "signature_hash_algorithm",
+                    ShareProto::get_signature_hash_algorithm_for_reflect,
+                    ShareProto::mut_signature_hash_algorithm_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ShareProto>(
+                    "ShareProto",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for ShareProto {
+    fn clear(&mut self) {
+        self.clear_xor_data();
+        self.clear_signature();
+        self.clear_proof();
+        self.clear_share_set_id();
+        self.clear_signature_hash_algorithm();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ShareProto {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ShareProto {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0fxor/share.proto\x12\x03xor\x1a\x0fsignature.proto\"\xd5\x01\n\nSha\
+    reProto\x12\x19\n\x08xor_data\x18\x10\x20\x01(\x0cR\x07xorData\x12\x1c\n\
+    \tsignature\x18\x11\x20\x03(\x0cR\tsignature\x12\x14\n\x05proof\x18\x12\
+    \x20\x01(\x0cR\x05proof\x12\x20\n\x0cshare_set_id\x18\x13\x20\x01(\x0cR\
+    \nshareSetId\x12V\n\x18signature_hash_algorithm\x18\x14\x20\x01(\x0e2\
+    \x1c.SignatureHashAlgorithmProtoR\x16signatureHashAlgorithmJ\x9c\x03\n\
+    \x06\x12\x04\0\0\x0c\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\x08\n\x01\x02\
+    \x12\x03\x02\x08\x0b\n\t\n\x02\x03\0\x12\x03\x04\x07\x18\n\n\n\x02\x04\0\
+    \x12\x04\x06\0\x0c\x01\n\n\n\x03\x04\0\x01\x12\x03\x06\x08\x12\n\x0b\n\
+    \x04\x04\0\x02\0\x12\x03\x07\x08\x1c\n\r\n\x05\x04\0\x02\0\x04\x12\x04\
+    \x07\x08\x06\x14\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x07\x08\r\n\x0c\n\
+    \x05\x04\0\x02\0\x01\x12\x03\x07\x0e\x16\n\x0c\n\x05\x04\0\x02\0\x03\x12\
+    \x03\x07\x19\x1b\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x08\x08&\n\x0c\n\x05\
+    \x04\0\x02\x01\x04\x12\x03\x08\x08\x10\n\x0c\n\x05\x04\0\x02\x01\x05\x12\
+    \x03\x08\x11\x16\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x08\x17\x20\n\x0c\
+    \n\x05\x04\0\x02\x01\x03\x12\x03\x08#%\n\x0b\n\x04\x04\0\x02\x02\x12\x03\
+    \t\x08\x19\n\r\n\x05\x04\0\x02\x02\x04\x12\x04\t\x08\x08&\n\x0c\n\x05\
+    \x04\0\x02\x02\x05\x12\x03\t\x08\r\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\
+    \t\x0e\x13\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\t\x16\x18\n\x0b\n\x04\
+    \x04\0\x02\x03\x12\x03\n\x08\x20\n\r\n\x05\x04\0\x02\x03\x04\x12\x04\n\
+    \x08\t\x19\n\x0c\n\x05\x04\0\x02\x03\x05\x12\x03\n\x08\r\n\x0c\n\x05\x04\
+    \0\x02\x03\x01\x12\x03\n\x0e\x1a\n\x0c\n\x05\x04\0\x02\x03\x03\x12\x03\n\
+    \x1d\x1f\n\x0b\n\x04\x04\0\x02\x04\x12\x03\x0b\x08B\n\r\n\x05\x04\0\x02\
+    \x04\x04\x12\x04\x0b\x08\n\x20\n\x0c\n\x05\x04\0\x02\x04\x06\x12\x03\x0b\
+    \x08#\n\x0c\n\x05\x04\0\x02\x04\x01\x12\x03\x0b$<\n\x0c\n\x05\x04\0\x02\
+    \x04\x03\x12\x03\x0b?Ab\x06proto3\
+";
+
+static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
+    lock: ::protobuf::lazy::ONCE_INIT,
+    ptr: 0 as *const ::protobuf::descriptor::FileDescriptorProto,
+};
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    unsafe {
+        file_descriptor_proto_lazy.get(|| {
+            parse_descriptor_proto()
+        })
+    }
+}
@@ -0,0 +1,401 @@
+//! Incremental recovery of a secret, for ceremonies where shares are handed in one at a time.
+//!
+//! A `RecoverySession` validates every share as soon as it is added, against the ones
+//! added before it, so that a corrupted or foreign share is rejected while its holder is
+//! still around, rather than once everybody has left.
+
+use armor::Scheme;
+use errors::*;
+use scheme::SecretSharingScheme;
+use share::validation::validate_share_consistency;
+use share::IsSignedShare;
+use sss::{self, SSS};
+use wrapped_secrets::WrappedSecrets;
+use xor::{self, Xor};
+
+#[cfg(feature = "dss")]
+use dss::{ss1, thss};
+
+/// Accepts shares incrementally, and recovers the secret once enough of them were added.
+///
+/// The state of a session only consists of its scheme, whether signatures are verified,
+/// and the shares accepted so far, as returned by `scheme`, `verify_signatures` and `shares`.
+/// Shares are parsed and verified once, when they are added, and kept parsed afterwards.
+/// A paused session can thus be resumed with `RecoverySession::resume`, or, when the
+/// `serialization` feature is enabled, serialized and deserialized with `serde`. Note that
+/// the serialized state holds the shares themselves, and must be stored as carefully as they are.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_secrets::armor::Scheme;
+/// use rusty_secrets::recovery::RecoverySession;
+/// use rusty_secrets::sss::split_secret;
+///
+/// let shares = split_secret(3, 5, b"The secret", false).unwrap();
+///
+/// let mut session = RecoverySession::new(Scheme::SSS, false);
+/// assert_eq!(session.add_share(&shares[4]).unwrap(), 2);
+/// assert_eq!(session.add_share(&shares[0]).unwrap(), 1);
+///
+/// // A share cannot be added twice.
+/// assert!(session.add_share(&shares[0]).is_err());
+///
+/// assert_eq!(session.add_share(&shares[2]).unwrap(), 0);
+/// assert_eq!(session.recover().unwrap(), b"The secret");
+/// ```
+#[derive(Clone, Debug)]
+pub struct RecoverySession {
+    scheme: Scheme,
+    verify_signatures: bool,
+    shares: Vec<String>,
+    threshold: Option<u8>,
+    parsed: ParsedShares,
+}
+
+/// The shares accepted by a session, as parsed when they were added.
+#[derive(Clone, Debug)]
+enum ParsedShares {
+    SSS(Vec<sss::Share>),
+    Xor(Vec<xor::Share>),
+    #[cfg(feature = "dss")]
+    ThSS(Vec<thss::Share>),
+    #[cfg(feature = "dss")]
+    SS1(Vec<ss1::Share>),
+}
+
+impl ParsedShares {
+    fn new(scheme: Scheme) -> Self {
+        match scheme {
+            Scheme::SSS | Scheme::WrappedSecrets => ParsedShares::SSS(Vec::new()),
+            Scheme::Xor => ParsedShares::Xor(Vec::new()),
+            #[cfg(feature = "dss")]
+            Scheme::ThSS => ParsedShares::ThSS(Vec::new()),
+            #[cfg(feature = "dss")]
+            Scheme::SS1 => ParsedShares::SS1(Vec::new()),
+        }
+    }
+
+    /// Parses the given share, validates it against the shares accepted so far, and accepts it.
+    /// Returns the threshold of the shares. The shares are left untouched on error.
+    fn accept(&mut self, raw: &str, verify_signatures: bool) -> Result<u8> {
+        match *self {
+            ParsedShares::SSS(ref mut shares) => {
+                let share = sss::Share::from_string(raw, verify_signatures)?;
+                accept_share(shares, share, verify_signatures, |_| Ok(()))
+            }
+            ParsedShares::Xor(ref mut shares) => {
+                let share = xor::Share::from_string(raw, verify_signatures)?;
+                accept_share(shares, share, verify_signatures, |_| Ok(()))
+            }
+            #[cfg(feature = "dss")]
+            ParsedShares::ThSS(ref mut shares) => {
+                let share = thss::Share::from_string(raw)?;
+                accept_share(shares, share, verify_signatures, |_| Ok(()))
+            }
+            #[cfg(feature = "dss")]
+            ParsedShares::SS1(ref mut shares) => {
+                let share = ss1::Share::from_string(raw)?;
+                accept_share(shares, share, verify_signatures, |shares| {
+                    ss1::validate_share_parameters(shares).map(|_| ())
+                })
+            }
+        }
+    }
+
+    /// Recovers the secret shared with the given `scheme` from the shares accepted so far.
+    /// Their signatures were verified when they were accepted, and are not verified again.
+    fn recover(&self, scheme: Scheme) -> Result<Vec<u8>> {
+        match *self {
+            ParsedShares::SSS(ref shares) if scheme == Scheme::WrappedSecrets => {
+                WrappedSecrets::default().recover(shares, false)
+            }
+            ParsedShares::SSS(ref shares) => SSS::default().recover(shares, false),
+            ParsedShares::Xor(ref shares) => Xor::default().recover(shares, false),
+            #[cfg(feature = "dss")]
+            ParsedShares::ThSS(ref shares) => thss::ThSS::default().recover(shares, false),
+            #[cfg(feature = "dss")]
+            ParsedShares::SS1(ref shares) => ss1::SS1::default().recover(shares, false),
+        }
+    }
+}
+
+/// Adds `share` to the `accepted` shares if it is consistent with them, and returns their
+/// threshold. Any additional validation of the shares is performed by `validate`.
+fn accept_share<S, F>(
+    accepted: &mut Vec<S>,
+    share: S,
+    verify_signatures: bool,
+    validate: F,
+) -> Result<u8>
+where
+    S: IsSignedShare,
+    F: Fn(&[S]) -> Result<()>,
+{
+    accepted.push(share);
+
+    let result = validate_accepted_shares(accepted, verify_signatures, validate);
+    if result.is_err() {
+        accepted.pop();
+    }
+    result
+}
+
+/// The signatures of the shares accepted before the last one were verified when they were
+/// added, and come from the same dealer, so that only the last share needs to be verified,
+/// along with the one accepted before it, so as to check that it comes from the same dealer.
+fn validate_accepted_shares<S, F>(shares: &[S], verify_signatures: bool, validate: F) -> Result<u8>
+where
+    S: IsSignedShare,
+    F: Fn(&[S]) -> Result<()>,
+{
+    let (threshold, _) = validate_share_consistency(shares)?;
+    validate(shares)?;
+
+    if verify_signatures {
+        let from = shares.len().saturating_sub(2);
+        S::verify_signatures(&shares[from..])?;
+    }
+
+    Ok(threshold)
+}
+
+/// Sessions are equal when they recover the same secret from the same shares.
+impl PartialEq for RecoverySession {
+    fn eq(&self, other: &Self) -> bool {
+        self.scheme == other.scheme && self.verify_signatures == other.verify_signatures
+            && self.shares == other.shares
+    }
+}
+
+impl Eq for RecoverySession {}
+
+impl RecoverySession {
+    /// Starts a session recovering a secret shared with the given `scheme`.
+    ///
+    /// If `verify_signatures` is set, every share must be signed, and all signatures must
+    /// be valid and come from the same dealer.
+    pub fn new(scheme: Scheme, verify_signatures: bool) -> Self {
+        RecoverySession {
+            scheme,
+            verify_signatures,
+            shares: Vec::new(),
+            threshold: None,
+            parsed: ParsedShares::new(scheme),
+        }
+    }
+
+    /// Resumes a session with the given shares, as returned by `shares`.
+    /// The shares are validated as if they had been added one after the other.
+    pub fn resume(scheme: Scheme, verify_signatures: bool, shares: &[String]) -> Result<Self> {
+        let mut session = Self::new(scheme, verify_signatures);
+        for share in shares {
+            session.add_share(share)?;
+        }
+        Ok(session)
+    }
+
+    /// Validates the given share against the shares added so far, and adds it to the session.
+    /// Returns how many more shares are needed to recover the secret.
+    ///
+    /// If the share is invalid, or inconsistent with the previous ones, an error is returned
+    /// and the session is left untouched.
+    pub fn add_share(&mut self, share: &str) -> Result<u8> {
+        let share = share.trim();
+        let threshold = self.parsed.accept(share, self.verify_signatures)?;
+        self.shares.push(share.to_string());
+        self.threshold = Some(threshold);
+
+        Ok(self.missing().unwrap_or(0))
+    }
+
+    /// Returns the scheme of the secret being recovered.
+    pub fn scheme(&self) -> Scheme {
+        self.scheme
+    }
+
+    /// Returns whether the signatures of the shares are verified.
+    pub fn verify_signatures(&self) -> bool {
+        self.verify_signatures
+    }
+
+    /// Returns the shares accepted so far, in the order they were added.
+    pub fn shares(&self) -> &[String] {
+        &self.shares
+    }
+
+    /// Returns the number of shares necessary to recover the secret,
+    /// or `None` if no share was added yet.
+    pub fn threshold(&self) -> Option<u8> {
+        self.threshold
+    }
+
+    /// Returns how many more shares are needed to recover the secret,
+    /// or `None` if no share was added yet, in which case the threshold is unknown.
+    pub fn missing(&self) -> Option<u8> {
+        self.threshold.map(|threshold| {
+            let added = self.shares.len();
+            if added >= threshold as usize {
+                0
+            } else {
+                threshold - added as u8
+            }
+        })
+    }
+
+    /// Returns whether enough shares were added to recover the secret.
+    pub fn is_complete(&self) -> bool {
+        self.missing() == Some(0)
+    }
+
+    /// Recovers the secret from the shares added so far.
+    ///
+    /// For the `WrappedSecrets` scheme, the unwrapped secret is returned. Its MIME type
+    /// and version can be retrieved by passing `shares` to `wrapped_secrets::recover_secret`.
+    pub fn recover(&self) -> Result<Vec<u8>> {
+        match self.threshold {
+            None => bail!(ErrorKind::EmptyShares),
+            Some(threshold) if !self.is_complete() => {
+                bail!(ErrorKind::MissingShares(self.shares.len(), threshold))
+            }
+            Some(_) => {}
+        }
+
+        self.parsed.recover(self.scheme)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use wrapped_secrets;
+
+    #[test]
+    fn sss_session() {
+        let shares = sss::split_secret(3, 5, b"Hello, World!", true).unwrap();
+
+        let mut session = RecoverySession::new(Scheme::SSS, true);
+        assert_eq!(session.missing(), None);
+        assert_eq!(session.add_share(&shares[1]).unwrap(), 2);
+        assert_eq!(session.threshold(), Some(3));
+        assert_eq!(session.add_share(&shares[3]).unwrap(), 1);
+        assert!(!session.is_complete());
+        assert!(session.recover().is_err());
+        assert_eq!(session.add_share(&shares[0]).unwrap(), 0);
+        assert_eq!(session.recover().unwrap(), b"Hello, World!");
+
+        // Additional shares are still validated.
+        assert_eq!(session.add_share(&shares[4]).unwrap(), 0);
+        assert_eq!(session.recover().unwrap(), b"Hello, World!");
+    }
+
+    #[test]
+    fn wrapped_secrets_session() {
+        let shares = wrapped_secrets::split_secret(2, 3, b"Hello", None, false).unwrap();
+
+        let mut session = RecoverySession::new(Scheme::WrappedSecrets, false);
+        session.add_share(&shares[2]).unwrap();
+        session.add_share(&shares[0]).unwrap();
+        assert_eq!(session.recover().unwrap(), b"Hello");
+    }
+
+    #[test]
+    fn xor_session() {
+        let shares = xor::split_secret(3, b"Hello", true).unwrap();
+
+        let mut session = RecoverySession::new(Scheme::Xor, true);
+        assert_eq!(session.add_share(&shares[2]).unwrap(), 2);
+        assert_eq!(session.add_share(&shares[0]).unwrap(), 1);
+        assert!(session.recover().is_err());
+        assert_eq!(session.add_share(&shares[1]).unwrap(), 0);
+        assert_eq!(session.recover().unwrap(), b"Hello");
+    }
+
+    #[test]
+    fn invalid_shares_are_rejected() {
+        let shares = sss::split_secret(3, 5, b"Hello, World!", false).unwrap();
+        let others = sss::split_secret(3, 5, b"Hello, World!", false).unwrap();
+
+        let mut session = RecoverySession::new(Scheme::SSS, false);
+        session.add_share(&shares[0]).unwrap();
+
+        match *session.add_share(&shares[0]).unwrap_err().kind() {
+            ErrorKind::DuplicateShareId(1) => {}
+            ref kind => panic!("Unexpected error: {:?}", kind),
+        }
+        match *session.add_share(&others[1]).unwrap_err().kind() {
+            ErrorKind::InconsistentShareSets(2, ..) => {}
+            ref kind => panic!("Unexpected error: {:?}", kind),
+        }
+        match *session.add_share("2-1-garbage").unwrap_err().kind() {
+            ErrorKind::ShareParsingError(_) => {}
+            ref kind => panic!("Unexpected error: {:?}", kind),
+        }
+
+        // The session is left untouched.
+        assert_eq!(session.shares().len(), 1);
+        assert_eq!(session.missing(), Some(2));
+    }
+
+    #[test]
+    fn inconsistent_legacy_shares_are_rejected() {
+        // Shares created before share-set identifiers were introduced.
+        let share1 = "2-1-Cha7s14Q/mSwWko0ittr+/Uf79RHQMIP";
+        let share2 = "3-2-ChaydsUJDypD9ZWxwvIICh/cmZvzusOF";
+        let share3 = "2-3-ChaydsUJDypD9ZWxwvIICh/cmZvzusO";
+
+        let mut session = RecoverySession::new(Scheme::SSS, false);
+        session.add_share(share1).unwrap();
+        assert!(session.add_share(share2).is_err());
+        assert!(session.add_share(share3).is_err());
+    }
+
+    #[test]
+    fn unsigned_shares_are_rejected() {
+        let shares = sss::split_secret(2, 3, b"Hello", false).unwrap();
+        let mut session = RecoverySession::new(Scheme::SSS, true);
+        assert!(session.add_share(&shares[0]).is_err());
+        assert_eq!(session.threshold(), None);
+    }
+
+    #[test]
+    fn resume_session() {
+        let shares = sss::split_secret(3, 5, b"Hello, World!", false).unwrap();
+
+        let mut session = RecoverySession::new(Scheme::SSS, false);
+        session.add_share(&shares[0]).unwrap();
+        session.add_share(&shares[1]).unwrap();
+
+        let mut resumed =
+            RecoverySession::resume(session.scheme(), false, session.shares()).unwrap();
+        assert_eq!(resumed, session);
+        resumed.add_share(&shares[2]).unwrap();
+        assert_eq!(resumed.recover().unwrap(), b"Hello, World!");
+
+        let duplicated = vec![shares[0].clone(), shares[0].clone()];
+        assert!(RecoverySession::resume(Scheme::SSS, false, &duplicated).is_err());
+    }
+
+    #[cfg(feature = "dss")]
+    #[test]
+    fn dss_sessions() {
+        use dss::{ss1, thss};
+
+        let shares = thss::ThSS::builder()
+            .sign_shares(true)
+            .build()
+            .split_secret(2, 3, b"Hello", &None)
+            .unwrap();
+        let mut session = RecoverySession::new(Scheme::ThSS, true);
+        assert_eq!(session.add_share(&shares[0].clone().into_string()).unwrap(), 1);
+        assert_eq!(session.add_share(&shares[2].clone().into_string()).unwrap(), 0);
+        assert_eq!(session.recover().unwrap(), b"Hello");
+
+        let shares = ss1::split_secret(2, 3, b"Hello", ss1::Reproducibility::none(), &None).unwrap();
+        let mut session = RecoverySession::new(Scheme::SS1, false);
+        assert_eq!(session.add_share(&shares[1].clone().into_string()).unwrap(), 1);
+        assert_eq!(session.add_share(&shares[0].clone().into_string()).unwrap(), 0);
+        assert_eq!(session.recover().unwrap(), b"Hello");
+    }
+}
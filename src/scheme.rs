@@ -0,0 +1,238 @@
+//! A common interface to the secret sharing schemes provided by this library.
+//!
+//! Every scheme implements the `SecretSharingScheme` trait, so that code written against
+//! the trait works with any of them. When the scheme is only known at runtime, for example
+//! because it is read from a configuration file, `SchemeKind` implements the trait as well,
+//! and deals with shares in their string representation.
+//!
+//! # Examples
+//!
+//! ```
+//! use rusty_secrets::scheme::{SchemeKind, SecretSharingScheme};
+//!
+//! let scheme: SchemeKind = "sss".parse().unwrap();
+//!
+//! let shares = scheme.split(2, 3, b"The secret", false).unwrap();
+//! let secret = scheme.recover(&shares[1..], false).unwrap();
+//!
+//! assert_eq!(secret, b"The secret");
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use errors::*;
+use sss::SSS;
+use wrapped_secrets::WrappedSecrets;
+use xor::Xor;
+
+#[cfg(feature = "dss")]
+use dss::ss1::SS1;
+#[cfg(feature = "dss")]
+use dss::thss::ThSS;
+
+/// A threshold secret sharing scheme.
+pub trait SecretSharingScheme {
+    /// The type of the shares dealt by this scheme.
+    type Share;
+
+    /// Returns the kind of this scheme.
+    fn kind(&self) -> SchemeKind;
+
+    /// Splits the `secret` into `n` shares, `k` of which are necessary to recover it.
+    /// If `sign_shares` is set, every share is signed by the dealer.
+    fn split(&self, k: u8, n: u8, secret: &[u8], sign_shares: bool) -> Result<Vec<Self::Share>>;
+
+    /// Recovers the secret from the given shares.
+    /// If `verify_signatures` is set, all the shares must be signed by the same dealer.
+    fn recover(&self, shares: &[Self::Share], verify_signatures: bool) -> Result<Vec<u8>>;
+
+    /// Parses a share from its string representation.
+    fn parse_share(&self, raw: &str) -> Result<Self::Share>;
+
+    /// Formats a share into its string representation, as accepted by `parse_share`.
+    fn format_share(&self, share: &Self::Share) -> String;
+}
+
+/// The schemes provided by this library, selectable at runtime.
+///
+/// `SchemeKind` implements `SecretSharingScheme` by delegating to the default configuration
+/// of the selected scheme, with shares represented as strings.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
+pub enum SchemeKind {
+    /// Shares created by `rusty_secrets::sss`
+    SSS,
+    /// Shares created by `rusty_secrets::wrapped_secrets`
+    WrappedSecrets,
+    /// Shares created by `rusty_secrets::xor`, all of which are necessary to recover the secret
+    Xor,
+    /// Shares created by `rusty_secrets::dss::thss`
+    #[cfg(feature = "dss")]
+    ThSS,
+    /// Shares created by `rusty_secrets::dss::ss1`
+    #[cfg(feature = "dss")]
+    SS1,
+}
+
+impl fmt::Display for SchemeKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match *self {
+            SchemeKind::SSS => "SSS",
+            SchemeKind::WrappedSecrets => "WrappedSecrets",
+            SchemeKind::Xor => "Xor",
+            #[cfg(feature = "dss")]
+            SchemeKind::ThSS => "ThSS",
+            #[cfg(feature = "dss")]
+            SchemeKind::SS1 => "SS1",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl FromStr for SchemeKind {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "sss" => Ok(SchemeKind::SSS),
+            "wrappedsecrets" | "wrapped_secrets" => Ok(SchemeKind::WrappedSecrets),
+            "xor" => Ok(SchemeKind::Xor),
+            #[cfg(feature = "dss")]
+            "thss" => Ok(SchemeKind::ThSS),
+            #[cfg(feature = "dss")]
+            "ss1" => Ok(SchemeKind::SS1),
+            _ => bail!(ErrorKind::UnknownScheme(s.to_string())),
+        }
+    }
+}
+
+impl SecretSharingScheme for SchemeKind {
+    type Share = String;
+
+    fn kind(&self) -> SchemeKind {
+        *self
+    }
+
+    fn split(&self, k: u8, n: u8, secret: &[u8], sign_shares: bool) -> Result<Vec<String>> {
+        match *self {
+            SchemeKind::SSS => split_to_strings(&SSS::default(), k, n, secret, sign_shares),
+            SchemeKind::WrappedSecrets => {
+                split_to_strings(&WrappedSecrets::default(), k, n, secret, sign_shares)
+            }
+            SchemeKind::Xor => split_to_strings(&Xor::default(), k, n, secret, sign_shares),
+            #[cfg(feature = "dss")]
+            SchemeKind::ThSS => split_to_strings(&ThSS::default(), k, n, secret, sign_shares),
+            #[cfg(feature = "dss")]
+            SchemeKind::SS1 => split_to_strings(&SS1::default(), k, n, secret, sign_shares),
+        }
+    }
+
+    fn recover(&self, shares: &[String], verify_signatures: bool) -> Result<Vec<u8>> {
+        match *self {
+            SchemeKind::SSS => recover_from_strings(&SSS::default(), shares, verify_signatures),
+            SchemeKind::WrappedSecrets => {
+                recover_from_strings(&WrappedSecrets::default(), shares, verify_signatures)
+            }
+            SchemeKind::Xor => recover_from_strings(&Xor::default(), shares, verify_signatures),
+            #[cfg(feature = "dss")]
+            SchemeKind::ThSS => recover_from_strings(&ThSS::default(), shares, verify_signatures),
+            #[cfg(feature = "dss")]
+            SchemeKind::SS1 => recover_from_strings(&SS1::default(), shares, verify_signatures),
+        }
+    }
+
+    /// Checks that the given string is a valid share of this scheme,
+    /// and returns it in its canonical representation.
+    fn parse_share(&self, raw: &str) -> Result<String> {
+        match *self {
+            SchemeKind::SSS => normalize(&SSS::default(), raw),
+            SchemeKind::WrappedSecrets => normalize(&WrappedSecrets::default(), raw),
+            SchemeKind::Xor => normalize(&Xor::default(), raw),
+            #[cfg(feature = "dss")]
+            SchemeKind::ThSS => normalize(&ThSS::default(), raw),
+            #[cfg(feature = "dss")]
+            SchemeKind::SS1 => normalize(&SS1::default(), raw),
+        }
+    }
+
+    fn format_share(&self, share: &String) -> String {
+        share.clone()
+    }
+}
+
+fn split_to_strings<S: SecretSharingScheme>(
+    scheme: &S,
+    k: u8,
+    n: u8,
+    secret: &[u8],
+    sign_shares: bool,
+) -> Result<Vec<String>> {
+    let shares = scheme.split(k, n, secret, sign_shares)?;
+    Ok(shares
+        .iter()
+        .map(|share| scheme.format_share(share))
+        .collect())
+}
+
+fn recover_from_strings<S: SecretSharingScheme>(
+    scheme: &S,
+    shares: &[String],
+    verify_signatures: bool,
+) -> Result<Vec<u8>> {
+    let shares = shares
+        .iter()
+        .map(|share| scheme.parse_share(share))
+        .collect::<Result<Vec<_>>>()?;
+    scheme.recover(&shares, verify_signatures)
+}
+
+fn normalize<S: SecretSharingScheme>(scheme: &S, raw: &str) -> Result<String> {
+    scheme
+        .parse_share(raw)
+        .map(|share| scheme.format_share(&share))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn kinds() -> Vec<SchemeKind> {
+        let mut kinds = vec![SchemeKind::SSS, SchemeKind::WrappedSecrets, SchemeKind::Xor];
+        kinds.extend(dss_kinds());
+        kinds
+    }
+
+    #[cfg(feature = "dss")]
+    fn dss_kinds() -> Vec<SchemeKind> {
+        vec![SchemeKind::ThSS, SchemeKind::SS1]
+    }
+
+    #[cfg(not(feature = "dss"))]
+    fn dss_kinds() -> Vec<SchemeKind> {
+        vec![]
+    }
+
+    #[test]
+    fn every_kind_splits_then_recovers() {
+        for kind in kinds() {
+            // All the shares of the `Xor` scheme are necessary to recover the secret.
+            let k = if kind == SchemeKind::Xor { 5 } else { 3 };
+            let shares = kind.split(k, 5, b"Hello, World!", true).unwrap();
+            assert_eq!(shares.len(), 5);
+            let shares = &shares[5 - k as usize..];
+            assert_eq!(kind.recover(shares, true).unwrap(), b"Hello, World!");
+            assert_eq!(kind.parse_share(&format!(" {}\n", shares[0])).unwrap(), shares[0]);
+        }
+    }
+
+    #[test]
+    fn kinds_roundtrip_through_strings() {
+        for kind in kinds() {
+            assert_eq!(kind.to_string().parse::<SchemeKind>().unwrap(), kind);
+        }
+        assert!("shamir".parse::<SchemeKind>().is_err());
+    }
+
+}
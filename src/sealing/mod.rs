@@ -0,0 +1,502 @@
+//! Sealing of shares to the X25519 public keys of their holders, so that the dealer
+//! does not have to protect them in transit by other means.
+//!
+//! Each share is encrypted with a key derived with HKDF-SHA256 from the X25519 agreement
+//! between a fresh ephemeral key and the public key of its holder, using ChaCha20-Poly1305.
+//! The sealed share records the fingerprint of the public key it is sealed to, along with
+//! the ephemeral public key, and is represented as `sealed-` followed by the Base64
+//! encoding of its protobuf.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use rusty_secrets::sealing::{open_share, split_sealed, PrivateKey};
+//! use rusty_secrets::scheme::{SchemeKind, SecretSharingScheme};
+//!
+//! let holders = (0..3).map(|_| PrivateKey::generate().unwrap()).collect::<Vec<_>>();
+//! let recipients = holders.iter().map(PrivateKey::public_key).collect::<Vec<_>>();
+//!
+//! let scheme = SchemeKind::SSS;
+//! let sealed = split_sealed(&scheme, 2, b"The secret", &recipients, true).unwrap();
+//!
+//! // Each holder opens their share before the secret is recovered.
+//! let shares = vec![
+//!     open_share(&sealed[0], &holders[0]).unwrap(),
+//!     open_share(&sealed[2], &holders[2]).unwrap(),
+//! ];
+//! assert_eq!(scheme.recover(&shares, true).unwrap(), b"The secret");
+//!
+//! // A holder cannot open the share of another one.
+//! assert!(open_share(&sealed[1], &holders[0]).is_err());
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use base64;
+use protobuf::{self, Message};
+use ring::rand::{SecureRandom, SystemRandom};
+use ring::{aead, digest, hkdf, hmac};
+
+use errors::*;
+use proto::sealed::SealedShareProto;
+use scheme::SecretSharingScheme;
+
+mod x25519;
+
+pub mod request;
+
+/// The length in bytes of X25519 public and private keys.
+pub const KEY_LEN: usize = x25519::KEY_LEN;
+
+/// The length in bytes of a key fingerprint.
+pub const FINGERPRINT_LEN: usize = 16;
+
+const SEALED_SHARE_PREFIX: &str = "sealed-";
+
+const BASE64_CONFIG: base64::Config = base64::STANDARD_NO_PAD;
+
+/// Binds the keys of sealed shares to their use.
+const HKDF_INFO: &[u8] = b"rusty_secrets sealed share";
+
+static AEAD_ALGORITHM: &aead::Algorithm = &aead::CHACHA20_POLY1305;
+
+/// Every sealing key is derived from a fresh ephemeral key, hence is only used once.
+const NONCE: [u8; 12] = [0; 12];
+
+/// The public key of the holder of a share, to which the share is sealed.
+///
+/// Public keys are displayed and parsed in Base64.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PublicKey([u8; KEY_LEN]);
+
+impl PublicKey {
+    /// Builds a public key out of the given bytes.
+    ///
+    /// Fails if `bytes` is not exactly `KEY_LEN` bytes long.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        to_key_bytes(bytes).map(PublicKey)
+    }
+
+    /// Returns the bytes making up this key.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Returns the fingerprint of this key, that is the first `FINGERPRINT_LEN` bytes
+    /// of its SHA-256 digest.
+    pub fn fingerprint(&self) -> KeyFingerprint {
+        let digest = digest::digest(&digest::SHA256, &self.0);
+        let mut fingerprint = [0u8; FINGERPRINT_LEN];
+        fingerprint.copy_from_slice(&digest.as_ref()[..FINGERPRINT_LEN]);
+        KeyFingerprint(fingerprint)
+    }
+}
+
+impl fmt::Display for PublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", base64::encode_config(&self.0, BASE64_CONFIG))
+    }
+}
+
+impl FromStr for PublicKey {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let bytes = base64::decode_config(s.trim(), BASE64_CONFIG)
+            .chain_err(|| ErrorKind::InvalidKey("The key is not valid Base64.".to_string()))?;
+        Self::from_bytes(&bytes)
+    }
+}
+
+/// The private key of the holder of a share, with which sealed shares are opened.
+#[derive(Clone)]
+#[allow(missing_copy_implementations)]
+pub struct PrivateKey([u8; KEY_LEN]);
+
+impl PrivateKey {
+    /// Generates a new private key, using a `ring::rand::SystemRandom` as a source of entropy.
+    pub fn generate() -> Result<Self> {
+        random_key_bytes(&SystemRandom::new()).map(PrivateKey)
+    }
+
+    /// Builds a private key out of the given bytes.
+    ///
+    /// Fails if `bytes` is not exactly `KEY_LEN` bytes long.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        to_key_bytes(bytes).map(PrivateKey)
+    }
+
+    /// Returns the bytes making up this key, which must be kept secret.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Returns the public key matching this private key.
+    pub fn public_key(&self) -> PublicKey {
+        PublicKey(x25519::x25519(&self.0, &x25519::BASE_POINT))
+    }
+}
+
+impl fmt::Debug for PrivateKey {
+    /// Only shows the public key, so that the private key does not end up in logs.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("PrivateKey")
+            .field("public_key", &self.public_key())
+            .finish()
+    }
+}
+
+/// Identifies the public key a share is sealed to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct KeyFingerprint([u8; FINGERPRINT_LEN]);
+
+impl KeyFingerprint {
+    /// Builds a fingerprint out of the given bytes.
+    ///
+    /// Fails if `bytes` is not exactly `FINGERPRINT_LEN` bytes long.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() != FINGERPRINT_LEN {
+            bail!(ErrorKind::ShareParsingError(format!(
+                "Expected a key fingerprint of {} bytes, found {} bytes.",
+                FINGERPRINT_LEN,
+                bytes.len()
+            )));
+        }
+        let mut fingerprint = [0u8; FINGERPRINT_LEN];
+        fingerprint.copy_from_slice(bytes);
+        Ok(KeyFingerprint(fingerprint))
+    }
+
+    /// Returns the bytes making up this fingerprint.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl fmt::Display for KeyFingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for byte in &self.0 {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+/// A share sealed to the public key of its holder.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SealedShare {
+    /// The fingerprint of the public key the share is sealed to
+    pub recipient: KeyFingerprint,
+    /// The public part of the ephemeral key the share was sealed with
+    pub ephemeral_public_key: PublicKey,
+    /// The share, encrypted and authenticated
+    pub ciphertext: Vec<u8>,
+}
+
+impl SealedShare {
+    /// Parses a sealed share from its string representation.
+    pub fn from_string(raw: &str) -> Result<Self> {
+        let mut proto: SealedShareProto =
+            decode_prefixed(raw, SEALED_SHARE_PREFIX, "sealed share")?;
+
+        let ephemeral_public_key = PublicKey::from_bytes(proto.get_ephemeral_public_key())
+            .chain_err(|| {
+                ErrorKind::ShareParsingError("Invalid ephemeral public key.".to_string())
+            })?;
+
+        Ok(SealedShare {
+            recipient: KeyFingerprint::from_bytes(proto.get_recipient_fingerprint())?,
+            ephemeral_public_key,
+            ciphertext: proto.take_ciphertext(),
+        })
+    }
+
+    /// Formats the sealed share into its string representation, as accepted by `from_string`.
+    pub fn into_string(self) -> String {
+        let mut proto = SealedShareProto::new();
+        proto.set_recipient_fingerprint(self.recipient.as_bytes().to_vec());
+        proto.set_ephemeral_public_key(self.ephemeral_public_key.as_bytes().to_vec());
+        proto.set_ciphertext(self.ciphertext);
+        encode_prefixed(&proto, SEALED_SHARE_PREFIX)
+    }
+}
+
+/// Whether the given string looks like a sealed share, rather than a plain one.
+pub fn is_sealed_share(raw: &str) -> bool {
+    raw.trim().starts_with(SEALED_SHARE_PREFIX)
+}
+
+/// Splits the `secret` with the given `scheme` into as many shares as there are `recipients`,
+/// `k` of which are necessary to recover it, and seals each share to its recipient.
+///
+/// The `i`-th sealed share holds the share of identifier `i + 1`.
+pub fn split_sealed<S: SecretSharingScheme>(
+    scheme: &S,
+    k: u8,
+    secret: &[u8],
+    recipients: &[PublicKey],
+    sign_shares: bool,
+) -> Result<Vec<SealedShare>> {
+    if recipients.len() > usize::from(MAX_SHARES) {
+        bail!(ErrorKind::InvalidShareCountMax(recipients.len(), MAX_SHARES));
+    }
+
+    let shares = scheme.split(k, recipients.len() as u8, secret, sign_shares)?;
+    let random = SystemRandom::new();
+    shares
+        .iter()
+        .zip(recipients)
+        .map(|(share, recipient)| seal(&scheme.format_share(share), recipient, &random))
+        .collect()
+}
+
+/// Seals the given share, in its string representation, to the given `recipient`,
+/// using a `ring::rand::SystemRandom` as a source of entropy.
+pub fn seal_share(share: &str, recipient: &PublicKey) -> Result<SealedShare> {
+    seal(share, recipient, &SystemRandom::new())
+}
+
+fn seal(share: &str, recipient: &PublicKey, random: &SecureRandom) -> Result<SealedShare> {
+    let recipient_fingerprint = recipient.fingerprint();
+    let (ephemeral_public_key, ciphertext) = encrypt_to(
+        share.as_bytes(),
+        recipient,
+        HKDF_INFO,
+        recipient_fingerprint.as_bytes(),
+        random,
+    )?;
+
+    Ok(SealedShare {
+        recipient: recipient_fingerprint,
+        ephemeral_public_key,
+        ciphertext,
+    })
+}
+
+/// Opens the given sealed share with the `private_key` of its holder, and returns
+/// the share in its string representation, as accepted by the scheme which dealt it.
+///
+/// Fails with `WrongRecipient` if the share is sealed to another key,
+/// and with `CannotOpenShare` if it was tampered with.
+pub fn open_share(sealed: &SealedShare, private_key: &PrivateKey) -> Result<String> {
+    let public_key = private_key.public_key();
+    if sealed.recipient != public_key.fingerprint() {
+        bail!(ErrorKind::WrongRecipient(
+            sealed.recipient,
+            public_key.fingerprint()
+        ));
+    }
+
+    let plaintext = decrypt_with(
+        &sealed.ciphertext,
+        &sealed.ephemeral_public_key,
+        private_key,
+        HKDF_INFO,
+        sealed.recipient.as_bytes(),
+    )?;
+
+    String::from_utf8(plaintext).map_err(|_| ErrorKind::CannotOpenShare.into())
+}
+
+/// Encrypts `plaintext` to the `recipient` with a fresh ephemeral key, deriving the key
+/// with the given HKDF `info`, and authenticating the additional data `ad` along with it.
+/// Returns the public part of the ephemeral key, along with the ciphertext.
+fn encrypt_to(
+    plaintext: &[u8],
+    recipient: &PublicKey,
+    info: &[u8],
+    ad: &[u8],
+    random: &SecureRandom,
+) -> Result<(PublicKey, Vec<u8>)> {
+    let ephemeral_key = random_key_bytes(random)?;
+    let ephemeral_public_key = PublicKey(x25519::x25519(&ephemeral_key, &x25519::BASE_POINT));
+    let shared_secret = x25519::x25519(&ephemeral_key, &recipient.0);
+    if is_zero(&shared_secret) {
+        bail!(ErrorKind::InvalidKey(
+            "The public key is a point of small order.".to_string()
+        ));
+    }
+
+    let key = derive_key(&shared_secret, &ephemeral_public_key, recipient, info);
+    let ciphertext = seal_with(AEAD_ALGORITHM, &key, &NONCE, ad, plaintext);
+
+    Ok((ephemeral_public_key, ciphertext))
+}
+
+/// Decrypts a ciphertext encrypted by `encrypt_to` with the same `info` and `ad`.
+///
+/// Fails with `CannotOpenShare` if it was tampered with.
+fn decrypt_with(
+    ciphertext: &[u8],
+    ephemeral_public_key: &PublicKey,
+    private_key: &PrivateKey,
+    info: &[u8],
+    ad: &[u8],
+) -> Result<Vec<u8>> {
+    let shared_secret = x25519::x25519(&private_key.0, &ephemeral_public_key.0);
+    if is_zero(&shared_secret) {
+        bail!(ErrorKind::CannotOpenShare);
+    }
+
+    let key = derive_key(
+        &shared_secret,
+        ephemeral_public_key,
+        &private_key.public_key(),
+        info,
+    );
+    let opening_key = aead::OpeningKey::new(AEAD_ALGORITHM, &key).unwrap();
+
+    let mut in_out = ciphertext.to_vec();
+    let plaintext = aead::open_in_place(&opening_key, &NONCE, ad, 0, &mut in_out)
+        .map_err(|_| ErrorKind::CannotOpenShare)?;
+    Ok(plaintext.to_vec())
+}
+
+/// Encrypts and authenticates `plaintext` with the given `algorithm`, key and nonce,
+/// authenticating the additional data `ad` along with it, and returns the ciphertext
+/// followed by the tag.
+pub(crate) fn seal_with(
+    algorithm: &'static aead::Algorithm,
+    key: &[u8],
+    nonce: &[u8],
+    ad: &[u8],
+    plaintext: &[u8],
+) -> Vec<u8> {
+    let sealing_key = aead::SealingKey::new(algorithm, key).unwrap();
+
+    let tag_len = algorithm.tag_len();
+    let mut ciphertext = plaintext.to_vec();
+    ciphertext.extend(vec![0u8; tag_len]);
+    let len = aead::seal_in_place(&sealing_key, nonce, ad, &mut ciphertext, tag_len).unwrap();
+    ciphertext.truncate(len);
+    ciphertext
+}
+
+/// Formats the given protobuf as the given `prefix` followed by its Base64 encoding,
+/// which is how sealed and encrypted shares, and recovery requests and responses, are represented.
+pub(crate) fn encode_prefixed<M: Message>(proto: &M, prefix: &str) -> String {
+    let bytes = proto.write_to_bytes().unwrap();
+    format!("{}{}", prefix, base64::encode_config(&bytes, BASE64_CONFIG))
+}
+
+/// Parses a protobuf formatted by `encode_prefixed` with the given `prefix`,
+/// which errors refer to by the given `name`.
+pub(crate) fn decode_prefixed<M: Message>(raw: &str, prefix: &str, name: &str) -> Result<M> {
+    let raw = raw.trim();
+    if !raw.starts_with(prefix) {
+        bail!(ErrorKind::ShareParsingError(format!(
+            "The {} must start with '{}'.",
+            name, prefix
+        )));
+    }
+
+    let data = base64::decode_config(&raw[prefix.len()..], BASE64_CONFIG).chain_err(|| {
+        ErrorKind::ShareParsingError(format!("Base64 decoding of the {} failed.", name))
+    })?;
+
+    protobuf::parse_from_bytes::<M>(&data).map_err(|e| {
+        ErrorKind::ShareParsingError(format!(
+            "Protobuf decoding of the {} failed with error: {} .",
+            name, e
+        )).into()
+    })
+}
+
+/// Derives an encryption key from the X25519 shared secret,
+/// bound to both public keys taking part in the agreement.
+fn derive_key(
+    shared_secret: &[u8],
+    ephemeral_public_key: &PublicKey,
+    recipient: &PublicKey,
+    info: &[u8],
+) -> Vec<u8> {
+    let mut salt = ephemeral_public_key.as_bytes().to_vec();
+    salt.extend_from_slice(recipient.as_bytes());
+    let salt = hmac::SigningKey::new(&digest::SHA256, &salt);
+
+    let mut key = vec![0u8; AEAD_ALGORITHM.key_len()];
+    hkdf::extract_and_expand(&salt, shared_secret, info, &mut key);
+    key
+}
+
+fn random_key_bytes(random: &SecureRandom) -> Result<[u8; KEY_LEN]> {
+    let mut bytes = [0u8; KEY_LEN];
+    random
+        .fill(&mut bytes)
+        .chain_err(|| ErrorKind::CannotGenerateRandomNumbers)?;
+    Ok(bytes)
+}
+
+fn to_key_bytes(bytes: &[u8]) -> Result<[u8; KEY_LEN]> {
+    if bytes.len() != KEY_LEN {
+        bail!(ErrorKind::InvalidKey(format!(
+            "Expected a key of {} bytes, found {} bytes.",
+            KEY_LEN,
+            bytes.len()
+        )));
+    }
+    let mut key = [0u8; KEY_LEN];
+    key.copy_from_slice(bytes);
+    Ok(key)
+}
+
+/// Whether all the given bytes are zero, in constant time.
+fn is_zero(bytes: &[u8]) -> bool {
+    bytes.iter().fold(0, |acc, &byte| acc | byte) == 0
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn key(byte: u8) -> PrivateKey {
+        PrivateKey::from_bytes(&[byte; KEY_LEN]).unwrap()
+    }
+
+    #[test]
+    fn sealed_share_roundtrips_through_strings() {
+        let sealed = seal_share("2-1-CgmKQZHMO+5n5pU", &key(1).public_key()).unwrap();
+        let raw = sealed.clone().into_string();
+
+        assert!(is_sealed_share(&raw));
+        assert!(!is_sealed_share("2-1-CgmKQZHMO+5n5pU"));
+        assert_eq!(SealedShare::from_string(&format!(" {}\n", raw)).unwrap(), sealed);
+    }
+
+    #[test]
+    fn opens_with_the_recipient_key_only() {
+        let sealed = seal_share("2-1-CgmKQZHMO+5n5pU", &key(1).public_key()).unwrap();
+        assert_eq!(sealed.recipient, key(1).public_key().fingerprint());
+        assert_eq!(open_share(&sealed, &key(1)).unwrap(), "2-1-CgmKQZHMO+5n5pU");
+
+        match *open_share(&sealed, &key(2)).unwrap_err().kind() {
+            ErrorKind::WrongRecipient(recipient, found) => {
+                assert_eq!(recipient, key(1).public_key().fingerprint());
+                assert_eq!(found, key(2).public_key().fingerprint());
+            }
+            ref kind => panic!("Unexpected error: {}", kind),
+        }
+    }
+
+    #[test]
+    fn tampering_is_detected() {
+        let sealed = seal_share("2-1-CgmKQZHMO+5n5pU", &key(1).public_key()).unwrap();
+
+        let mut tampered = sealed.clone();
+        tampered.ciphertext[0] ^= 1;
+        assert!(open_share(&tampered, &key(1)).is_err());
+
+        let mut tampered = sealed;
+        tampered.ephemeral_public_key = key(3).public_key();
+        assert!(open_share(&tampered, &key(1)).is_err());
+    }
+
+    #[test]
+    fn public_keys_roundtrip_through_strings() {
+        let public_key = key(7).public_key();
+        assert_eq!(public_key.to_string().parse::<PublicKey>().unwrap(), public_key);
+        assert!("AAAA".parse::<PublicKey>().is_err());
+    }
+}
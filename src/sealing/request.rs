@@ -0,0 +1,327 @@
+//! A recovery protocol in which share holders send their shares to whoever recovers
+//! the secret encrypted to an ephemeral key, so that shares captured in transit are useless.
+//!
+//! The recoverer starts a `Recoverer`, which generates an ephemeral X25519 key pair along
+//! with a random request identifier, and sends the resulting `RecoveryRequest` to the holders.
+//! Each holder answers with a `RecoveryResponse`, holding their share encrypted to the
+//! ephemeral key and bound to the request identifier. The recoverer then opens the responses
+//! and recovers the secret. A response is only accepted once, and only by the recoverer
+//! which issued the request it answers, whose ephemeral private key never leaves memory.
+//!
+//! Requests and responses are represented as `recovery-request-` and `recovery-response-`
+//! followed by the Base64 encoding of their protobuf.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use rusty_secrets::sealing::request::{respond, Recoverer, RecoveryRequest};
+//! use rusty_secrets::scheme::{SchemeKind, SecretSharingScheme};
+//!
+//! let scheme = SchemeKind::SSS;
+//! let shares = scheme.split(2, 3, b"The secret", false).unwrap();
+//!
+//! let mut recoverer = Recoverer::new().unwrap();
+//! let request = recoverer.request().clone().into_string();
+//!
+//! // Each holder answers the request with their share.
+//! let request = RecoveryRequest::from_string(&request).unwrap();
+//! let responses = vec![
+//!     respond(&request, &shares[0]).unwrap(),
+//!     respond(&request, &shares[2]).unwrap(),
+//! ];
+//!
+//! let secret = recoverer.recover_secret(&scheme, &responses, false).unwrap();
+//! assert_eq!(secret, b"The secret");
+//!
+//! // Responses cannot be replayed.
+//! assert!(recoverer.open_response(&responses[0]).is_err());
+//! ```
+
+use std::collections::HashSet;
+use std::fmt;
+
+use ring::rand::{SecureRandom, SystemRandom};
+
+use errors::*;
+use proto::sealed::{RecoveryRequestProto, RecoveryResponseProto};
+use scheme::SecretSharingScheme;
+
+use super::{decode_prefixed, decrypt_with, encode_prefixed, encrypt_to, PrivateKey, PublicKey};
+
+/// The length in bytes of a request identifier.
+pub const REQUEST_ID_LEN: usize = 16;
+
+const REQUEST_PREFIX: &str = "recovery-request-";
+
+const RESPONSE_PREFIX: &str = "recovery-response-";
+
+/// Binds the keys of recovery responses to their use.
+const HKDF_INFO: &[u8] = b"rusty_secrets recovery response";
+
+/// A random identifier of a recovery request, to which the responses are bound.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RequestId([u8; REQUEST_ID_LEN]);
+
+impl RequestId {
+    /// Builds a request identifier out of the given bytes.
+    ///
+    /// Fails if `bytes` is not exactly `REQUEST_ID_LEN` bytes long.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() != REQUEST_ID_LEN {
+            bail!(ErrorKind::ShareParsingError(format!(
+                "Expected a request identifier of {} bytes, found {} bytes.",
+                REQUEST_ID_LEN,
+                bytes.len()
+            )));
+        }
+        let mut id = [0u8; REQUEST_ID_LEN];
+        id.copy_from_slice(bytes);
+        Ok(RequestId(id))
+    }
+
+    /// Returns the bytes making up this identifier.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    fn random(random: &SecureRandom) -> Result<Self> {
+        let mut bytes = [0u8; REQUEST_ID_LEN];
+        random
+            .fill(&mut bytes)
+            .chain_err(|| ErrorKind::CannotGenerateRandomNumbers)?;
+        Ok(RequestId(bytes))
+    }
+}
+
+impl fmt::Display for RequestId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for byte in &self.0 {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+/// A request for shares, sent by the recoverer to the share holders.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecoveryRequest {
+    /// The identifier of the request
+    pub id: RequestId,
+    /// The ephemeral public key the shares are to be encrypted to
+    pub public_key: PublicKey,
+}
+
+impl RecoveryRequest {
+    /// Parses a recovery request from its string representation.
+    pub fn from_string(raw: &str) -> Result<Self> {
+        let proto: RecoveryRequestProto = decode_prefixed(raw, REQUEST_PREFIX, "recovery request")?;
+
+        Ok(RecoveryRequest {
+            id: RequestId::from_bytes(proto.get_request_id())?,
+            public_key: PublicKey::from_bytes(proto.get_public_key())?,
+        })
+    }
+
+    /// Formats the request into its string representation, as accepted by `from_string`.
+    pub fn into_string(self) -> String {
+        let mut proto = RecoveryRequestProto::new();
+        proto.set_request_id(self.id.as_bytes().to_vec());
+        proto.set_public_key(self.public_key.as_bytes().to_vec());
+        encode_prefixed(&proto, REQUEST_PREFIX)
+    }
+}
+
+/// The answer of a share holder to a recovery request, holding their share.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecoveryResponse {
+    /// The identifier of the request this response answers
+    pub request_id: RequestId,
+    /// The public part of the ephemeral key the share was encrypted with
+    pub ephemeral_public_key: PublicKey,
+    /// The share, encrypted and authenticated
+    pub ciphertext: Vec<u8>,
+}
+
+impl RecoveryResponse {
+    /// Parses a recovery response from its string representation.
+    pub fn from_string(raw: &str) -> Result<Self> {
+        let mut proto: RecoveryResponseProto =
+            decode_prefixed(raw, RESPONSE_PREFIX, "recovery response")?;
+
+        let ephemeral_public_key = PublicKey::from_bytes(proto.get_ephemeral_public_key())
+            .chain_err(|| {
+                ErrorKind::ShareParsingError("Invalid ephemeral public key.".to_string())
+            })?;
+
+        Ok(RecoveryResponse {
+            request_id: RequestId::from_bytes(proto.get_request_id())?,
+            ephemeral_public_key,
+            ciphertext: proto.take_ciphertext(),
+        })
+    }
+
+    /// Formats the response into its string representation, as accepted by `from_string`.
+    pub fn into_string(self) -> String {
+        let mut proto = RecoveryResponseProto::new();
+        proto.set_request_id(self.request_id.as_bytes().to_vec());
+        proto.set_ephemeral_public_key(self.ephemeral_public_key.as_bytes().to_vec());
+        proto.set_ciphertext(self.ciphertext);
+        encode_prefixed(&proto, RESPONSE_PREFIX)
+    }
+}
+
+/// Whether the given string looks like a recovery response, rather than a plain share.
+pub fn is_recovery_response(raw: &str) -> bool {
+    raw.trim().starts_with(RESPONSE_PREFIX)
+}
+
+/// Answers the given recovery `request` with the given share, in its string representation,
+/// using a `ring::rand::SystemRandom` as a source of entropy.
+pub fn respond(request: &RecoveryRequest, share: &str) -> Result<RecoveryResponse> {
+    let (ephemeral_public_key, ciphertext) = encrypt_to(
+        share.trim().as_bytes(),
+        &request.public_key,
+        HKDF_INFO,
+        request.id.as_bytes(),
+        &SystemRandom::new(),
+    )?;
+
+    Ok(RecoveryResponse {
+        request_id: request.id,
+        ephemeral_public_key,
+        ciphertext,
+    })
+}
+
+/// Issues a recovery request, and opens the responses to it.
+#[derive(Debug)]
+pub struct Recoverer {
+    request: RecoveryRequest,
+    private_key: PrivateKey,
+    received: HashSet<PublicKey>,
+}
+
+impl Recoverer {
+    /// Generates an ephemeral key pair and a request identifier,
+    /// using a `ring::rand::SystemRandom` as a source of entropy.
+    pub fn new() -> Result<Self> {
+        let private_key = PrivateKey::generate()?;
+        let request = RecoveryRequest {
+            id: RequestId::random(&SystemRandom::new())?,
+            public_key: private_key.public_key(),
+        };
+
+        Ok(Recoverer {
+            request,
+            private_key,
+            received: HashSet::new(),
+        })
+    }
+
+    /// Returns the request to send to the share holders.
+    pub fn request(&self) -> &RecoveryRequest {
+        &self.request
+    }
+
+    /// Opens the given response, and returns the share it holds in its string representation,
+    /// as accepted by the scheme which dealt it.
+    ///
+    /// Fails with `WrongRequest` if the response answers another request, with
+    /// `ReplayedResponse` if it was already opened, and with `CannotOpenShare` if it was
+    /// tampered with.
+    pub fn open_response(&mut self, response: &RecoveryResponse) -> Result<String> {
+        let share = self.open(response, &HashSet::new())?;
+        self.received.insert(response.ephemeral_public_key);
+        Ok(share)
+    }
+
+    /// Opens the given responses, and recovers the secret from the shares they hold
+    /// with the given `scheme`.
+    ///
+    /// The responses are only recorded as received if all of them could be opened.
+    pub fn recover_secret<S: SecretSharingScheme>(
+        &mut self,
+        scheme: &S,
+        responses: &[RecoveryResponse],
+        verify_signatures: bool,
+    ) -> Result<Vec<u8>> {
+        let mut received = HashSet::new();
+        let mut shares = Vec::with_capacity(responses.len());
+        for response in responses {
+            let share = self.open(response, &received)?;
+            shares.push(scheme.parse_share(&share)?);
+            received.insert(response.ephemeral_public_key);
+        }
+
+        self.received.extend(received);
+        scheme.recover(&shares, verify_signatures)
+    }
+
+    fn open(&self, response: &RecoveryResponse, pending: &HashSet<PublicKey>) -> Result<String> {
+        if response.request_id != self.request.id {
+            bail!(ErrorKind::WrongRequest(
+                self.request.id,
+                response.request_id
+            ));
+        }
+
+        let key = &response.ephemeral_public_key;
+        if self.received.contains(key) || pending.contains(key) {
+            bail!(ErrorKind::ReplayedResponse);
+        }
+
+        let share = decrypt_with(
+            &response.ciphertext,
+            key,
+            &self.private_key,
+            HKDF_INFO,
+            self.request.id.as_bytes(),
+        )?;
+
+        String::from_utf8(share).map_err(|_| ErrorKind::CannotOpenShare.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn requests_and_responses_roundtrip_through_strings() {
+        let recoverer = Recoverer::new().unwrap();
+        let request = recoverer.request().clone();
+        let parsed = RecoveryRequest::from_string(&request.clone().into_string()).unwrap();
+        assert_eq!(parsed, request);
+
+        let response = respond(&request, "2-1-CgmKQZHMO+5n5pU").unwrap();
+        let raw = format!(" {}\n", response.clone().into_string());
+        assert_eq!(RecoveryResponse::from_string(&raw).unwrap(), response);
+
+        assert!(is_recovery_response(&raw));
+        assert!(RecoveryRequest::from_string(&raw).is_err());
+        assert!(RecoveryResponse::from_string("recovery-response-!").is_err());
+    }
+
+    #[test]
+    fn responses_are_only_opened_once_by_their_recoverer() {
+        let mut recoverer = Recoverer::new().unwrap();
+        let mut other = Recoverer::new().unwrap();
+
+        let response = respond(recoverer.request(), "2-1-CgmKQZHMO+5n5pU").unwrap();
+        let kind = |result: Result<String>| result.unwrap_err().kind().error_code();
+
+        assert_eq!(kind(other.open_response(&response)), ErrorCode::WrongRequest);
+        assert_eq!(recoverer.open_response(&response).unwrap(), "2-1-CgmKQZHMO+5n5pU");
+        assert_eq!(kind(recoverer.open_response(&response)), ErrorCode::ReplayedResponse);
+
+        // A response redirected to another request cannot be opened.
+        let mut redirected = respond(recoverer.request(), "2-2-CgmKQZHMO+5n5pU").unwrap();
+        redirected.request_id = other.request().id;
+        assert_eq!(kind(other.open_response(&redirected)), ErrorCode::CannotOpenShare);
+
+        let mut tampered = respond(recoverer.request(), "2-3-CgmKQZHMO+5n5pU").unwrap();
+        tampered.ciphertext[0] ^= 1;
+        assert_eq!(kind(recoverer.open_response(&tampered)), ErrorCode::CannotOpenShare);
+    }
+}
@@ -0,0 +1,262 @@
+//! The X25519 function (RFC 7748), which `ring` only exposes for ephemeral keys,
+//! while opening a sealed share requires the long-term private key of its holder.
+//!
+//! Field elements are represented as 16 limbs of 16 bits, following TweetNaCl,
+//! and every operation runs in constant time.
+
+/// The length in bytes of X25519 scalars and points.
+pub(crate) const KEY_LEN: usize = 32;
+
+/// The u-coordinate of the base point.
+pub(crate) const BASE_POINT: [u8; KEY_LEN] = [
+    9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+];
+
+/// An element of GF(2^255 - 19).
+type Fe = [i64; 16];
+
+/// (A - 2) / 4, where A = 486662 is the coefficient of the curve.
+const A24: Fe = [0xdb41, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+
+fn carry(o: &mut Fe) {
+    for i in 0..16 {
+        o[i] += 1 << 16;
+        let c = o[i] >> 16;
+        if i < 15 {
+            o[i + 1] += c - 1;
+        } else {
+            o[0] += 38 * (c - 1);
+        }
+        o[i] -= c << 16;
+    }
+}
+
+/// Swaps `p` and `q` if `b` is 1, leaves them untouched if it is 0.
+fn swap(p: &mut Fe, q: &mut Fe, b: i64) {
+    let mask = !(b - 1);
+    for (pi, qi) in p.iter_mut().zip(q.iter_mut()) {
+        let t = mask & (*pi ^ *qi);
+        *pi ^= t;
+        *qi ^= t;
+    }
+}
+
+fn pack(n: &Fe) -> [u8; KEY_LEN] {
+    let mut t = *n;
+    carry(&mut t);
+    carry(&mut t);
+    carry(&mut t);
+
+    // Subtract the modulus twice, keeping the result only if it did not underflow.
+    for _ in 0..2 {
+        let mut m = [0i64; 16];
+        m[0] = t[0] - 0xffed;
+        for i in 1..15 {
+            m[i] = t[i] - 0xffff - ((m[i - 1] >> 16) & 1);
+            m[i - 1] &= 0xffff;
+        }
+        m[15] = t[15] - 0x7fff - ((m[14] >> 16) & 1);
+        let b = (m[15] >> 16) & 1;
+        m[14] &= 0xffff;
+        swap(&mut t, &mut m, 1 - b);
+    }
+
+    let mut o = [0u8; KEY_LEN];
+    for (i, limb) in t.iter().enumerate() {
+        o[2 * i] = *limb as u8;
+        o[2 * i + 1] = (*limb >> 8) as u8;
+    }
+    o
+}
+
+fn unpack(n: &[u8; KEY_LEN]) -> Fe {
+    let mut o = [0i64; 16];
+    for (i, limb) in o.iter_mut().enumerate() {
+        *limb = i64::from(n[2 * i]) + (i64::from(n[2 * i + 1]) << 8);
+    }
+    // The most significant bit of the u-coordinate is ignored.
+    o[15] &= 0x7fff;
+    o
+}
+
+fn add(a: &Fe, b: &Fe) -> Fe {
+    let mut o = [0i64; 16];
+    for i in 0..16 {
+        o[i] = a[i] + b[i];
+    }
+    o
+}
+
+fn sub(a: &Fe, b: &Fe) -> Fe {
+    let mut o = [0i64; 16];
+    for i in 0..16 {
+        o[i] = a[i] - b[i];
+    }
+    o
+}
+
+fn mul(a: &Fe, b: &Fe) -> Fe {
+    let mut t = [0i64; 31];
+    for i in 0..16 {
+        for j in 0..16 {
+            t[i + j] += a[i] * b[j];
+        }
+    }
+    for i in 0..15 {
+        t[i] += 38 * t[i + 16];
+    }
+    let mut o = [0i64; 16];
+    o.copy_from_slice(&t[..16]);
+    carry(&mut o);
+    carry(&mut o);
+    o
+}
+
+fn square(a: &Fe) -> Fe {
+    mul(a, a)
+}
+
+/// Computes `i^(p - 2)`, that is the inverse of `i`.
+fn invert(i: &Fe) -> Fe {
+    let mut c = *i;
+    for a in (0..254).rev() {
+        c = square(&c);
+        if a != 2 && a != 4 {
+            c = mul(&c, i);
+        }
+    }
+    c
+}
+
+/// Multiplies the point with u-coordinate `point` by the clamped `scalar`,
+/// with the Montgomery ladder.
+pub(crate) fn x25519(scalar: &[u8; KEY_LEN], point: &[u8; KEY_LEN]) -> [u8; KEY_LEN] {
+    let mut z = *scalar;
+    z[31] = (z[31] & 127) | 64;
+    z[0] &= 248;
+
+    let x = unpack(point);
+    let mut a = [0i64; 16];
+    let mut b = x;
+    let mut c = [0i64; 16];
+    let mut d = [0i64; 16];
+    a[0] = 1;
+    d[0] = 1;
+
+    for i in (0..255).rev() {
+        let r = i64::from((z[i >> 3] >> (i & 7)) & 1);
+        swap(&mut a, &mut b, r);
+        swap(&mut c, &mut d, r);
+        let mut e = add(&a, &c);
+        a = sub(&a, &c);
+        c = add(&b, &d);
+        b = sub(&b, &d);
+        d = square(&e);
+        let f = square(&a);
+        a = mul(&c, &a);
+        c = mul(&b, &e);
+        e = add(&a, &c);
+        a = sub(&a, &c);
+        b = square(&a);
+        c = sub(&d, &f);
+        a = mul(&c, &A24);
+        a = add(&a, &d);
+        c = mul(&c, &a);
+        a = mul(&d, &f);
+        d = mul(&b, &x);
+        b = square(&e);
+        swap(&mut a, &mut b, r);
+        swap(&mut c, &mut d, r);
+    }
+
+    pack(&mul(&a, &invert(&c)))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn from_hex(hex: &str) -> [u8; KEY_LEN] {
+        let mut bytes = [0u8; KEY_LEN];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap();
+        }
+        bytes
+    }
+
+    #[test]
+    fn matches_rfc_7748_vectors() {
+        let scalar = from_hex("a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4");
+        let point = from_hex("e6db6867583030db3594c1a424b15f7c726624ec26b3353b10a903a6d0ab1c4c");
+        let expected =
+            from_hex("c3da55379de9c6908e94ea4df28d084f32eccf03491c71f754b4075577a28552");
+        assert_eq!(x25519(&scalar, &point), expected);
+
+        let scalar = from_hex("4b66e9d4d1b4673c5ad22691957d6af5c11b6421e0ea01d42ca4169e7918ba0d");
+        let point = from_hex("e5210f12786811d3f4b7959d0538ae2c31dbe7106fc03c3efc4cd549c715a493");
+        let expected =
+            from_hex("95cbde9476e8907d7aade45cb4b873f88b595a68799fa152e6f8f7647aac7957");
+        assert_eq!(x25519(&scalar, &point), expected);
+    }
+
+    #[test]
+    fn agrees_on_rfc_7748_shared_secret() {
+        let alice = from_hex("77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a");
+        let bob = from_hex("5dab087e624a8a4b79e17f8b83800ee66f3bb1292618b6fd1c2f8b27ff88e0eb");
+
+        let alice_public = x25519(&alice, &BASE_POINT);
+        let bob_public = x25519(&bob, &BASE_POINT);
+        assert_eq!(
+            alice_public,
+            from_hex("8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a")
+        );
+        assert_eq!(
+            bob_public,
+            from_hex("de9edb7d7b7dc1b4d35b61c2ece435373f8343c85b78674dadfc7e146f882b4f")
+        );
+
+        let shared =
+            from_hex("4a5d9d5ba4ce2de1728e3bf480350f25e07e21c947d19e3376f09b3c1e161742");
+        assert_eq!(x25519(&alice, &bob_public), shared);
+        assert_eq!(x25519(&bob, &alice_public), shared);
+    }
+
+    #[test]
+    fn matches_rfc_7748_iterated_vectors() {
+        let mut scalar = BASE_POINT;
+        let mut point = BASE_POINT;
+        for i in 0..1000 {
+            let result = x25519(&scalar, &point);
+            point = scalar;
+            scalar = result;
+            if i == 0 {
+                assert_eq!(
+                    scalar,
+                    from_hex("422c8e7a6227d7bca1350b3e2bb7279f7897b87bb6854b783c60e80311ae3079")
+                );
+            }
+        }
+        assert_eq!(
+            scalar,
+            from_hex("684cf59ba83309552800ef566f2f4d3c1c3887c49360e3875f2eb94d99532c51")
+        );
+    }
+
+    #[test]
+    fn points_of_small_order_yield_zero() {
+        let scalar = from_hex("a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4");
+        let points = [
+            "0000000000000000000000000000000000000000000000000000000000000000",
+            "0100000000000000000000000000000000000000000000000000000000000000",
+            "e0eb7a7c3b41b8ae1656e3faf19fc46ada098deb9c32b1fd866205165f49b800",
+            "5f9c95bca3508c24b1d0b1559c83ef5b04445cc4581c8e86d8224eddd09f1157",
+            "ecffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+            "edffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+            "eeffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+        ];
+        for point in &points {
+            assert_eq!(x25519(&scalar, &from_hex(point)), [0u8; KEY_LEN]);
+        }
+    }
+}
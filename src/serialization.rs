@@ -0,0 +1,160 @@
+//! Helpers for the `serde` implementations of the public share, metadata and
+//! recovery session types, which are derived when the `serialization` feature is enabled.
+//!
+//! Structs are represented as maps keyed by field name, and enums follow serde's
+//! externally tagged representation. Binary fields are represented as Base64
+//! strings (standard alphabet, with padding), while share-set identifiers are
+//! represented as lowercase hexadecimal strings, as printed by their `Display` instance.
+//! Optional fields may be omitted when deserializing.
+//!
+//! The shares of the `sss` and `xor` schemes, whose signatures cannot be represented field
+//! by field, are represented by their string representation, as returned by `into_string`.
+//!
+//! A `RecoverySession` is represented by its scheme, whether signatures are verified,
+//! and its shares, which are validated again when deserializing it.
+
+use serde::de::{self, Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+
+use armor::Scheme;
+use recovery::RecoverySession;
+use share_set::ShareSetId;
+use sss;
+use xor;
+
+#[cfg(feature = "dss")]
+use hash::{HashAlgorithm, DEFAULT_SIGNATURE_HASH_ALGORITHM};
+
+/// Represents binary fields as Base64 strings.
+pub(crate) mod base64_bytes {
+
+    use base64;
+    use serde::de::{self, Deserialize, Deserializer};
+    use serde::ser::Serializer;
+
+    pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&base64::encode_config(bytes, base64::STANDARD))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let encoded = String::deserialize(deserializer)?;
+        base64::decode_config(&encoded, base64::STANDARD).map_err(de::Error::custom)
+    }
+}
+
+/// Represents lists of binary fields as lists of Base64 strings.
+#[cfg(feature = "dss")]
+pub(crate) mod base64_bytes_vec {
+
+    use serde::de::{Deserialize, Deserializer};
+    use serde::ser::{Serialize, Serializer};
+
+    /// Borrows a binary field to serialize it.
+    struct Encoded<'a>(&'a [u8]);
+
+    impl<'a> Serialize for Encoded<'a> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            ::serialization::base64_bytes::serialize(self.0, serializer)
+        }
+    }
+
+    #[derive(Deserialize)]
+    struct Decoded(
+        #[serde(deserialize_with = "::serialization::base64_bytes::deserialize")] Vec<u8>,
+    );
+
+    pub fn serialize<S: Serializer>(list: &[Vec<u8>], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(list.iter().map(|bytes| Encoded(bytes.as_slice())))
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<Vec<u8>>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Vec::<Decoded>::deserialize(deserializer)
+            .map(|list| list.into_iter().map(|bytes| bytes.0).collect())
+    }
+}
+
+/// The hash function of signatures serialized before it was recorded in them.
+#[cfg(feature = "dss")]
+pub(crate) fn default_signature_hash_algorithm() -> HashAlgorithm {
+    DEFAULT_SIGNATURE_HASH_ALGORITHM
+}
+
+impl Serialize for ShareSetId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ShareSetId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let hex = String::deserialize(deserializer)?;
+        if hex.len() % 2 != 0 || !hex.chars().all(|c| c.is_digit(16)) {
+            return Err(de::Error::invalid_value(
+                de::Unexpected::Str(&hex),
+                &"a share-set identifier encoded as an hexadecimal string",
+            ));
+        }
+        // The string only holds ASCII characters, and can thus be sliced anywhere.
+        let bytes = (0..hex.len() / 2)
+            .map(|i| u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap())
+            .collect::<Vec<_>>();
+
+        ShareSetId::from_bytes(&bytes).map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for sss::Share {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.clone().into_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for sss::Share {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = String::deserialize(deserializer)?;
+        sss::Share::from_string(&raw, true).map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for xor::Share {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.clone().into_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for xor::Share {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = String::deserialize(deserializer)?;
+        xor::Share::from_string(&raw, true).map_err(de::Error::custom)
+    }
+}
+
+/// The state of a `RecoverySession`, which is validated again when deserialized.
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "RecoverySession")]
+struct RecoverySessionState {
+    scheme: Scheme,
+    verify_signatures: bool,
+    shares: Vec<String>,
+}
+
+impl Serialize for RecoverySession {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        RecoverySessionState {
+            scheme: self.scheme(),
+            verify_signatures: self.verify_signatures(),
+            shares: self.shares().to_vec(),
+        }.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for RecoverySession {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let state = RecoverySessionState::deserialize(deserializer)?;
+        RecoverySession::resume(state.scheme, state.verify_signatures, &state.shares)
+            .map_err(de::Error::custom)
+    }
+}
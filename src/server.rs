@@ -0,0 +1,431 @@
+//! A local unseal server, which starts sealed and collects shares submitted by operators
+//! over a Unix domain socket, until enough of them were submitted to recover the secret.
+//!
+//! Every share is validated against the ones submitted before it as soon as it is received,
+//! through a `RecoverySession`, so that an operator learns right away that their share was
+//! rejected. Once the threshold is met, the secret is recovered and handed to a `SecretSink`,
+//! and the shares are forgotten. The shares collected so far are also forgotten if the
+//! threshold is not met within the configured timeout, counted from the first share.
+//!
+//! # Protocol
+//!
+//! Clients send one command per line, and receive one response line per command:
+//!
+//! Command         | Effect
+//! --------------- | ---------------------------------------------------------------
+//! `share <share>` | Submits a share, which must fit on a single line of at most 1 MiB.
+//! `status`        | Reports the progress of the recovery.
+//! `reset`         | Forgets the shares submitted so far.
+//!
+//! Responses start with `ok`, followed by the status of the server as `key=value` pairs,
+//! or with `error`, followed by the code of the error, as returned by `ErrorKind::code`,
+//! and a description of it:
+//!
+//! ```text
+//! > share 3-1-CgnFZg2rqVgaWy8SFgRsPw7L3rEoT1yqVZaXX0VKIdU
+//! < ok state=sealed threshold=3 shares=1 missing=2 share-set=8f07c3a9e1d4b2f6a0c5d7e9f1a3b5c7
+//! > share 3-1-CgnFZg2rqVgaWy8SFgRsPw7L3rEoT1yqVZaXX0VKIdU
+//! < error 32 This share number (1) has already been used by a previous share.
+//! > status
+//! < ok state=sealed threshold=3 shares=1 missing=2 share-set=8f07c3a9e1d4b2f6a0c5d7e9f1a3b5c7
+//! ```
+//!
+//! Unknown values are reported as `-`, and the state is `unsealed` in the response to the
+//! share which met the threshold, after which the server stops.
+
+use std::fmt;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::os::unix::fs::{DirBuilderExt, PermissionsExt};
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use rand::{OsRng, Rng};
+
+use armor::Scheme;
+use errors::*;
+use inspect::inspect_share;
+use recovery::RecoverySession;
+use share_set::ShareSetId;
+
+/// How many seconds the shares submitted are kept by default while waiting for the threshold
+/// to be met.
+pub const DEFAULT_TIMEOUT_SECS: u64 = 300;
+
+/// How many seconds a client may stay silent before it is disconnected,
+/// so that it cannot keep other operators from submitting their shares.
+const CLIENT_TIMEOUT_SECS: u64 = 30;
+
+/// How often, in milliseconds, the server checks whether the timeout expired
+/// while no client is connected.
+const POLL_INTERVAL_MILLIS: u64 = 100;
+
+/// The longest line accepted from a client, newline included, so that a client
+/// cannot exhaust the memory of the server by never ending its line.
+const MAX_LINE_LEN: u64 = 1 << 20;
+
+/// Where the recovered secret is handed to.
+pub enum SecretSink {
+    /// The secret is passed to the given function.
+    Callback(Box<FnMut(Vec<u8>) -> Result<()>>),
+    /// The secret is written to the given writer, for instance a pipe inherited
+    /// from the process waiting for it.
+    Writer(Box<Write>),
+}
+
+impl SecretSink {
+    fn deliver(&mut self, secret: Vec<u8>) -> Result<()> {
+        match *self {
+            SecretSink::Callback(ref mut callback) => callback(secret),
+            SecretSink::Writer(ref mut writer) => {
+                writer.write_all(&secret)?;
+                writer.flush()?;
+                Ok(())
+            }
+        }
+    }
+}
+
+impl fmt::Debug for SecretSink {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SecretSink::Callback(_) => write!(f, "SecretSink::Callback"),
+            SecretSink::Writer(_) => write!(f, "SecretSink::Writer"),
+        }
+    }
+}
+
+/// The progress of the recovery.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct UnsealStatus {
+    /// Whether the secret was recovered and handed to the sink
+    pub unsealed: bool,
+    /// The number of shares necessary to recover the secret, if any share was submitted
+    pub threshold: Option<u8>,
+    /// The number of shares submitted so far
+    pub shares: usize,
+    /// How many more shares are needed, if any share was submitted
+    pub missing: Option<u8>,
+    /// The share set the shares submitted so far belong to, if they carry one
+    pub share_set_id: Option<ShareSetId>,
+}
+
+impl fmt::Display for UnsealStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fn or_dash<T: fmt::Display>(value: Option<T>) -> String {
+            value.map_or_else(|| "-".to_string(), |value| value.to_string())
+        }
+
+        write!(
+            f,
+            "state={} threshold={} shares={} missing={} share-set={}",
+            if self.unsealed { "unsealed" } else { "sealed" },
+            or_dash(self.threshold),
+            self.shares,
+            or_dash(self.missing),
+            or_dash(self.share_set_id)
+        )
+    }
+}
+
+/// Collects shares until the secret can be recovered, independently of how they are received.
+#[derive(Debug)]
+pub struct Unsealer {
+    session: RecoverySession,
+    share_set_id: Option<ShareSetId>,
+    timeout: Duration,
+    started: Option<Instant>,
+    sink: SecretSink,
+}
+
+impl Unsealer {
+    /// Starts collecting shares of a secret shared with the given `scheme`, to be handed
+    /// to `sink` once recovered. If `verify_signatures` is set, every share must be signed.
+    pub fn new(
+        scheme: Scheme,
+        verify_signatures: bool,
+        timeout: Duration,
+        sink: SecretSink,
+    ) -> Self {
+        Unsealer {
+            session: RecoverySession::new(scheme, verify_signatures),
+            share_set_id: None,
+            timeout,
+            started: None,
+            sink,
+        }
+    }
+
+    /// Validates the given share against the ones submitted before it, and adds it.
+    ///
+    /// If the threshold is met, the secret is recovered and handed to the sink, and the shares
+    /// are forgotten. If the share is rejected, the shares submitted before it are kept.
+    pub fn submit(&mut self, share: &str) -> Result<UnsealStatus> {
+        self.expire();
+
+        self.session.add_share(share)?;
+        if self.started.is_none() {
+            self.started = Some(Instant::now());
+            self.share_set_id = inspect_share(share)
+                .ok()
+                .and_then(|info| info.share_set_id);
+        }
+
+        if !self.session.is_complete() {
+            return Ok(self.status());
+        }
+
+        let secret = self.session.recover();
+        self.reset();
+        self.sink.deliver(secret?)?;
+
+        Ok(UnsealStatus {
+            unsealed: true,
+            ..self.status()
+        })
+    }
+
+    /// Reports the progress of the recovery.
+    pub fn status(&mut self) -> UnsealStatus {
+        self.expire();
+
+        UnsealStatus {
+            unsealed: false,
+            threshold: self.session.threshold(),
+            shares: self.session.shares().len(),
+            missing: self.session.missing(),
+            share_set_id: self.share_set_id,
+        }
+    }
+
+    /// Forgets the shares submitted so far.
+    pub fn reset(&mut self) {
+        let (scheme, verify_signatures) = (self.session.scheme(), self.session.verify_signatures());
+        self.session = RecoverySession::new(scheme, verify_signatures);
+        self.share_set_id = None;
+        self.started = None;
+    }
+
+    /// Forgets the shares submitted so far if the timeout expired.
+    fn expire(&mut self) {
+        let expired = self.started
+            .map_or(false, |started| started.elapsed() >= self.timeout);
+        if expired {
+            self.reset();
+        }
+    }
+
+    /// Runs the given protocol command, and returns the response to send back,
+    /// along with whether the secret was recovered.
+    fn handle(&mut self, line: &str) -> (String, bool) {
+        let line = line.trim();
+        let (command, argument) = match line.find(' ') {
+            Some(index) => (&line[..index], line[index + 1..].trim()),
+            None => (line, ""),
+        };
+
+        let result = match command {
+            "share" => self.submit(argument),
+            "status" => Ok(self.status()),
+            "reset" => {
+                self.reset();
+                Ok(self.status())
+            }
+            _ => Err(ErrorKind::Msg(format!("Unknown command '{}'.", command)).into()),
+        };
+
+        match result {
+            Ok(status) => (format!("ok {}", status), status.unsealed),
+            Err(e) => (
+                format!("error {} {}", e.kind().code(), e.to_string().replace('\n', " ")),
+                false,
+            ),
+        }
+    }
+}
+
+/// Listens on a Unix domain socket for the shares of the operators.
+#[derive(Debug)]
+pub struct Server {
+    listener: UnixListener,
+    path: PathBuf,
+    unsealer: Unsealer,
+}
+
+impl Server {
+    /// Creates the socket at `path`, only accessible to the current user, and collects
+    /// the shares submitted to it with `unsealer`.
+    ///
+    /// Fails if a file already exists at `path`.
+    pub fn bind<P: AsRef<Path>>(path: P, unsealer: Unsealer) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+
+        // The socket is created in a directory only accessible to the current user, and only
+        // linked at `path` once restricted, so that no other user can connect to it in between.
+        let dir = private_dir_next_to(&path)?;
+        let bound = bind_private(&dir.join("socket"), &path);
+        let _ = fs::remove_dir_all(&dir);
+        let listener = bound?;
+        listener.set_nonblocking(true)?;
+
+        Ok(Server {
+            listener,
+            path,
+            unsealer,
+        })
+    }
+
+    /// Serves one client at a time, until the secret is recovered and handed to the sink.
+    pub fn run(mut self) -> Result<()> {
+        loop {
+            match self.listener.accept() {
+                Ok((stream, _)) => {
+                    // Clients which misbehave are simply disconnected.
+                    if let Ok(true) = self.serve(stream) {
+                        return Ok(());
+                    }
+                }
+                Err(ref e) if e.kind() == io::ErrorKind::WouldBlock => {
+                    self.unsealer.expire();
+                    thread::sleep(Duration::from_millis(POLL_INTERVAL_MILLIS));
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+    }
+
+    /// Serves the given client, and returns whether the secret was recovered.
+    fn serve(&mut self, stream: UnixStream) -> io::Result<bool> {
+        stream.set_nonblocking(false)?;
+        stream.set_read_timeout(Some(Duration::from_secs(CLIENT_TIMEOUT_SECS)))?;
+
+        let mut writer = stream.try_clone()?;
+        let mut reader = BufReader::new(stream);
+        loop {
+            let mut line = String::new();
+            let len = (&mut reader).take(MAX_LINE_LEN).read_line(&mut line)?;
+            if len == 0 {
+                break;
+            }
+            if len as u64 == MAX_LINE_LEN && !line.ends_with('\n') {
+                return Err(io::Error::new(io::ErrorKind::InvalidData, "The line is too long."));
+            }
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let (response, unsealed) = self.unsealer.handle(&line);
+            writeln!(writer, "{}", response)?;
+            if unsealed {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+}
+
+/// Creates a new directory, only accessible to the current user, in the directory of `path`.
+fn private_dir_next_to(path: &Path) -> Result<PathBuf> {
+    let dir = path.with_file_name(format!(".unseal-{:08x}", OsRng::new()?.next_u32()));
+    fs::DirBuilder::new().mode(0o700).create(&dir)?;
+    Ok(dir)
+}
+
+/// Binds a socket at `tmp_path`, restricts it to the current user, and links it at `path`.
+fn bind_private(tmp_path: &Path, path: &Path) -> Result<UnixListener> {
+    let listener = UnixListener::bind(tmp_path)?;
+    fs::set_permissions(tmp_path, fs::Permissions::from_mode(0o600))?;
+    // Unlike a rename, linking fails if a file already exists at `path`.
+    fs::hard_link(tmp_path, path)?;
+    Ok(listener)
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::*;
+    use sss;
+
+    fn unsealer(timeout: Duration) -> (Unsealer, Rc<RefCell<Option<Vec<u8>>>>) {
+        let recovered = Rc::new(RefCell::new(None));
+        let sink = {
+            let recovered = Rc::clone(&recovered);
+            SecretSink::Callback(Box::new(move |secret| {
+                *recovered.borrow_mut() = Some(secret);
+                Ok(())
+            }))
+        };
+        (Unsealer::new(Scheme::SSS, false, timeout, sink), recovered)
+    }
+
+    #[test]
+    fn unseals_once_the_threshold_is_met() {
+        let shares = sss::split_secret(3, 5, b"Hello, World!", false).unwrap();
+        let (mut unsealer, recovered) = unsealer(Duration::from_secs(DEFAULT_TIMEOUT_SECS));
+
+        let status = unsealer.submit(&shares[3]).unwrap();
+        assert_eq!(status.threshold, Some(3));
+        assert_eq!(status.missing, Some(2));
+        assert!(status.share_set_id.is_some());
+
+        assert!(unsealer.submit(&shares[3]).is_err());
+        assert_eq!(unsealer.status().shares, 1);
+
+        assert!(!unsealer.submit(&shares[0]).unwrap().unsealed);
+        assert!(recovered.borrow().is_none());
+        assert!(unsealer.submit(&shares[1]).unwrap().unsealed);
+        assert_eq!(recovered.borrow().as_ref().unwrap(), b"Hello, World!");
+
+        // The shares are forgotten once the secret was handed over.
+        assert_eq!(unsealer.status().shares, 0);
+    }
+
+    #[test]
+    fn forgets_shares_after_the_timeout() {
+        let shares = sss::split_secret(2, 3, b"Hello, World!", false).unwrap();
+        let (mut unsealer, recovered) = unsealer(Duration::from_millis(10));
+
+        unsealer.submit(&shares[0]).unwrap();
+        thread::sleep(Duration::from_millis(20));
+        assert_eq!(unsealer.status().threshold, None);
+
+        assert!(!unsealer.submit(&shares[1]).unwrap().unsealed);
+        assert!(recovered.borrow().is_none());
+    }
+
+    #[test]
+    fn handles_protocol_commands() {
+        let shares = sss::split_secret(2, 3, b"Hello, World!", false).unwrap();
+        let (mut unsealer, _) = unsealer(Duration::from_secs(DEFAULT_TIMEOUT_SECS));
+
+        let (response, _) = unsealer.handle("status");
+        assert_eq!(response, "ok state=sealed threshold=- shares=0 missing=- share-set=-");
+
+        let (response, _) = unsealer.handle(&format!("share {}\n", shares[0]));
+        assert!(response.starts_with("ok state=sealed threshold=2 shares=1 missing=1 share-set="));
+
+        let (response, unsealed) = unsealer.handle(&format!("share {}", shares[0]));
+        assert!(response.starts_with("error 32 "));
+        assert!(!unsealed);
+
+        assert!(unsealer.handle("reset").0.contains("shares=0"));
+        assert!(unsealer.handle("open sesame").0.starts_with("error "));
+
+        unsealer.handle(&format!("share {}", shares[2]));
+        let (response, unsealed) = unsealer.handle(&format!("share {}", shares[1]));
+        assert!(response.starts_with("ok state=unsealed"));
+        assert!(unsealed);
+    }
+}
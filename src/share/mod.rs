@@ -4,6 +4,7 @@
 //! change in the future.
 
 use errors::*;
+use share_set::ShareSetId;
 
 pub(crate) mod validation;
 
@@ -21,6 +22,9 @@ pub(crate) trait IsShare: Sized {
 
     /// Returns the total number of shares that have been dealt
     fn get_shares_count(&self) -> Option<u8>;
+
+    /// Returns the identifier of the share set this share belongs to, if any
+    fn get_share_set_id(&self) -> Option<ShareSetId>;
 }
 
 /// This trait must be implemented by shares' types wich can be signed.
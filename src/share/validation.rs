@@ -9,20 +9,36 @@ use share::{IsShare, IsSignedShare};
 
 /// TODO: Doc
 pub(crate) fn validate_signed_shares<S: IsSignedShare>(
-    shares: &Vec<S>,
+    shares: &[S],
     verify_signatures: bool,
 ) -> Result<(u8, usize)> {
     let result = validate_shares(shares)?;
 
     if verify_signatures {
-        S::verify_signatures(&shares)?;
+        S::verify_signatures(shares)?;
     }
 
     Ok(result)
 }
 
 /// TODO: Doc
-pub(crate) fn validate_shares<S: IsShare>(shares: &Vec<S>) -> Result<(u8, usize)> {
+pub(crate) fn validate_shares<S: IsShare>(shares: &[S]) -> Result<(u8, usize)> {
+    let (threshold, slen) = validate_share_consistency(shares)?;
+
+    // Only once the threshold is confirmed as consistent should we determine if shares are
+    // missing.
+    let shares_count = shares.len();
+    if shares_count < threshold as usize {
+        bail!(ErrorKind::MissingShares(shares_count, threshold))
+    }
+
+    Ok((threshold, slen))
+}
+
+/// Validates the given shares individually, and checks that they are consistent with
+/// each other, without requiring that enough of them are given to recover the secret.
+/// Returns their threshold and the length of their data.
+pub(crate) fn validate_share_consistency<S: IsShare>(shares: &[S]) -> Result<(u8, usize)> {
     if shares.is_empty() {
         bail!(ErrorKind::EmptyShares);
     }
@@ -32,6 +48,7 @@ pub(crate) fn validate_shares<S: IsShare>(shares: &Vec<S>) -> Result<(u8, usize)
     let mut ids = Vec::with_capacity(shares_count);
     let mut threshold = 0;
     let mut slen = 0;
+    let mut share_set_id = None;
 
     for share in shares {
         let id = share.get_id();
@@ -49,6 +66,19 @@ pub(crate) fn validate_shares<S: IsShare>(shares: &Vec<S>) -> Result<(u8, usize)
             bail!(ErrorKind::ShareParsingErrorEmptyShare(id))
         }
 
+        // Shares created without a share-set identifier are compatible with any set.
+        if let Some(share_set_id_) = share.get_share_set_id() {
+            match share_set_id {
+                Some(set) if set != share_set_id_ => bail!(ErrorKind::InconsistentShareSets(
+                    id,
+                    share_set_id_,
+                    ids,
+                    set
+                )),
+                _ => share_set_id = Some(share_set_id_),
+            }
+        }
+
         if ids.iter().any(|&x| x == id) {
             bail!(ErrorKind::DuplicateShareId(id));
         }
@@ -73,12 +103,6 @@ pub(crate) fn validate_shares<S: IsShare>(shares: &Vec<S>) -> Result<(u8, usize)
         ids.push(id);
     }
 
-    // Only once the threshold is confirmed as consistent should we determine if shares are
-    // missing.
-    if shares_count < threshold as usize {
-        bail!(ErrorKind::MissingShares(shares_count, threshold))
-    }
-
     Ok((threshold, slen))
 }
 
@@ -87,7 +111,7 @@ pub(crate) fn validate_share_count(threshold: u8, shares_count: u8) -> Result<(u
         bail!(ErrorKind::ThresholdTooSmall(threshold));
     }
     if shares_count > MAX_SHARES {
-        bail!(ErrorKind::InvalidShareCountMax(shares_count, MAX_SHARES));
+        bail!(ErrorKind::InvalidShareCountMax(usize::from(shares_count), MAX_SHARES));
     }
     if shares_count < MIN_SHARES {
         bail!(ErrorKind::InvalidShareCountMin(shares_count, MIN_SHARES));
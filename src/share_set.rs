@@ -0,0 +1,188 @@
+//! Share-set identifiers, and sorting of shares coming from several splits.
+//!
+//! Every share produced by a single split carries the same share-set identifier,
+//! which makes it possible to tell apart shares coming from different splits, even
+//! when they share the same threshold and the same secret length.
+
+use std::fmt;
+
+use rand::Rng;
+#[cfg(feature = "dss")]
+use ring::rand::SecureRandom;
+
+use errors::*;
+use share::IsShare;
+
+/// The length in bytes of a share-set identifier.
+pub const SHARE_SET_ID_LEN: usize = 16;
+
+/// A random identifier shared by all the shares produced by a single split.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ShareSetId([u8; SHARE_SET_ID_LEN]);
+
+impl ShareSetId {
+    /// Generates a new random share-set identifier using the given RNG.
+    pub(crate) fn random<R: Rng>(rng: &mut R) -> Self {
+        let mut bytes = [0u8; SHARE_SET_ID_LEN];
+        rng.fill_bytes(&mut bytes);
+        ShareSetId(bytes)
+    }
+
+    /// Generates a new random share-set identifier using the given `SecureRandom`.
+    #[cfg(feature = "dss")]
+    pub(crate) fn random_secure(random: &SecureRandom) -> Result<Self> {
+        let mut bytes = [0u8; SHARE_SET_ID_LEN];
+        random
+            .fill(&mut bytes)
+            .chain_err(|| ErrorKind::CannotGenerateRandomNumbers)?;
+        Ok(ShareSetId(bytes))
+    }
+
+    /// Builds a share-set identifier out of the given bytes.
+    ///
+    /// Fails if `bytes` is not exactly `SHARE_SET_ID_LEN` bytes long.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() != SHARE_SET_ID_LEN {
+            bail!(ErrorKind::ShareParsingError(format!(
+                "Expected a share-set identifier of {} bytes, found {} bytes.",
+                SHARE_SET_ID_LEN,
+                bytes.len()
+            )));
+        }
+        let mut id = [0u8; SHARE_SET_ID_LEN];
+        id.copy_from_slice(bytes);
+        Ok(ShareSetId(id))
+    }
+
+    /// Returns the bytes making up this identifier.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Parses the identifier stored in a share protobuf, where an empty field
+    /// denotes a share which was created without one.
+    pub(crate) fn from_proto(bytes: &[u8]) -> Result<Option<Self>> {
+        if bytes.is_empty() {
+            Ok(None)
+        } else {
+            Self::from_bytes(bytes).map(Some)
+        }
+    }
+}
+
+impl fmt::Display for ShareSetId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for byte in &self.0 {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+/// A group of shares which belong to the same share set.
+#[derive(Debug)]
+pub struct ShareSet<T> {
+    /// The identifier of the share set, or `None` for shares which were
+    /// created by a version of RustySecrets which did not embed one.
+    pub id: Option<ShareSetId>,
+    /// The number of shares necessary to recover the secret, aka the threshold.
+    pub threshold: u8,
+    /// The identifiers of the shares which were found for this set.
+    pub share_ids: Vec<u8>,
+    /// The number of additional shares needed to recover the secret.
+    pub missing: u8,
+    /// The outcome of the recovery, if enough shares were found.
+    pub recovered: Option<Result<T>>,
+}
+
+impl<T> ShareSet<T> {
+    /// Returns whether enough shares were found to attempt a recovery.
+    pub fn is_complete(&self) -> bool {
+        self.missing == 0
+    }
+}
+
+/// The outcome of sorting a pile of shares into share sets.
+#[derive(Debug)]
+pub struct SortedShares<T> {
+    /// The share sets, in the order in which they were first encountered.
+    pub sets: Vec<ShareSet<T>>,
+    /// The shares which could not be sorted, along with the reason why.
+    pub rejected: Vec<(String, Error)>,
+}
+
+/// Parses the given shares, groups them by share set and threshold, and
+/// recovers the secret of every group holding enough shares.
+///
+/// A share given twice is only counted once, while a share whose identifier is
+/// already taken by a different share of the same set is rejected.
+pub(crate) fn sort_shares<S, T, P, R>(raws: &[String], parse: P, recover: R) -> SortedShares<T>
+where
+    S: IsShare,
+    P: Fn(&str) -> Result<S>,
+    R: Fn(Vec<S>) -> Result<T>,
+{
+    let mut groups: Vec<(Option<ShareSetId>, u8, Vec<S>)> = Vec::new();
+    let mut rejected = Vec::new();
+
+    for raw in raws {
+        let share = match parse(raw) {
+            Ok(share) => share,
+            Err(e) => {
+                rejected.push((raw.clone(), e));
+                continue;
+            }
+        };
+
+        let share_set_id = share.get_share_set_id();
+        let threshold = share.get_threshold();
+        let position = groups
+            .iter()
+            .position(|&(ref id, k, _)| *id == share_set_id && k == threshold);
+
+        let index = match position {
+            Some(index) => index,
+            None => {
+                groups.push((share_set_id, threshold, Vec::new()));
+                groups.len() - 1
+            }
+        };
+
+        let duplicate = groups[index]
+            .2
+            .iter()
+            .find(|s| s.get_id() == share.get_id())
+            .map(|s| s.get_data() == share.get_data());
+
+        match duplicate {
+            None => groups[index].2.push(share),
+            Some(true) => {}
+            Some(false) => {
+                rejected.push((raw.clone(), ErrorKind::DuplicateShareId(share.get_id()).into()))
+            }
+        }
+    }
+
+    let sets = groups
+        .into_iter()
+        .map(|(id, threshold, shares)| {
+            let share_ids = shares.iter().map(|s| s.get_id()).collect::<Vec<_>>();
+            let missing = (threshold as usize).saturating_sub(shares.len()) as u8;
+            let recovered = if missing == 0 {
+                Some(recover(shares))
+            } else {
+                None
+            };
+
+            ShareSet {
+                id,
+                threshold,
+                share_ids,
+                missing,
+                recovered,
+            }
+        })
+        .collect();
+
+    SortedShares { sets, rejected }
+}
@@ -0,0 +1,192 @@
+//! Arithmetic on shares, which Shamir's secret sharing over GF(2^8) makes linear:
+//! the sum of the shares of two secrets with the same identifier is a share of the sum
+//! of the secrets, and so is the product of a share with a public constant.
+
+use ring::digest;
+
+use errors::*;
+use field::Gf256;
+use share_set::{ShareSetId, SHARE_SET_ID_LEN};
+use sss::Share;
+
+/// Adds the given shares of two secrets, which must have the same identifier, threshold
+/// and length, and returns a share of the sum of the secrets, that is their bytewise XOR.
+///
+/// See `sum_shares` for more details.
+pub fn add_shares(a: &Share, b: &Share) -> Result<Share> {
+    sum_shares(&[a.clone(), b.clone()])
+}
+
+/// Adds the given shares of several secrets, which must all have the same identifier,
+/// threshold and length, and returns a share of the sum of the secrets.
+///
+/// The resulting share is not signed. Its share-set identifier is derived from the ones of
+/// the given shares regardless of their order, so that the sums of the shares of the same
+/// secrets are recognized as belonging to the same set. If any of the given shares does not
+/// carry a share-set identifier, neither does the resulting share.
+///
+/// Fails with `InconsistentShares` if the shares do not have the same identifier.
+pub fn sum_shares(shares: &[Share]) -> Result<Share> {
+    let first = match shares.first() {
+        Some(first) => first,
+        None => bail!(ErrorKind::EmptyShares),
+    };
+
+    let mut data = vec![0u8; first.data.len()];
+    for share in shares {
+        if share.id != first.id {
+            bail!(ErrorKind::InconsistentShares);
+        }
+        if share.threshold != first.threshold {
+            bail!(ErrorKind::InconsistentThresholds(
+                share.id,
+                share.threshold,
+                vec![first.id],
+                first.threshold
+            ));
+        }
+        if share.data.len() != data.len() {
+            bail!(ErrorKind::InconsistentSecretLengths(
+                share.id,
+                share.data.len(),
+                vec![first.id],
+                data.len()
+            ));
+        }
+
+        for (sum, &byte) in data.iter_mut().zip(&share.data) {
+            *sum ^= byte;
+        }
+    }
+
+    let share_set_ids = shares
+        .iter()
+        .map(|share| share.share_set_id)
+        .collect::<Option<Vec<_>>>();
+
+    Ok(Share {
+        id: first.id,
+        threshold: first.threshold,
+        data,
+        signature_pair: None,
+        share_set_id: share_set_ids.map(|mut ids| {
+            ids.sort();
+            derive_share_set_id(b"sum", &ids, &[])
+        }),
+        passphrase_protected: shares.iter().any(|share| share.passphrase_protected),
+    })
+}
+
+/// Multiplies the given share by the public constant `c`, and returns a share
+/// of the product of the secret with `c`, taken bytewise in GF(2^8).
+///
+/// The resulting share is not signed, and its share-set identifier is derived from
+/// the one of the given share and `c`, if the given share carries one.
+pub fn scale_share(share: &Share, c: u8) -> Share {
+    let c_ = Gf256::from_byte(c);
+    let data = share
+        .data
+        .iter()
+        .map(|&byte| (Gf256::from_byte(byte) * c_).to_byte())
+        .collect();
+
+    Share {
+        id: share.id,
+        threshold: share.threshold,
+        data,
+        signature_pair: None,
+        share_set_id: share
+            .share_set_id
+            .map(|id| derive_share_set_id(b"scale", &[id], &[c])),
+        passphrase_protected: share.passphrase_protected,
+    }
+}
+
+/// Derives the share-set identifier of the result of an operation on shares
+/// of the given sets, with the given parameters.
+fn derive_share_set_id(operation: &[u8], ids: &[ShareSetId], parameters: &[u8]) -> ShareSetId {
+    let mut ctx = digest::Context::new(&digest::SHA256);
+    ctx.update(b"rusty_secrets share arithmetic ");
+    ctx.update(operation);
+    for id in ids {
+        ctx.update(id.as_bytes());
+    }
+    ctx.update(parameters);
+
+    let digest = ctx.finish();
+    ShareSetId::from_bytes(&digest.as_ref()[..SHARE_SET_ID_LEN]).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use rand::{ChaChaRng, Rng, SeedableRng};
+
+    use super::*;
+    use sss::SSS;
+
+    fn split(rng: &mut ChaChaRng, secret: &[u8]) -> Vec<Share> {
+        SSS::default().split_secret(rng, 3, 5, secret, false).unwrap()
+    }
+
+    #[test]
+    fn sums_of_shares_are_shares_of_sums() {
+        let mut rng = ChaChaRng::from_seed(&[1, 2, 3]);
+        let a = split(&mut rng, b"Hello, World!");
+        let b = split(&mut rng, b"Bonjour, tout");
+        let c = split(&mut rng, &[42; 13]);
+
+        let sums = (0..5)
+            .map(|i| sum_shares(&[a[i].clone(), b[i].clone(), c[i].clone()]).unwrap())
+            .collect::<Vec<_>>();
+        let expected = b"Hello, World!"
+            .iter()
+            .zip(b"Bonjour, tout")
+            .map(|(&x, &y)| x ^ y ^ 42)
+            .collect::<Vec<_>>();
+        assert_eq!(SSS::recover_secret(sums[1..4].to_vec(), false).unwrap(), expected);
+
+        // The sums belong to the same set, whatever the order of the operands.
+        let reordered = sum_shares(&[c[0].clone(), a[0].clone(), b[0].clone()]).unwrap();
+        assert_eq!(reordered.share_set_id, sums[0].share_set_id);
+        assert_ne!(reordered.share_set_id, a[0].share_set_id);
+        let shares = vec![reordered, sums[3].clone(), sums[4].clone()];
+        assert_eq!(SSS::recover_secret(shares, false).unwrap(), expected);
+    }
+
+    #[test]
+    fn scaled_shares_are_shares_of_scaled_secrets() {
+        let mut rng = ChaChaRng::from_seed(&[4, 5, 6]);
+        let secret = rng.gen_iter::<u8>().take(32).collect::<Vec<_>>();
+        let shares = split(&mut rng, &secret);
+
+        let scaled = shares
+            .iter()
+            .map(|share| scale_share(share, 0x53))
+            .collect::<Vec<_>>();
+        let expected = secret
+            .iter()
+            .map(|&byte| (Gf256::from_byte(byte) * Gf256::from_byte(0x53)).to_byte())
+            .collect::<Vec<_>>();
+        assert_eq!(SSS::recover_secret(scaled[2..].to_vec(), false).unwrap(), expected);
+
+        let summed = add_shares(&scaled[0], &scale_share(&shares[0], 0x53)).unwrap();
+        assert!(summed.data.iter().all(|&byte| byte == 0));
+    }
+
+    #[test]
+    fn incompatible_shares_are_rejected() {
+        let mut rng = ChaChaRng::from_seed(&[7, 8, 9]);
+        let a = split(&mut rng, b"Hello, World!");
+        let b = split(&mut rng, b"Hello");
+        let c = SSS::default()
+            .split_secret(&mut rng, 2, 5, b"Hello, World!", false)
+            .unwrap();
+
+        let code = |a: &Share, b: &Share| add_shares(a, b).unwrap_err().kind().error_code();
+        assert_eq!(code(&a[0], &a[1]), ErrorCode::InconsistentShares);
+        assert_eq!(code(&a[0], &b[0]), ErrorCode::InconsistentSecretLengths);
+        assert_eq!(code(&a[0], &c[0]), ErrorCode::InconsistentThresholds);
+        assert_eq!(sum_shares(&[]).unwrap_err().kind().error_code(), ErrorCode::EmptyShares);
+    }
+}
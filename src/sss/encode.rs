@@ -1,4 +1,4 @@
-use gf256::Gf256;
+use field::Gf256;
 use std::io;
 use std::io::prelude::*;
 
@@ -1,9 +1,11 @@
 use base64;
 use errors::*;
+use hash::HashAlgorithm;
 use merkle_sigs::{MerklePublicKey, Proof, PublicKey};
 use proto::wrapped::ShareProto;
 use protobuf::{self, Message, RepeatedField};
-use sss::{Share, HASH_ALGO};
+use share_set::ShareSetId;
+use sss::{Share, SignaturePair};
 use std::error::Error;
 
 const BASE64_CONFIG: base64::Config = base64::STANDARD_NO_PAD;
@@ -12,15 +14,25 @@ pub(crate) fn share_to_string(
     share: Vec<u8>,
     threshold: u8,
     share_num: u8,
+    share_set_id: Option<ShareSetId>,
+    passphrase_protected: bool,
     signature_pair: Option<(Vec<Vec<u8>>, Proof<MerklePublicKey>)>,
 ) -> String {
     let mut share_protobuf = ShareProto::new();
     share_protobuf.set_shamir_data(share);
+    share_protobuf.set_passphrase_protected(passphrase_protected);
+
+    if let Some(share_set_id) = share_set_id {
+        share_protobuf.set_share_set_id(share_set_id.as_bytes().to_vec());
+    }
 
     if signature_pair.is_some() {
         let (signature, proof) = signature_pair.unwrap();
+        let hash_algorithm = HashAlgorithm::from_digest_algorithm(proof.algorithm)
+            .expect("Signatures are built on a supported fixed-length hash.");
         share_protobuf.set_signature(RepeatedField::from_vec(signature));
         share_protobuf.set_proof(proof.write_to_bytes().unwrap());
+        share_protobuf.set_signature_hash_algorithm(hash_algorithm.to_signature_proto());
     }
 
     let proto_buf = share_protobuf.write_to_bytes().unwrap();
@@ -29,6 +41,67 @@ pub(crate) fn share_to_string(
 }
 
 pub(crate) fn share_from_string(s: &str, is_signed: bool) -> Result<Share> {
+    let (k, i, protobuf_data) = share_protobuf_from_string(s)?;
+
+    let data = Vec::from(protobuf_data.get_shamir_data());
+    let share_set_id = ShareSetId::from_proto(protobuf_data.get_share_set_id())?;
+
+    // Unsigned shares are reported as such by `Share::verify_signatures`.
+    let signature_pair = if is_signed && !protobuf_data.get_proof().is_empty() {
+        Some(parse_signature_pair(
+            i,
+            protobuf_data.get_signature(),
+            protobuf_data.get_proof(),
+            HashAlgorithm::from_signature_proto(protobuf_data.get_signature_hash_algorithm()),
+        )?)
+    } else {
+        None
+    };
+
+    Ok(Share {
+        id: i,
+        data,
+        threshold: k,
+        signature_pair,
+        share_set_id,
+        passphrase_protected: protobuf_data.get_passphrase_protected(),
+    })
+}
+
+/// Rebuilds the signature of the share `id` from its encoded signature and proof
+/// of inclusion, signed with the given hash function.
+///
+/// Fails with `InvalidSignature` if the proof or the public key it holds cannot be decoded.
+pub(crate) fn parse_signature_pair(
+    id: u8,
+    signature: &[Vec<u8>],
+    proof: &[u8],
+    hash_algorithm: HashAlgorithm,
+) -> Result<SignaturePair> {
+    let invalid = |reason: &str| ErrorKind::InvalidSignature(id, reason.to_string());
+    let algorithm = hash_algorithm.signature_algorithm()?;
+
+    let p = Proof::parse_from_bytes(proof, algorithm)
+        .map_err(|e| invalid(e.description()))?
+        .ok_or_else(|| invalid("Missing proof of inclusion"))?;
+    let public_key =
+        PublicKey::from_vec(p.value, algorithm).ok_or_else(|| invalid("Invalid public key"))?;
+
+    let proof = Proof {
+        algorithm,
+        lemma: p.lemma,
+        root_hash: p.root_hash,
+        value: MerklePublicKey::new(public_key),
+    };
+
+    Ok((Vec::from(signature), proof).into())
+}
+
+/// Parses the `K-N-D` parts of a share, and decodes the Base64 encoding of its `D` part.
+///
+/// This is the string representation shared by the shares of all the schemes,
+/// whose `D` part holds a scheme-specific protobuf.
+pub(crate) fn share_payload_from_string(s: &str) -> Result<(u8, u8, Vec<u8>)> {
     let parts: Vec<_> = s.trim().split('-').collect();
 
     if parts.len() != SSS_SHARE_PARTS_COUNT {
@@ -57,48 +130,47 @@ pub(crate) fn share_from_string(s: &str, is_signed: bool) -> Result<Share> {
         bail!(ErrorKind::ShareParsingErrorEmptyShare(i))
     }
 
-    let raw_data = base64::decode_config(p3, BASE64_CONFIG).chain_err(|| {
+    let payload = base64::decode_config(p3, BASE64_CONFIG).chain_err(|| {
         ErrorKind::ShareParsingError("Base64 decoding of data block failed".to_owned())
     })?;
 
+    Ok((k, i, payload))
+}
+
+/// Parses the `K-N-D` parts of a share, and decodes the protobuf held in its `D` part.
+pub(crate) fn share_protobuf_from_string(s: &str) -> Result<(u8, u8, ShareProto)> {
+    let (k, i, payload) = share_payload_from_string(s)?;
+
     let protobuf_data =
-        protobuf::parse_from_bytes::<ShareProto>(raw_data.as_slice()).map_err(|e| {
+        protobuf::parse_from_bytes::<ShareProto>(payload.as_slice()).map_err(|e| {
             ErrorKind::ShareParsingError(format!(
                 "Protobuf decoding of data block failed with error: {} .",
                 e.description()
             ))
         })?;
 
-    let data = Vec::from(protobuf_data.get_shamir_data());
-
-    let signature_pair = if is_signed {
-        let p_result = Proof::parse_from_bytes(protobuf_data.get_proof(), HASH_ALGO);
-
-        let p_opt = p_result.unwrap();
-        let p = p_opt.unwrap();
-
-        let proof = Proof {
-            algorithm: HASH_ALGO,
-            lemma: p.lemma,
-            root_hash: p.root_hash,
-            value: MerklePublicKey::new(PublicKey::from_vec(p.value, HASH_ALGO).unwrap()),
-        };
-
-        let signature = protobuf_data.get_signature();
-        Some((Vec::from(signature), proof).into())
-    } else {
-        None
-    };
-
-    Ok(Share {
-        id: i,
-        data,
-        threshold: k,
-        signature_pair,
-    })
+    Ok((k, i, protobuf_data))
 }
 
-pub(crate) fn format_share_for_signing(k: u8, i: u8, data: &[u8]) -> Vec<u8> {
+/// Shares created without a share-set identifier keep the original
+/// `k-i-data` format, so that their signatures still verify.
+/// Shares which are not passphrase-protected do not mention it either, for the same reason,
+/// while protected ones are suffixed with `-protected`, which Base64 never produces, so that
+/// the flag cannot be cleared without invalidating their signature.
+pub(crate) fn format_share_for_signing(
+    k: u8,
+    i: u8,
+    share_set_id: Option<ShareSetId>,
+    passphrase_protected: bool,
+    data: &[u8],
+) -> Vec<u8> {
     let b64_data = base64::encode_config(data, BASE64_CONFIG);
-    format!("{}-{}-{}", k, i, b64_data).into_bytes()
+    let mut message = match share_set_id {
+        Some(share_set_id) => format!("{}-{}-{}-{}", k, i, share_set_id, b64_data),
+        None => format!("{}-{}-{}", k, i, b64_data),
+    };
+    if passphrase_protected {
+        message.push_str("-protected");
+    }
+    message.into_bytes()
 }
@@ -0,0 +1,129 @@
+//! Dealerless generation of a joint random secret, which no single participant ever knows.
+//!
+//! Each of the `n` participants deals a random secret of their own with `deal_contribution`,
+//! and sends the `j`-th share of it to the participant `j`. Every participant then sums the
+//! shares they received, one from each participant including themselves, with
+//! `combine_contributions`. The results are shares of the sum of all the random secrets,
+//! which can be recovered as usual by any `k` of the participants, while learning it
+//! otherwise requires the collusion of every participant.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use rusty_secrets::sss::joint::{combine_contributions, deal_contribution};
+//! use rusty_secrets::sss::SSS;
+//!
+//! // Each of the three participants deals a contribution.
+//! let contributions = (0..3)
+//!     .map(|_| deal_contribution(2, 3, 32).unwrap())
+//!     .collect::<Vec<_>>();
+//!
+//! // Each participant combines the shares they received.
+//! let shares = (0..3)
+//!     .map(|j| {
+//!         let received = contributions
+//!             .iter()
+//!             .map(|shares| shares[j].clone())
+//!             .collect::<Vec<_>>();
+//!         combine_contributions(&received).unwrap()
+//!     })
+//!     .collect::<Vec<_>>();
+//!
+//! // Any two of them can recover the joint secret.
+//! let secret = SSS::recover_secret(shares[1..].to_vec(), false).unwrap();
+//! assert_eq!(secret, SSS::recover_secret(shares[..2].to_vec(), false).unwrap());
+//! assert_eq!(secret.len(), 32);
+//! ```
+
+use std::collections::HashSet;
+
+use rand::{OsRng, Rng};
+
+use errors::*;
+use sss::arithmetic::sum_shares;
+use sss::{Share, SSS};
+
+/// Deals the contribution of a participant to a joint random secret of `secret_len` bytes,
+/// `k` out of the `n` participants being necessary to recover it.
+///
+/// Uses a `rand::OsRng` as a source of entropy. The `j`-th share is meant for the participant
+/// of identifier `j + 1`, and the random secret is forgotten.
+pub fn deal_contribution(k: u8, n: u8, secret_len: usize) -> Result<Vec<Share>> {
+    deal_contribution_rng(&mut OsRng::new()?, k, n, secret_len)
+}
+
+/// Same as `deal_contribution`, with a custom RNG.
+pub fn deal_contribution_rng<R: Rng>(
+    rng: &mut R,
+    k: u8,
+    n: u8,
+    secret_len: usize,
+) -> Result<Vec<Share>> {
+    let mut secret = vec![0u8; secret_len];
+    rng.fill_bytes(&mut secret);
+    SSS::default().split_secret(rng, k, n, &secret, false)
+}
+
+/// Sums the shares received by a participant, one from each contribution,
+/// into their share of the joint secret.
+///
+/// Fails with `DuplicateContribution` if two of the shares come from the same contribution,
+/// as they would cancel each other out, and otherwise as `sss::sum_shares` does.
+pub fn combine_contributions(received: &[Share]) -> Result<Share> {
+    let mut contributions = HashSet::new();
+    for share in received {
+        if let Some(share_set_id) = share.share_set_id {
+            if !contributions.insert(share_set_id) {
+                bail!(ErrorKind::DuplicateContribution(share_set_id));
+            }
+        }
+    }
+
+    sum_shares(received)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use rand::{ChaChaRng, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn any_threshold_of_participants_recover_the_same_secret() {
+        let mut rng = ChaChaRng::from_seed(&[1, 2, 3]);
+        let contributions = (0..5)
+            .map(|_| deal_contribution_rng(&mut rng, 3, 5, 16).unwrap())
+            .collect::<Vec<_>>();
+
+        let shares = (0..5)
+            .map(|j| {
+                let received = contributions
+                    .iter()
+                    .map(|shares| shares[j].clone())
+                    .collect::<Vec<_>>();
+                combine_contributions(&received).unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        let secret = SSS::recover_secret(shares[..3].to_vec(), false).unwrap();
+        let others = vec![shares[4].clone(), shares[1].clone(), shares[3].clone()];
+        assert_eq!(SSS::recover_secret(others, false).unwrap(), secret);
+
+        // The joint secret differs from every contribution.
+        for shares in &contributions {
+            assert_ne!(SSS::recover_secret(shares[..3].to_vec(), false).unwrap(), secret);
+        }
+    }
+
+    #[test]
+    fn duplicate_contributions_are_rejected() {
+        let mut rng = ChaChaRng::from_seed(&[4, 5, 6]);
+        let a = deal_contribution_rng(&mut rng, 2, 3, 16).unwrap();
+        let b = deal_contribution_rng(&mut rng, 2, 3, 16).unwrap();
+
+        let received = vec![a[0].clone(), b[0].clone(), a[0].clone()];
+        let error = combine_contributions(&received).unwrap_err();
+        assert_eq!(error.kind().error_code(), ErrorCode::DuplicateContribution);
+    }
+}
@@ -1,21 +1,28 @@
 //! SSS provides Shamir's secret sharing with raw data.
 
 use errors::*;
+use share_set::{self, SortedShares};
 
 mod share;
-pub(crate) use self::share::*;
+pub use self::share::{Share, SignaturePair};
+pub(crate) use self::share::verify_signature_pairs;
 
 mod format;
 // pub use self::format::*;
+pub(crate) use self::format::{parse_signature_pair, share_payload_from_string,
+                              share_protobuf_from_string};
 
 mod scheme;
-pub(crate) use self::scheme::*;
+pub use self::scheme::SSS;
 
 mod encode;
 
+mod arithmetic;
+pub use self::arithmetic::{add_shares, scale_share, sum_shares};
+
+pub mod joint;
+
 use rand::{OsRng, Rng};
-use ring::digest::{Algorithm, SHA512};
-static HASH_ALGO: &'static Algorithm = &SHA512;
 
 /// Performs threshold k-out-of-n Shamir's secret sharing.
 ///
@@ -116,3 +123,78 @@ pub fn recover_secret(shares: &[String], verify_signatures: bool) -> Result<Vec<
     let shares = Share::parse_all(shares, verify_signatures)?;
     SSS::recover_secret(shares, verify_signatures)
 }
+
+/// Performs threshold k-out-of-n Shamir's secret sharing of the secret encrypted under
+/// a key derived from the recovery `passphrase`, so that even `k` colluding share holders
+/// cannot recover it without the passphrase.
+///
+/// Uses a `rand::OsRng` as a source of entropy.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_secrets::sss::{recover_secret_with_passphrase, split_secret_with_passphrase};
+///
+/// let shares = split_secret_with_passphrase(2, 3, b"The secret", "correct horse", false).unwrap();
+///
+/// let secret = recover_secret_with_passphrase(&shares[1..], "correct horse", false).unwrap();
+/// assert_eq!(secret, b"The secret");
+/// assert!(recover_secret_with_passphrase(&shares[1..], "wrong horse", false).is_err());
+/// ```
+pub fn split_secret_with_passphrase(
+    k: u8,
+    n: u8,
+    secret: &[u8],
+    passphrase: &str,
+    sign_shares: bool,
+) -> Result<Vec<String>> {
+    SSS::default()
+        .split_secret_with_passphrase(&mut OsRng::new()?, k, n, secret, passphrase, sign_shares)
+        .map(|shares| shares.into_iter().map(Share::into_string).collect())
+}
+
+/// Recovers the secret from shares created by `split_secret_with_passphrase`.
+///
+/// Fails with `WrongPassphrase` if the recovery `passphrase` is wrong. Note that
+/// `recover_secret` fails with `MissingPassphrase` on such shares.
+pub fn recover_secret_with_passphrase(
+    shares: &[String],
+    passphrase: &str,
+    verify_signatures: bool,
+) -> Result<Vec<u8>> {
+    let shares = Share::parse_all(shares, verify_signatures)?;
+    SSS::recover_secret_with_passphrase(shares, passphrase, verify_signatures)
+}
+
+/// Sorts a pile of shares coming from several splits by share set, reports
+/// how many shares each set is missing, and recovers the secret of every set
+/// for which enough shares were provided.
+///
+/// Shares which cannot be parsed are reported along with the reason why.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_secrets::sss::{sort_shares, split_secret};
+///
+/// let mut shares = split_secret(2, 3, b"first secret", false).unwrap();
+/// shares.extend(split_secret(3, 5, b"second secret", false).unwrap().into_iter().take(2));
+///
+/// let sorted = sort_shares(&shares, false);
+/// assert_eq!(sorted.sets.len(), 2);
+///
+/// let first = &sorted.sets[0];
+/// assert_eq!(first.recovered.as_ref().unwrap().as_ref().unwrap(), b"first secret");
+///
+/// let second = &sorted.sets[1];
+/// assert_eq!(second.threshold, 3);
+/// assert_eq!(second.missing, 1);
+/// assert!(second.recovered.is_none());
+/// ```
+pub fn sort_shares(shares: &[String], verify_signatures: bool) -> SortedShares<Vec<u8>> {
+    share_set::sort_shares(
+        shares,
+        |raw| Share::from_string(raw, verify_signatures),
+        |shares| SSS::recover_secret(shares, verify_signatures),
+    )
+}
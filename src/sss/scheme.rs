@@ -1,19 +1,39 @@
 //! SSS provides Shamir's secret sharing with raw data.
 
 use merkle_sigs::sign_data_vec;
-use rand::Rng;
+use rand::{OsRng, Rng};
 
 use errors::*;
-use lagrange::interpolate_at;
+use field::Gf256;
+use hash::{HashAlgorithm, DEFAULT_SIGNATURE_HASH_ALGORITHM};
+use lagrange;
+use passphrase::{self, KeyDerivation};
+use scheme::{SchemeKind, SecretSharingScheme};
 use share::validation::{validate_share_count, validate_signed_shares};
+use share_set::ShareSetId;
 use sss::format::format_share_for_signing;
-use sss::{Share, HASH_ALGO};
+use sss::Share;
 
 use super::encode::encode_secret_byte;
 
 /// SSS provides Shamir's secret sharing with raw data.
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
-pub(crate) struct SSS;
+///
+/// Shares are signed with SHA-512 by default, and the hash function on which
+/// signatures are built is recorded in the shares.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SSS {
+    /// The hash function on which the signatures of the shares are built,
+    /// which must be a fixed-length one
+    pub signature_hash_algorithm: HashAlgorithm,
+}
+
+impl Default for SSS {
+    fn default() -> Self {
+        SSS {
+            signature_hash_algorithm: DEFAULT_SIGNATURE_HASH_ALGORITHM,
+        }
+    }
+}
 
 impl SSS {
     /// Performs threshold k-out-of-n Shamir's secret sharing.
@@ -24,18 +44,42 @@ impl SSS {
         shares_count: u8,
         secret: &[u8],
         sign_shares: bool,
+    ) -> Result<Vec<Share>> {
+        self.deal_shares(rng, threshold, shares_count, secret, false, sign_shares)
+    }
+
+    /// Splits the secret into shares flagged with `passphrase_protected`,
+    /// which is covered by their signatures.
+    fn deal_shares<R: Rng>(
+        &self,
+        rng: &mut R,
+        threshold: u8,
+        shares_count: u8,
+        secret: &[u8],
+        passphrase_protected: bool,
+        sign_shares: bool,
     ) -> Result<Vec<Share>> {
         let (threshold, shares_count) = validate_share_count(threshold, shares_count)?;
         let shares = Self::secret_share(rng, secret, threshold, shares_count)?;
+        let share_set_id = ShareSetId::random(rng);
 
         let signatures = if sign_shares {
             let shares_to_sign = shares
                 .iter()
                 .enumerate()
-                .map(|(i, x)| format_share_for_signing(threshold, (i + 1) as u8, x))
+                .map(|(i, x)| {
+                    format_share_for_signing(
+                        threshold,
+                        (i + 1) as u8,
+                        Some(share_set_id),
+                        passphrase_protected,
+                        x,
+                    )
+                })
                 .collect::<Vec<_>>();
 
-            let sign = sign_data_vec(&shares_to_sign, HASH_ALGO)
+            let signature_algorithm = self.signature_hash_algorithm.signature_algorithm()?;
+            let sign = sign_data_vec(&shares_to_sign, signature_algorithm)
                 .unwrap()
                 .into_iter()
                 .map(Some)
@@ -62,12 +106,35 @@ impl SSS {
                 threshold,
                 data,
                 signature_pair,
+                share_set_id: Some(share_set_id),
+                passphrase_protected,
             }
         });
 
         Ok(result.collect())
     }
 
+    /// Performs threshold k-out-of-n Shamir's secret sharing of the secret encrypted
+    /// under a key derived from the recovery `passphrase`, so that the shares alone
+    /// are not enough to recover it.
+    ///
+    /// The key is derived with `KeyDerivation::random`, and the salt and nonce
+    /// of the encryption are drawn from a `ring::rand::SystemRandom`. The shares are
+    /// flagged as protected, so that `SSS::recover_secret` refuses to recover them.
+    pub fn split_secret_with_passphrase<R: Rng>(
+        &self,
+        rng: &mut R,
+        threshold: u8,
+        shares_count: u8,
+        secret: &[u8],
+        passphrase: &str,
+        sign_shares: bool,
+    ) -> Result<Vec<Share>> {
+        let key_derivation = KeyDerivation::random()?;
+        let encrypted = passphrase::encrypt_secret(secret, passphrase, &key_derivation)?;
+        self.deal_shares(rng, threshold, shares_count, &encrypted, true, sign_shares)
+    }
+
     fn secret_share<R: Rng>(
         rng: &mut R,
         src: &[u8],
@@ -97,19 +164,72 @@ impl SSS {
     /// Recovers the secret from a k-out-of-n Shamir's secret sharing.
     ///
     /// At least `k` distinct shares need to be provided to recover the share.
+    ///
+    /// Fails with `MissingPassphrase` if the shares were created by
+    /// `SSS::split_secret_with_passphrase`.
     pub fn recover_secret(shares: Vec<Share>, verify_signatures: bool) -> Result<Vec<u8>> {
+        if shares.iter().any(|share| share.passphrase_protected) {
+            bail!(ErrorKind::MissingPassphrase);
+        }
+        Self::interpolate_secret(shares, verify_signatures)
+    }
+
+    fn interpolate_secret(shares: Vec<Share>, verify_signatures: bool) -> Result<Vec<u8>> {
         let (threshold, slen) = validate_signed_shares(&shares, verify_signatures)?;
 
-        let mut col_in = Vec::with_capacity(threshold as usize);
-        let mut secret = Vec::with_capacity(slen);
-        for byteindex in 0..slen {
-            col_in.clear();
-            for s in shares.iter().take(threshold as usize) {
-                col_in.push((s.id, s.data[byteindex]));
-            }
-            secret.push(interpolate_at(threshold, &*col_in));
-        }
+        let shares = &shares[..threshold as usize];
+
+        // The Lagrange basis only depends on the share identifiers,
+        // so it is computed once rather than for every byte.
+        let ids = shares
+            .iter()
+            .map(|s| Gf256::from_byte(s.id))
+            .collect::<Vec<_>>();
+        let coeffs = lagrange::basis_at(&ids, Gf256::zero());
+
+        let secret = (0..slen)
+            .map(|byteindex| {
+                lagrange::interpolate_with(&coeffs, shares.iter().map(|s| s.data[byteindex]))
+            })
+            .collect();
 
         Ok(secret)
     }
+
+    /// Recovers the secret from shares created by `SSS::split_secret_with_passphrase`.
+    ///
+    /// Fails with `WrongPassphrase` if the recovery `passphrase` is wrong.
+    pub fn recover_secret_with_passphrase(
+        shares: Vec<Share>,
+        passphrase: &str,
+        verify_signatures: bool,
+    ) -> Result<Vec<u8>> {
+        let encrypted = Self::interpolate_secret(shares, verify_signatures)?;
+        passphrase::decrypt_secret(&encrypted, passphrase)
+    }
+}
+
+impl SecretSharingScheme for SSS {
+    type Share = Share;
+
+    fn kind(&self) -> SchemeKind {
+        SchemeKind::SSS
+    }
+
+    /// Splits the secret, using a `rand::OsRng` as a source of entropy.
+    fn split(&self, k: u8, n: u8, secret: &[u8], sign_shares: bool) -> Result<Vec<Share>> {
+        self.split_secret(&mut OsRng::new()?, k, n, secret, sign_shares)
+    }
+
+    fn recover(&self, shares: &[Share], verify_signatures: bool) -> Result<Vec<u8>> {
+        Self::recover_secret(shares.to_vec(), verify_signatures)
+    }
+
+    fn parse_share(&self, raw: &str) -> Result<Share> {
+        Share::from_string(raw, true)
+    }
+
+    fn format_share(&self, share: &Share) -> String {
+        share.clone().into_string()
+    }
 }
@@ -6,6 +6,7 @@ use merkle_sigs::{MerklePublicKey, Proof};
 
 use errors::*;
 use share::{IsShare, IsSignedShare};
+use share_set::ShareSetId;
 use sss::format::{format_share_for_signing, share_from_string, share_to_string};
 
 /// A share identified by an `id`, a threshold `k`, a number of total shares `n`,
@@ -13,7 +14,7 @@ use sss::format::{format_share_for_signing, share_from_string, share_to_string};
 // #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 // TODO: Write manual instances which ignore the signature / fix merkle_sigs+merkle.rs
 #[derive(Clone, Debug)]
-pub(crate) struct Share {
+pub struct Share {
     /// The identifier of the share (varies between 1 and n where n is the total number of generated shares)
     pub id: u8,
     /// The number of shares necessary to recover the secret, aka a threshold
@@ -23,6 +24,11 @@ pub(crate) struct Share {
     /// If the share is signed, this fields holds the signature
     /// along with the proof of inclusion into the underlying MerkleTree.
     pub signature_pair: Option<SignaturePair>,
+    /// The identifier of the share set this share belongs to, if any.
+    pub share_set_id: Option<ShareSetId>,
+    /// Whether the shared secret is encrypted under a recovery passphrase,
+    /// and must be recovered with `recover_secret_with_passphrase`.
+    pub passphrase_protected: bool,
 }
 
 impl Share {
@@ -54,13 +60,16 @@ impl Share {
     /// - N is the identifier of the share and varies between 1 and n where
     ///   n is the total number of generated shares.
     /// - D is a Base64 encoding of a ShareData protobuf containing
-    ///   information about the share, and if signed, the signature.
+    ///   information about the share, its share-set identifier,
+    ///   and if signed, the signature.
     /// ```
     pub fn into_string(self) -> String {
         share_to_string(
             self.data,
             self.threshold,
             self.id,
+            self.share_set_id,
+            self.passphrase_protected,
             self.signature_pair.map(Into::into),
         )
     }
@@ -82,56 +91,30 @@ impl IsShare for Share {
     fn get_shares_count(&self) -> Option<u8> {
         None
     }
+
+    fn get_share_set_id(&self) -> Option<ShareSetId> {
+        self.share_set_id
+    }
 }
 
 impl IsSignedShare for Share {
     type Signature = Option<SignaturePair>;
 
     fn verify_signatures(shares: &[Self]) -> Result<()> {
-        let mut rh_compatibility_sets = HashMap::new();
-
-        for share in shares {
-            if !share.is_signed() {
-                bail!(ErrorKind::MissingSignature(share.get_id()));
-            }
-
-            let sig_pair = share.signature_pair.as_ref().unwrap();
-            let signature = &sig_pair.signature;
-            let proof = &sig_pair.proof;
-            let root_hash = &proof.root_hash;
-
-            verify_data_vec_signature(
-                format_share_for_signing(share.threshold, share.id, share.data.as_slice()),
-                &(signature.to_vec(), proof.clone()),
-                root_hash,
-            ).map_err(|e| ErrorKind::InvalidSignature(share.id, String::from(e.description())))?;
-
-            rh_compatibility_sets
-                .entry(root_hash)
-                .or_insert_with(HashSet::new);
-
-            let rh_set = rh_compatibility_sets.get_mut(&root_hash).unwrap();
-            rh_set.insert(share.id);
-        }
-
-        let rh_sets = rh_compatibility_sets.keys().count();
-
-        match rh_sets {
-            0 => bail!(ErrorKind::EmptyShares),
-            1 => {} // All shares have the same roothash.
-            _ => {
-                bail! {
-                    ErrorKind::IncompatibleSets(
-                        rh_compatibility_sets
-                            .values()
-                            .map(|x| x.to_owned())
-                            .collect(),
-                    )
-                }
-            }
-        }
-
-        Ok(())
+        let messages = shares
+            .iter()
+            .map(|share| {
+                let message = format_share_for_signing(
+                    share.threshold,
+                    share.id,
+                    share.share_set_id,
+                    share.passphrase_protected,
+                    share.data.as_slice(),
+                );
+                (share.id, message, share.signature_pair.as_ref())
+            })
+            .collect::<Vec<_>>();
+        verify_signature_pairs(&messages)
     }
 
     fn is_signed(&self) -> bool {
@@ -143,6 +126,58 @@ impl IsSignedShare for Share {
     }
 }
 
+/// Verifies the signatures of the given `(id, message, signature)` triples, which must all
+/// be signed, and checks that they were all signed by the same dealer, that is that their
+/// proofs lead to the same Merkle root.
+pub(crate) fn verify_signature_pairs(
+    messages: &[(u8, Vec<u8>, Option<&SignaturePair>)],
+) -> Result<()> {
+    let mut rh_compatibility_sets = HashMap::new();
+
+    for &(id, ref message, sig_pair) in messages {
+        let sig_pair = match sig_pair {
+            Some(sig_pair) => sig_pair,
+            None => bail!(ErrorKind::MissingSignature(id)),
+        };
+
+        let signature = &sig_pair.signature;
+        let proof = &sig_pair.proof;
+        let root_hash = &proof.root_hash;
+
+        verify_data_vec_signature(
+            message.clone(),
+            &(signature.to_vec(), proof.clone()),
+            root_hash,
+        ).map_err(|e| ErrorKind::InvalidSignature(id, String::from(e.description())))?;
+
+        rh_compatibility_sets
+            .entry(root_hash)
+            .or_insert_with(HashSet::new);
+
+        let rh_set = rh_compatibility_sets.get_mut(&root_hash).unwrap();
+        rh_set.insert(id);
+    }
+
+    let rh_sets = rh_compatibility_sets.keys().count();
+
+    match rh_sets {
+        0 => bail!(ErrorKind::EmptyShares),
+        1 => {} // All shares have the same roothash.
+        _ => {
+            bail! {
+                ErrorKind::IncompatibleSets(
+                    rh_compatibility_sets
+                        .values()
+                        .map(|x| x.to_owned())
+                        .collect(),
+                )
+            }
+        }
+    }
+
+    Ok(())
+}
+
 #[derive(Clone, Debug)]
 /// Holds the signature along with the proof of inclusion
 /// in the underlying Merkle tree used in the Lamport signature scheme.
@@ -1,45 +1,62 @@
 use std;
 use std::mem::transmute;
 
-use ring::digest::{Algorithm, Context};
+use ring::digest::Context;
+
+use hash::{HashAlgorithm, Hasher};
 
 #[allow(unsafe_code)]
 fn u32_to_bytes(x: u32) -> [u8; 4] {
     unsafe { transmute(x.to_be()) }
 }
 
+/// A variable output length hash function, built on top of a regular one,
+/// or directly provided by an extendable-output function.
+///
+/// The input is fed to a running hash context as it is processed, and never buffered,
+/// which is why the output length, that the input is prefixed with, must be given up front.
 pub struct VOLHash {
-    algorithm: &'static Algorithm,
-    bytes: Vec<u8>,
+    algorithm: HashAlgorithm,
+    output_len: usize,
+    hasher: Hasher,
 }
 
 impl VOLHash {
-    pub fn new(algorithm: &'static Algorithm) -> VOLHash {
+    pub fn new(algorithm: HashAlgorithm, output_len: usize) -> VOLHash {
+        assert!(output_len < std::u32::MAX as usize);
+
+        let mut hasher = Hasher::new(algorithm);
+        hasher.update(&[0u8]);
+        hasher.update(&u32_to_bytes(output_len as u32));
+
         Self {
             algorithm,
-            bytes: Vec::new(),
+            output_len,
+            hasher,
         }
     }
 
     pub fn process(&mut self, bytes: &[u8]) {
-        self.bytes.extend_from_slice(bytes)
+        self.hasher.update(bytes)
     }
 
     pub fn finish(self, dest: &mut [u8]) {
-        let len = dest.len();
-        assert!(len < std::u32::MAX as usize);
+        let len = self.output_len;
+        assert_eq!(dest.len(), len);
 
-        let mut ctx = Context::new(self.algorithm);
-        ctx.update(&[0u8]);
-        ctx.update(&u32_to_bytes(len as u32));
-        ctx.update(&self.bytes);
+        let digest_algorithm = match self.algorithm.digest_algorithm() {
+            Some(digest_algorithm) => digest_algorithm,
+            // An extendable-output function produces the whole output by itself.
+            None => return self.hasher.finish(dest),
+        };
 
-        let mut state = ctx.finish().as_ref().to_vec();
+        let mut state = vec![0u8; digest_algorithm.output_len];
+        self.hasher.finish(&mut state);
 
-        let iter_num = len / self.algorithm.output_len;
+        let iter_num = len / digest_algorithm.output_len;
 
         for i in 0..iter_num {
-            let mut inner_ctx = Context::new(self.algorithm);
+            let mut inner_ctx = Context::new(digest_algorithm);
             inner_ctx.update(&[255u8]);
             inner_ctx.update(&u32_to_bytes(1 + i as u32));
             inner_ctx.update(&state);
@@ -49,7 +66,96 @@ impl VOLHash {
 
         assert!(state.len() >= len);
 
-        let src = state.drain(0..len).collect::<Vec<_>>();
-        dest.copy_from_slice(&src);
+        dest.copy_from_slice(&state[..len]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use ring::digest::Algorithm;
+
+    /// The original implementation, which buffered its whole input.
+    fn buffered_hash(algorithm: &'static Algorithm, bytes: &[u8], dest: &mut [u8]) {
+        let len = dest.len();
+
+        let mut ctx = Context::new(algorithm);
+        ctx.update(&[0u8]);
+        ctx.update(&u32_to_bytes(len as u32));
+        ctx.update(bytes);
+
+        let mut state = ctx.finish().as_ref().to_vec();
+        for i in 0..len / algorithm.output_len {
+            let mut inner_ctx = Context::new(algorithm);
+            inner_ctx.update(&[255u8]);
+            inner_ctx.update(&u32_to_bytes(1 + i as u32));
+            inner_ctx.update(&state);
+            state.extend_from_slice(inner_ctx.finish().as_ref())
+        }
+
+        dest.copy_from_slice(&state[..len]);
+    }
+
+    #[test]
+    fn output_is_unchanged() {
+        let mut vol_hash = VOLHash::new(HashAlgorithm::SHA256, 40);
+        vol_hash.process(&[0]);
+        vol_hash.process(&[2, 3]);
+        vol_hash.process(b"Hello");
+
+        let mut dest = vec![0; 40];
+        vol_hash.finish(&mut dest);
+
+        let expected = "262cb1b09a3075a9b800dfbffa5cecd3d3147098ee0dc6215b9ee50192391e6a\
+                        225fe22a0c4e57ee";
+        let found = dest.iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect::<String>();
+        assert_eq!(found, expected);
+    }
+
+    quickcheck! {
+        fn matches_buffered_hash(chunks: Vec<Vec<u8>>, len: u16) -> bool {
+            [HashAlgorithm::SHA256, HashAlgorithm::SHA512].iter().all(|&algorithm| {
+                let len = len as usize;
+
+                let mut vol_hash = VOLHash::new(algorithm, len);
+                for chunk in &chunks {
+                    vol_hash.process(chunk);
+                }
+                let mut streamed = vec![0; len];
+                vol_hash.finish(&mut streamed);
+
+                let mut buffered = vec![0; len];
+                buffered_hash(
+                    algorithm.digest_algorithm().unwrap(),
+                    &chunks.concat(),
+                    &mut buffered,
+                );
+
+                streamed == buffered
+            })
+        }
+
+        fn xof_output_is_squeezed_directly(chunks: Vec<Vec<u8>>, len: u16) -> bool {
+            let len = len as usize;
+
+            let mut vol_hash = VOLHash::new(HashAlgorithm::SHAKE256, len);
+            for chunk in &chunks {
+                vol_hash.process(chunk);
+            }
+            let mut streamed = vec![0; len];
+            vol_hash.finish(&mut streamed);
+
+            let mut hasher = Hasher::new(HashAlgorithm::SHAKE256);
+            hasher.update(&[0u8]);
+            hasher.update(&u32_to_bytes(len as u32));
+            hasher.update(&chunks.concat());
+            let mut expected = vec![0; len];
+            hasher.finish(&mut expected);
+
+            streamed == expected
+        }
     }
 }
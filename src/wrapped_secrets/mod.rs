@@ -2,11 +2,13 @@
 
 use errors::*;
 use proto::wrapped::SecretProto;
+use share_set::{self, SortedShares};
 
 use rand::{OsRng, Rng};
 
 mod scheme;
-pub(crate) use self::scheme::*;
+pub use self::scheme::WrappedSecrets;
+pub(crate) use self::scheme::Share;
 
 /// Performs threshold k-out-of-n Shamir's secret sharing.
 ///
@@ -122,3 +124,54 @@ pub fn recover_secret(shares: &[String], verify_signatures: bool) -> Result<Secr
     let shares = Share::parse_all(shares, verify_signatures)?;
     WrappedSecrets::recover_secret(shares, verify_signatures)
 }
+
+/// Performs threshold k-out-of-n Shamir's secret sharing of the wrapped secret,
+/// encrypted under a key derived from the recovery `passphrase`.
+///
+/// Uses an `OsRng` as a source of entropy.
+/// See `rusty_secrets::sss::split_secret_with_passphrase` for more details.
+pub fn split_secret_with_passphrase(
+    k: u8,
+    n: u8,
+    secret: &[u8],
+    mime_type: Option<String>,
+    passphrase: &str,
+    sign_shares: bool,
+) -> Result<Vec<String>> {
+    WrappedSecrets::default()
+        .split_secret_with_passphrase(
+            &mut OsRng::new()?,
+            k,
+            n,
+            secret,
+            mime_type,
+            passphrase,
+            sign_shares,
+        )
+        .map(|shares| shares.into_iter().map(Share::into_string).collect())
+}
+
+/// Recovers the secret from shares created by `split_secret_with_passphrase`.
+///
+/// Fails with `WrongPassphrase` if the recovery `passphrase` is wrong.
+pub fn recover_secret_with_passphrase(
+    shares: &[String],
+    passphrase: &str,
+    verify_signatures: bool,
+) -> Result<SecretProto> {
+    let shares = Share::parse_all(shares, verify_signatures)?;
+    WrappedSecrets::recover_secret_with_passphrase(shares, passphrase, verify_signatures)
+}
+
+/// Sorts a pile of shares coming from several splits by share set, reports
+/// how many shares each set is missing, and recovers the secret of every set
+/// for which enough shares were provided.
+///
+/// See `rusty_secrets::sss::sort_shares` for more details.
+pub fn sort_shares(shares: &[String], verify_signatures: bool) -> SortedShares<SecretProto> {
+    share_set::sort_shares(
+        shares,
+        |raw| Share::from_string(raw, verify_signatures),
+        |shares| WrappedSecrets::recover_secret(shares, verify_signatures),
+    )
+}
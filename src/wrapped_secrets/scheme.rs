@@ -3,13 +3,18 @@ use proto::VersionProto;
 use proto::wrapped::SecretProto;
 use protobuf;
 use protobuf::Message;
-use rand::Rng;
+use rand::{OsRng, Rng};
 
+use scheme::{SchemeKind, SecretSharingScheme};
 use sss::SSS;
 pub(crate) use sss::Share;
 
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
-pub(crate) struct WrappedSecrets;
+/// Shamir's secret sharing of a secret wrapped along with its version and MIME type.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct WrappedSecrets {
+    /// The MIME type attached to the secrets split through `SecretSharingScheme::split`
+    pub mime_type: Option<String>,
+}
 
 impl WrappedSecrets {
     /// Performs threshold k-out-of-n Shamir's secret sharing.
@@ -22,17 +27,29 @@ impl WrappedSecrets {
         mime_type: Option<String>,
         sign_shares: bool,
     ) -> Result<Vec<Share>> {
-        let mut rusty_secret = SecretProto::new();
-        rusty_secret.set_version(VersionProto::INITIAL_RELEASE);
-        rusty_secret.set_secret(secret.to_owned());
+        let data = wrap_secret(secret, mime_type);
 
-        if let Some(mt) = mime_type {
-            rusty_secret.set_mime_type(mt);
-        }
+        SSS::default().split_secret(rng, k, n, data.as_slice(), sign_shares)
+    }
 
-        let data = rusty_secret.write_to_bytes().unwrap();
+    /// Performs threshold k-out-of-n Shamir's secret sharing of the secret, wrapped along
+    /// with its `mime_type` and then encrypted under a key derived from the recovery
+    /// `passphrase`, so that the shares alone are not enough to recover it.
+    ///
+    /// See `SSS::split_secret_with_passphrase` for more details.
+    pub fn split_secret_with_passphrase<R: Rng>(
+        &self,
+        rng: &mut R,
+        k: u8,
+        n: u8,
+        secret: &[u8],
+        mime_type: Option<String>,
+        passphrase: &str,
+        sign_shares: bool,
+    ) -> Result<Vec<Share>> {
+        let data = wrap_secret(secret, mime_type);
 
-        SSS::default().split_secret(rng, k, n, data.as_slice(), sign_shares)
+        SSS::default().split_secret_with_passphrase(rng, k, n, &data, passphrase, sign_shares)
     }
 
     /// Recovers the secret from a k-out-of-n Shamir's secret sharing.
@@ -44,4 +61,60 @@ impl WrappedSecrets {
         protobuf::parse_from_bytes::<SecretProto>(secret.as_slice())
             .chain_err(|| ErrorKind::SecretDeserializationError)
     }
+
+    /// Recovers the secret from shares created by `WrappedSecrets::split_secret_with_passphrase`.
+    ///
+    /// Fails with `WrongPassphrase` if the recovery `passphrase` is wrong.
+    pub fn recover_secret_with_passphrase(
+        shares: Vec<Share>,
+        passphrase: &str,
+        verify_signatures: bool,
+    ) -> Result<SecretProto> {
+        let secret = SSS::recover_secret_with_passphrase(shares, passphrase, verify_signatures)?;
+
+        protobuf::parse_from_bytes::<SecretProto>(secret.as_slice())
+            .chain_err(|| ErrorKind::SecretDeserializationError)
+    }
+}
+
+fn wrap_secret(secret: &[u8], mime_type: Option<String>) -> Vec<u8> {
+    let mut rusty_secret = SecretProto::new();
+    rusty_secret.set_version(VersionProto::INITIAL_RELEASE);
+    rusty_secret.set_secret(secret.to_owned());
+
+    if let Some(mt) = mime_type {
+        rusty_secret.set_mime_type(mt);
+    }
+
+    rusty_secret.write_to_bytes().unwrap()
+}
+
+impl SecretSharingScheme for WrappedSecrets {
+    type Share = Share;
+
+    fn kind(&self) -> SchemeKind {
+        SchemeKind::WrappedSecrets
+    }
+
+    /// Splits the secret, wrapped along with `self.mime_type`,
+    /// using a `rand::OsRng` as a source of entropy.
+    fn split(&self, k: u8, n: u8, secret: &[u8], sign_shares: bool) -> Result<Vec<Share>> {
+        let mime_type = self.mime_type.clone();
+        self.split_secret(&mut OsRng::new()?, k, n, secret, mime_type, sign_shares)
+    }
+
+    /// Recovers the unwrapped secret. Its MIME type and version can be retrieved
+    /// with `WrappedSecrets::recover_secret`.
+    fn recover(&self, shares: &[Share], verify_signatures: bool) -> Result<Vec<u8>> {
+        Self::recover_secret(shares.to_vec(), verify_signatures)
+            .map(|mut secret| secret.take_secret())
+    }
+
+    fn parse_share(&self, raw: &str) -> Result<Share> {
+        Share::from_string(raw, true)
+    }
+
+    fn format_share(&self, share: &Share) -> String {
+        share.clone().into_string()
+    }
 }
@@ -0,0 +1,112 @@
+use std::error::Error;
+
+use base64;
+use protobuf::{self, Message, RepeatedField};
+
+use errors::*;
+use hash::HashAlgorithm;
+use proto::xor::ShareProto;
+use share_set::ShareSetId;
+use sss::{parse_signature_pair, share_payload_from_string, SignaturePair};
+use xor::Share;
+
+const BASE64_CONFIG: base64::Config = base64::STANDARD_NO_PAD;
+
+pub(crate) fn share_to_string(
+    data: Vec<u8>,
+    shares_count: u8,
+    id: u8,
+    share_set_id: Option<ShareSetId>,
+    signature_pair: Option<SignaturePair>,
+) -> String {
+    let mut share_protobuf = ShareProto::new();
+    share_protobuf.set_xor_data(data);
+
+    if let Some(share_set_id) = share_set_id {
+        share_protobuf.set_share_set_id(share_set_id.as_bytes().to_vec());
+    }
+
+    if let Some(signature_pair) = signature_pair {
+        let proof = signature_pair.proof;
+        let hash_algorithm = HashAlgorithm::from_digest_algorithm(proof.algorithm)
+            .expect("Signatures are built on a supported fixed-length hash.");
+        share_protobuf.set_signature(RepeatedField::from_vec(signature_pair.signature));
+        share_protobuf.set_proof(proof.write_to_bytes().unwrap());
+        share_protobuf.set_signature_hash_algorithm(hash_algorithm.to_signature_proto());
+    }
+
+    let proto_buf = share_protobuf.write_to_bytes().unwrap();
+    let b64_share = base64::encode_config(&proto_buf, BASE64_CONFIG);
+    format!("{}-{}-{}", shares_count, id, b64_share)
+}
+
+pub(crate) fn share_from_string(s: &str, is_signed: bool) -> Result<Share> {
+    let (n, i, protobuf_data) = share_protobuf_from_string(s)?;
+
+    if protobuf_data.get_xor_data().is_empty() {
+        // The fields of Xor shares are numbered apart from the ones of the other schemes,
+        // so that the fields of their shares are left unknown.
+        if protobuf_data.get_unknown_fields().iter().next().is_some() {
+            bail!(ErrorKind::ShareParsingError(
+                "The share is not an Xor share.".to_string()
+            ));
+        }
+        bail!(ErrorKind::ShareParsingErrorEmptyShare(i));
+    }
+
+    let share_set_id = ShareSetId::from_proto(protobuf_data.get_share_set_id())?;
+
+    // Unsigned shares are reported as such by `Share::verify_signatures`.
+    let signature_pair = if is_signed && !protobuf_data.get_proof().is_empty() {
+        Some(parse_signature_pair(
+            i,
+            protobuf_data.get_signature(),
+            protobuf_data.get_proof(),
+            HashAlgorithm::from_signature_proto(protobuf_data.get_signature_hash_algorithm()),
+        )?)
+    } else {
+        None
+    };
+
+    Ok(Share {
+        id: i,
+        shares_count: n,
+        data: protobuf_data.get_xor_data().to_vec(),
+        signature_pair,
+        share_set_id,
+    })
+}
+
+/// Parses the `N-I-D` parts of a share, and decodes the protobuf held in its `D` part.
+pub(crate) fn share_protobuf_from_string(s: &str) -> Result<(u8, u8, ShareProto)> {
+    let (n, i, payload) = share_payload_from_string(s)?;
+
+    if i > n {
+        bail!(ErrorKind::ShareParsingInvalidShareId(i))
+    }
+
+    let protobuf_data =
+        protobuf::parse_from_bytes::<ShareProto>(payload.as_slice()).map_err(|e| {
+            ErrorKind::ShareParsingError(format!(
+                "Protobuf decoding of data block failed with error: {} .",
+                e.description()
+            ))
+        })?;
+
+    Ok((n, i, protobuf_data))
+}
+
+/// The message is prefixed with the name of the scheme, so that the signature of a share
+/// cannot be mistaken for the one of a share of the `SSS` scheme.
+pub(crate) fn format_share_for_signing(
+    n: u8,
+    i: u8,
+    share_set_id: Option<ShareSetId>,
+    data: &[u8],
+) -> Vec<u8> {
+    let share_set_id = share_set_id
+        .map(|share_set_id| share_set_id.to_string())
+        .unwrap_or_default();
+    let b64_data = base64::encode_config(data, BASE64_CONFIG);
+    format!("xor-{}-{}-{}-{}", n, i, share_set_id, b64_data).into_bytes()
+}
@@ -0,0 +1,92 @@
+//! Additive n-of-n secret sharing, where all the shares are necessary to recover the secret.
+//!
+//! When every party must cooperate, Shamir's secret sharing with a threshold of `n` works,
+//! but pays for the evaluation of a polynomial of degree `n - 1` for every byte of the secret.
+//! This scheme instead draws `n - 1` random pads, and XORs them into the secret to obtain
+//! the last share, which is much faster for large secrets.
+//!
+//! Shares carry a share-set identifier, can be signed like the ones of the `SSS` scheme,
+//! and are recognized by `inspect::inspect_share` and `armor::ArmoredShare`.
+
+use rand::{OsRng, Rng};
+
+use errors::*;
+use share_set::{self, SortedShares};
+
+mod share;
+pub use self::share::Share;
+
+mod format;
+pub(crate) use self::format::share_protobuf_from_string;
+
+mod scheme;
+pub use self::scheme::Xor;
+
+/// Performs n-of-n additive secret sharing.
+///
+/// Uses a `rand::OsRng` as a source of entropy.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_secrets::xor::split_secret;
+///
+/// let secret = "These programs were never about terrorism: they’re about economic spying, \
+///               social control, and diplomatic manipulation. They’re about power.";
+///
+/// match split_secret(3, &secret.as_bytes(), true) {
+///     Ok(shares) => {
+///         // Do something with the shares
+///     },
+///     Err(_) => {
+///         // Deal with error
+///     }
+/// }
+/// ```
+pub fn split_secret(n: u8, secret: &[u8], sign_shares: bool) -> Result<Vec<String>> {
+    split_secret_rng(&mut OsRng::new()?, n, secret, sign_shares)
+}
+
+/// Performs n-of-n additive secret sharing with a custom RNG.
+pub fn split_secret_rng<R: Rng>(
+    rng: &mut R,
+    n: u8,
+    secret: &[u8],
+    sign_shares: bool,
+) -> Result<Vec<String>> {
+    Xor::default()
+        .split_secret(rng, n, secret, sign_shares)
+        .map(|shares| shares.into_iter().map(Share::into_string).collect())
+}
+
+/// Recovers the secret from an n-of-n additive secret sharing.
+///
+/// All the `n` shares need to be provided to recover the secret.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_secrets::xor::{recover_secret, split_secret};
+///
+/// let shares = split_secret(3, b"The secret", false).unwrap();
+///
+/// assert_eq!(recover_secret(&shares, false).unwrap(), b"The secret");
+/// assert!(recover_secret(&shares[1..], false).is_err());
+/// ```
+pub fn recover_secret(shares: &[String], verify_signatures: bool) -> Result<Vec<u8>> {
+    let shares = Share::parse_all(shares, verify_signatures)?;
+    Xor::recover_secret(shares, verify_signatures)
+}
+
+/// Sorts a pile of shares coming from several splits by share set, reports
+/// how many shares each set is missing, and recovers the secret of every set
+/// for which all the shares were provided.
+///
+/// Shares which cannot be parsed are reported along with the reason why.
+pub fn sort_shares(shares: &[String], verify_signatures: bool) -> SortedShares<Vec<u8>> {
+    share_set::sort_shares(
+        shares,
+        |raw| Share::from_string(raw, verify_signatures),
+        |shares| Xor::recover_secret(shares, verify_signatures),
+    )
+}
@@ -0,0 +1,241 @@
+//! Additive n-of-n secret sharing, where the secret is the XOR of all the shares.
+
+use merkle_sigs::sign_data_vec;
+use rand::{OsRng, Rng};
+
+use errors::*;
+use hash::{HashAlgorithm, DEFAULT_SIGNATURE_HASH_ALGORITHM};
+use scheme::{SchemeKind, SecretSharingScheme};
+use share::validation::{validate_share_count, validate_signed_shares};
+use share_set::ShareSetId;
+use sss::SignaturePair;
+use xor::format::format_share_for_signing;
+use xor::Share;
+
+/// Additive n-of-n secret sharing: `n - 1` of the shares are random pads, and the last one
+/// is the secret XORed with all of them, so that all the `n` shares are necessary to recover
+/// the secret, while any fewer of them are independent of it.
+///
+/// Splitting and recovering thus cost a single pass over the secret per share, rather than
+/// the evaluation of a polynomial for every byte of the secret.
+///
+/// Shares are signed with SHA-512 by default, and the hash function on which
+/// signatures are built is recorded in the shares.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Xor {
+    /// The hash function on which the signatures of the shares are built,
+    /// which must be a fixed-length one
+    pub signature_hash_algorithm: HashAlgorithm,
+}
+
+impl Default for Xor {
+    fn default() -> Self {
+        Xor {
+            signature_hash_algorithm: DEFAULT_SIGNATURE_HASH_ALGORITHM,
+        }
+    }
+}
+
+impl Xor {
+    /// Performs n-of-n additive secret sharing.
+    pub fn split_secret<R: Rng>(
+        &self,
+        rng: &mut R,
+        shares_count: u8,
+        secret: &[u8],
+        sign_shares: bool,
+    ) -> Result<Vec<Share>> {
+        let (_, shares_count) = validate_share_count(shares_count, shares_count)?;
+        if secret.is_empty() {
+            bail!(ErrorKind::EmptySecret);
+        }
+
+        let shares = Self::secret_share(rng, secret, shares_count);
+        let share_set_id = ShareSetId::random(rng);
+
+        let signature_pairs = if sign_shares {
+            let shares_to_sign = shares
+                .iter()
+                .enumerate()
+                .map(|(i, x)| {
+                    format_share_for_signing(shares_count, (i + 1) as u8, Some(share_set_id), x)
+                })
+                .collect::<Vec<_>>();
+
+            let signature_algorithm = self.signature_hash_algorithm.signature_algorithm()?;
+            sign_data_vec(&shares_to_sign, signature_algorithm)
+                .unwrap()
+                .into_iter()
+                .map(|sig_pair| Some(SignaturePair::from(sig_pair)))
+                .collect::<Vec<_>>()
+        } else {
+            vec![None; shares_count as usize]
+        };
+
+        let result = shares
+            .into_iter()
+            .zip(signature_pairs)
+            .enumerate()
+            .map(|(index, (data, signature_pair))| Share {
+                // This is safe since we always generate less than 256 shares.
+                id: (index + 1) as u8,
+                shares_count,
+                data,
+                signature_pair,
+                share_set_id: Some(share_set_id),
+            });
+
+        Ok(result.collect())
+    }
+
+    fn secret_share<R: Rng>(rng: &mut R, src: &[u8], shares_count: u8) -> Vec<Vec<u8>> {
+        let mut result = Vec::with_capacity(shares_count as usize);
+        let mut last = src.to_vec();
+
+        for _ in 1..shares_count {
+            let mut pad = vec![0u8; src.len()];
+            rng.fill_bytes(&mut pad);
+            xor_into(&mut last, &pad);
+            result.push(pad);
+        }
+
+        result.push(last);
+        result
+    }
+
+    /// Recovers the secret from an n-of-n additive secret sharing.
+    ///
+    /// All the `n` shares need to be provided to recover the secret.
+    pub fn recover_secret(shares: Vec<Share>, verify_signatures: bool) -> Result<Vec<u8>> {
+        let (shares_count, slen) = validate_signed_shares(&shares, verify_signatures)?;
+
+        // Identifiers are distinct, so checking that none exceeds the number of shares
+        // ensures that none of them is missing.
+        if let Some(share) = shares.iter().find(|share| share.id > shares_count) {
+            bail!(ErrorKind::ShareParsingInvalidShareId(share.id));
+        }
+
+        let mut secret = vec![0u8; slen];
+        for share in &shares {
+            xor_into(&mut secret, &share.data);
+        }
+
+        Ok(secret)
+    }
+}
+
+impl SecretSharingScheme for Xor {
+    type Share = Share;
+
+    fn kind(&self) -> SchemeKind {
+        SchemeKind::Xor
+    }
+
+    /// Splits the secret, using a `rand::OsRng` as a source of entropy.
+    ///
+    /// Fails with `InvalidXorThreshold` unless the threshold `k` is the number of shares `n`.
+    fn split(&self, k: u8, n: u8, secret: &[u8], sign_shares: bool) -> Result<Vec<Share>> {
+        validate_share_count(k, n)?;
+        if k != n {
+            bail!(ErrorKind::InvalidXorThreshold(k, n));
+        }
+
+        self.split_secret(&mut OsRng::new()?, n, secret, sign_shares)
+    }
+
+    fn recover(&self, shares: &[Share], verify_signatures: bool) -> Result<Vec<u8>> {
+        Self::recover_secret(shares.to_vec(), verify_signatures)
+    }
+
+    fn parse_share(&self, raw: &str) -> Result<Share> {
+        Share::from_string(raw, true)
+    }
+
+    fn format_share(&self, share: &Share) -> String {
+        share.clone().into_string()
+    }
+}
+
+/// XORs `src` into `dst`, which have the same length.
+fn xor_into(dst: &mut [u8], src: &[u8]) {
+    for (d, &s) in dst.iter_mut().zip(src) {
+        *d ^= s;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use rand::{ChaChaRng, SeedableRng};
+
+    use super::*;
+    use sss;
+
+    #[test]
+    fn all_shares_recover_the_secret() {
+        let mut rng = ChaChaRng::from_seed(&[1, 2, 3]);
+        let mut shares = Xor::default()
+            .split_secret(&mut rng, 4, b"Hello, World!", false)
+            .unwrap();
+        assert_eq!(shares.len(), 4);
+        assert!(shares.iter().all(|share| share.shares_count == 4));
+
+        shares.reverse();
+        assert_eq!(Xor::recover_secret(shares.clone(), false).unwrap(), b"Hello, World!");
+
+        shares.pop();
+        let error = Xor::recover_secret(shares, false).unwrap_err();
+        assert_eq!(error.kind().error_code(), ErrorCode::MissingShares);
+    }
+
+    #[test]
+    fn signed_shares_are_verified() {
+        let mut rng = ChaChaRng::from_seed(&[4, 5, 6]);
+        let mut shares = Xor::default()
+            .split_secret(&mut rng, 3, b"Hello, World!", true)
+            .unwrap();
+        assert_eq!(Xor::recover_secret(shares.clone(), true).unwrap(), b"Hello, World!");
+
+        shares[1].data[0] ^= 1;
+        let error = Xor::recover_secret(shares, true).unwrap_err();
+        assert_eq!(error.kind().error_code(), ErrorCode::InvalidSignature);
+    }
+
+    #[test]
+    fn invalid_parameters_are_rejected() {
+        let mut rng = ChaChaRng::from_seed(&[7, 8, 9]);
+        let code = |n: u8, secret: &[u8], rng: &mut ChaChaRng| {
+            Xor::default()
+                .split_secret(rng, n, secret, false)
+                .unwrap_err()
+                .kind()
+                .error_code()
+        };
+        assert_eq!(code(1, b"Hello", &mut rng), ErrorCode::ThresholdTooSmall);
+        assert_eq!(code(3, b"", &mut rng), ErrorCode::EmptySecret);
+
+        let error = Xor::default().split(2, 3, b"Hello", false).unwrap_err();
+        assert_eq!(error.kind().error_code(), ErrorCode::InvalidXorThreshold);
+    }
+
+    #[test]
+    fn shares_roundtrip_through_strings() {
+        let mut rng = ChaChaRng::from_seed(&[10, 11, 12]);
+        let shares = Xor::default()
+            .split_secret(&mut rng, 3, b"Hello, World!", true)
+            .unwrap();
+
+        let raw = shares
+            .into_iter()
+            .map(Share::into_string)
+            .collect::<Vec<_>>();
+        let parsed = Share::parse_all(&raw, true).unwrap();
+        assert!(parsed.iter().all(|share| share.is_signed()));
+        assert_eq!(Xor::recover_secret(parsed, true).unwrap(), b"Hello, World!");
+
+        // Shares of the `SSS` scheme are not mistaken for `Xor` shares.
+        let sss_shares = sss::split_secret(3, 3, b"Hello, World!", false).unwrap();
+        assert!(Share::from_string(&sss_shares[0], false).is_err());
+        assert!(Share::from_string("3-4-ggEIPpEH1FrCGWs", false).is_err());
+    }
+}
@@ -0,0 +1,122 @@
+use errors::*;
+use share::{IsShare, IsSignedShare};
+use share_set::ShareSetId;
+use sss::{verify_signature_pairs, SignaturePair};
+use xor::format::{format_share_for_signing, share_from_string, share_to_string};
+
+/// A share identified by an `id`, the number of shares `n` that have been dealt,
+/// all of which are necessary to recover the secret, and the `data` held in the share.
+#[derive(Clone, Debug)]
+pub struct Share {
+    /// The identifier of the share (varies between 1 and n where n is the total number of generated shares)
+    pub id: u8,
+    /// The total number of shares that have been dealt, all of which are necessary
+    /// to recover the secret
+    pub shares_count: u8,
+    /// The share data itself
+    pub data: Vec<u8>,
+    /// If the share is signed, this fields holds the signature
+    /// along with the proof of inclusion into the underlying MerkleTree.
+    pub signature_pair: Option<SignaturePair>,
+    /// The identifier of the share set this share belongs to, if any.
+    pub share_set_id: Option<ShareSetId>,
+}
+
+impl Share {
+    /// Attempts to parse the given string into a share.
+    /// The string `raw` should follow the format of `Share::into_string`.
+    /// The signature of the share is only parsed if `is_signed` is set.
+    pub fn from_string(raw: &str, is_signed: bool) -> Result<Self> {
+        share_from_string(raw, is_signed)
+    }
+
+    /// Attempts to parse all the given strings into shares.
+    /// Calls out to `Share::from_string`.
+    pub(crate) fn parse_all(raws: &[String], is_signed: bool) -> Result<Vec<Share>> {
+        raws.into_iter()
+            .map(|raw| Self::from_string(raw, is_signed))
+            .collect()
+    }
+
+    /// Format the share as a string suitable for being stored in a file.
+    /// The format is the following:
+    ///
+    /// ```text
+    /// 3-1-ggEIPpEH1FrCGWs
+    /// ^ ^ ^^^^^^^^^^^^^^^
+    /// N I        D
+    ///
+    /// It is built out of three parts separated with a dash: N-I-D.
+    ///
+    /// - N specifies the number of shares that have been dealt, all of which
+    ///   are necessary to recover the secret.
+    /// - I is the identifier of the share and varies between 1 and N.
+    /// - D is a Base64 encoding of a ShareProto protobuf containing
+    ///   the share data, its share-set identifier, and if signed, the signature.
+    /// ```
+    ///
+    /// This is the `K-N-D` format of the other schemes, with a threshold of `N`, so that
+    /// shares can be armored as usual. The fields of the protobuf are numbered apart from
+    /// those of the other schemes, so that its shares are never mistaken for theirs.
+    pub fn into_string(self) -> String {
+        share_to_string(
+            self.data,
+            self.shares_count,
+            self.id,
+            self.share_set_id,
+            self.signature_pair,
+        )
+    }
+
+    /// Returns whether this share is signed.
+    pub fn is_signed(&self) -> bool {
+        self.signature_pair.is_some()
+    }
+
+    /// Returns the message signed by the dealer.
+    pub(crate) fn format_for_signing(&self) -> Vec<u8> {
+        format_share_for_signing(self.shares_count, self.id, self.share_set_id, &self.data)
+    }
+}
+
+impl IsShare for Share {
+    fn get_id(&self) -> u8 {
+        self.id
+    }
+
+    fn get_data(&self) -> &[u8] {
+        &self.data
+    }
+
+    fn get_threshold(&self) -> u8 {
+        self.shares_count
+    }
+
+    fn get_shares_count(&self) -> Option<u8> {
+        Some(self.shares_count)
+    }
+
+    fn get_share_set_id(&self) -> Option<ShareSetId> {
+        self.share_set_id
+    }
+}
+
+impl IsSignedShare for Share {
+    type Signature = Option<SignaturePair>;
+
+    fn is_signed(&self) -> bool {
+        self.signature_pair.is_some()
+    }
+
+    fn get_signature(&self) -> &Self::Signature {
+        &self.signature_pair
+    }
+
+    fn verify_signatures(shares: &[Self]) -> Result<()> {
+        let messages = shares
+            .iter()
+            .map(|share| (share.id, share.format_for_signing(), share.signature_pair.as_ref()))
+            .collect::<Vec<_>>();
+        verify_signature_pairs(&messages)
+    }
+}
@@ -0,0 +1,173 @@
+#![cfg(feature = "dss")]
+
+extern crate ring;
+extern crate rusty_secrets;
+
+use std::cell::Cell;
+
+use ring::error::Unspecified;
+use ring::rand::SecureRandom;
+use rusty_secrets::dss::ss1::{self, Reproducibility, SS1};
+use rusty_secrets::dss::thss::{self, ThSS};
+use rusty_secrets::hash::HashAlgorithm;
+
+const TEST_SECRET: &[u8] =
+    b"These programs were never about terrorism: they're about economic spying, \
+      social control, and diplomatic manipulation. They're about power.";
+
+/// A predictable randomness source, only suitable for tests.
+struct CounterRandom {
+    counter: Cell<u8>,
+}
+
+impl CounterRandom {
+    fn new() -> Self {
+        CounterRandom {
+            counter: Cell::new(0),
+        }
+    }
+}
+
+impl SecureRandom for CounterRandom {
+    fn fill(&self, dest: &mut [u8]) -> Result<(), Unspecified> {
+        for byte in dest.iter_mut() {
+            *byte = self.counter.get();
+            self.counter.set(byte.wrapping_add(1));
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_thss_with_custom_random() {
+    let scheme = ThSS::builder().random(Box::new(CounterRandom::new())).build();
+    let other = ThSS::builder().random(Box::new(CounterRandom::new())).build();
+
+    let shares = scheme.split_secret(3, 5, TEST_SECRET, &None).unwrap();
+    assert_eq!(shares, other.split_secret(3, 5, TEST_SECRET, &None).unwrap());
+
+    let (secret, access_structure, _) = scheme.recover_secret(&shares[1..4]).unwrap();
+    assert_eq!(secret, TEST_SECRET);
+    assert_eq!(access_structure.threshold, 3);
+}
+
+#[test]
+fn test_ss1_with_custom_random() {
+    let scheme = SS1::builder().random(Box::new(CounterRandom::new())).build().unwrap();
+    let other = SS1::builder().random(Box::new(CounterRandom::new())).build().unwrap();
+
+    let shares = scheme
+        .split_secret(2, 3, TEST_SECRET, Reproducibility::none(), &None)
+        .unwrap();
+    let other_shares = other
+        .split_secret(2, 3, TEST_SECRET, Reproducibility::none(), &None)
+        .unwrap();
+    assert_eq!(shares, other_shares);
+
+    let (secret, _, _) = ss1::recover_secret(&shares[..2]).unwrap();
+    assert_eq!(secret, TEST_SECRET);
+}
+
+#[test]
+fn test_ss1_with_custom_parameters() {
+    let scheme = SS1::builder()
+        .random_padding_len(ss1::MIN_RANDOM_PADDING_LEN)
+        .hash_len(1024)
+        .build()
+        .unwrap();
+    assert_eq!(scheme.random_padding_len(), ss1::MIN_RANDOM_PADDING_LEN);
+    assert_eq!(scheme.hash_len(), 1024);
+
+    let shares = scheme
+        .split_secret(2, 3, TEST_SECRET, Reproducibility::reproducible(), &None)
+        .unwrap();
+    let (secret, _, _) = scheme.recover_secret(&shares[1..]).unwrap();
+    assert_eq!(secret, TEST_SECRET);
+}
+
+#[test]
+#[should_panic(expected = "InvalidSS1Parameters")]
+fn test_ss1_random_padding_too_short() {
+    SS1::builder()
+        .random_padding_len(ss1::MIN_RANDOM_PADDING_LEN - 1)
+        .build()
+        .unwrap();
+}
+
+#[test]
+#[should_panic(expected = "InvalidSS1Parameters")]
+fn test_ss1_hash_too_short() {
+    SS1::new(ss1::DEFAULT_RANDOM_PADDING_LEN, ss1::MIN_HASH_LEN - 1).unwrap();
+}
+
+#[test]
+fn test_ss1_with_selectable_hashes() {
+    let algorithms = [
+        HashAlgorithm::SHA256,
+        HashAlgorithm::SHA384,
+        HashAlgorithm::SHA512,
+        HashAlgorithm::SHAKE256,
+    ];
+
+    for &algorithm in &algorithms {
+        let scheme = SS1::builder().hash_algorithm(algorithm).build().unwrap();
+
+        let shares = scheme
+            .split_secret(2, 3, TEST_SECRET, Reproducibility::reproducible(), &None)
+            .unwrap()
+            .into_iter()
+            .map(|share| ss1::Share::from_string(&share.into_string()).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(shares[0].parameters.hash_algorithm, algorithm);
+
+        // Recovery uses the algorithm recorded in the shares.
+        let (secret, _, _) = SS1::default().recover_secret(&shares[1..]).unwrap();
+        assert_eq!(secret, TEST_SECRET);
+    }
+}
+
+#[test]
+fn test_dss_signature_hash_algorithm() {
+    let scheme = ThSS::builder()
+        .sign_shares(true)
+        .signature_hash_algorithm(HashAlgorithm::SHA256)
+        .build();
+    let shares = scheme
+        .split_secret(2, 3, TEST_SECRET, &None)
+        .unwrap()
+        .into_iter()
+        .map(|share| thss::Share::from_string(&share.into_string()).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        shares[0].signature.as_ref().unwrap().hash_algorithm,
+        HashAlgorithm::SHA256
+    );
+
+    let verifier = ThSS::builder().verify_signatures(true).build();
+    let (secret, _, _) = verifier.recover_secret(&shares[..2]).unwrap();
+    assert_eq!(secret, TEST_SECRET);
+
+    let scheme = SS1::builder()
+        .hash_algorithm(HashAlgorithm::SHAKE256)
+        .sign_shares(true)
+        .signature_hash_algorithm(HashAlgorithm::SHA384)
+        .build()
+        .unwrap();
+    let shares = scheme
+        .split_secret(2, 3, TEST_SECRET, Reproducibility::none(), &None)
+        .unwrap();
+    let verifier = SS1::builder().verify_signatures(true).build().unwrap();
+    let (secret, _, _) = verifier.recover_secret(&shares[1..]).unwrap();
+    assert_eq!(secret, TEST_SECRET);
+}
+
+#[test]
+#[should_panic(expected = "UnsupportedHashAlgorithm")]
+fn test_dss_signature_with_xof() {
+    ThSS::builder()
+        .sign_shares(true)
+        .signature_hash_algorithm(HashAlgorithm::SHAKE256)
+        .build()
+        .split_secret(2, 3, TEST_SECRET, &None)
+        .unwrap();
+}
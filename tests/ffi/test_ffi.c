@@ -0,0 +1,194 @@
+/*
+ * Exercises the C bindings, built with `cargo build -p rusty-secrets-ffi`.
+ * Run it with `make ffi-test` from the root of the repository.
+ */
+
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "rusty_secrets.h"
+
+static int failures = 0;
+
+#define CHECK(cond)                                                        \
+    do {                                                                   \
+        if (!(cond)) {                                                     \
+            fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__,         \
+                    __LINE__, #cond);                                      \
+            failures++;                                                    \
+        }                                                                  \
+    } while (0)
+
+#define CHECK_CODE(call, expected)                                         \
+    do {                                                                   \
+        int32_t code_ = (call);                                            \
+        if (code_ != (expected)) {                                         \
+            char *error_ = rusty_secrets_last_error();                     \
+            fprintf(stderr, "%s:%d: %s returned %d, expected %d (%s)\n",   \
+                    __FILE__, __LINE__, #call, code_, (expected),          \
+                    error_ ? error_ : "no error");                         \
+            rusty_secrets_string_free(error_);                             \
+            failures++;                                                    \
+        }                                                                  \
+    } while (0)
+
+static const char SECRET[] = "These programs were never about terrorism.";
+
+static int secret_matches(const RustySecretsBuffer *buffer) {
+    return buffer->len == strlen(SECRET) &&
+           memcmp(buffer->data, SECRET, buffer->len) == 0;
+}
+
+static void test_sss(void) {
+    RustySecretsShares shares = { NULL, 0 };
+    RustySecretsBuffer secret = { NULL, 0 };
+    RustySecretsShareInfo info;
+
+    CHECK_CODE(rusty_secrets_sss_split(3, 5, (const uint8_t *)SECRET,
+                                       strlen(SECRET), true, &shares),
+               RUSTY_SECRETS_OK);
+    CHECK(shares.count == 5);
+
+    CHECK_CODE(rusty_secrets_sss_inspect(shares.shares[1], &info), RUSTY_SECRETS_OK);
+    CHECK(info.id == 2);
+    CHECK(info.threshold == 3);
+    CHECK(info.is_signed);
+    CHECK(info.has_share_set_id);
+
+    CHECK_CODE(rusty_secrets_sss_recover((const char *const *)shares.shares + 2, 3,
+                                         true, &secret),
+               RUSTY_SECRETS_OK);
+    CHECK(secret_matches(&secret));
+    rusty_secrets_buffer_free(&secret);
+    CHECK(secret.data == NULL && secret.len == 0);
+
+    /* Two shares are not enough. */
+    CHECK_CODE(rusty_secrets_sss_recover((const char *const *)shares.shares, 2,
+                                         true, &secret),
+               RUSTY_SECRETS_ERROR_MISSING_SHARES);
+    char *error = rusty_secrets_last_error();
+    CHECK(error != NULL);
+    rusty_secrets_string_free(error);
+
+    /* Tamper with the share data, which follows the protobuf field header. */
+    char *tampered = strdup(shares.shares[0]);
+    char *data = strrchr(tampered, '-') + 4;
+    *data = *data == 'A' ? 'B' : 'A';
+    const char *with_tampered[] = { tampered, shares.shares[1], shares.shares[2] };
+    CHECK_CODE(rusty_secrets_sss_recover(with_tampered, 3, true, &secret),
+               RUSTY_SECRETS_ERROR_INVALID_SIGNATURE);
+    free(tampered);
+
+    CHECK_CODE(rusty_secrets_sss_recover(NULL, 3, true, &secret),
+               RUSTY_SECRETS_ERROR_NULL_POINTER);
+
+    rusty_secrets_shares_free(&shares);
+    CHECK(shares.shares == NULL && shares.count == 0);
+
+    CHECK_CODE(rusty_secrets_sss_split(6, 5, (const uint8_t *)SECRET,
+                                       strlen(SECRET), false, &shares),
+               RUSTY_SECRETS_ERROR_THRESHOLD_TOO_BIG);
+}
+
+static void test_wrapped(void) {
+    RustySecretsShares shares = { NULL, 0 };
+    RustySecretsBuffer secret = { NULL, 0 };
+    RustySecretsShareInfo info;
+    char *mime_type = NULL;
+
+    CHECK_CODE(rusty_secrets_wrapped_split(2, 3, (const uint8_t *)SECRET,
+                                           strlen(SECRET), "text/plain", false,
+                                           &shares),
+               RUSTY_SECRETS_OK);
+    CHECK(shares.count == 3);
+
+    CHECK_CODE(rusty_secrets_wrapped_inspect(shares.shares[2], &info), RUSTY_SECRETS_OK);
+    CHECK(info.id == 3);
+    CHECK(!info.is_signed);
+
+    CHECK_CODE(rusty_secrets_wrapped_recover((const char *const *)shares.shares + 1, 2,
+                                             false, &secret, &mime_type),
+               RUSTY_SECRETS_OK);
+    CHECK(secret_matches(&secret));
+    CHECK(mime_type != NULL && strcmp(mime_type, "text/plain") == 0);
+    rusty_secrets_string_free(mime_type);
+    rusty_secrets_buffer_free(&secret);
+
+    CHECK_CODE(rusty_secrets_wrapped_recover((const char *const *)shares.shares, 1,
+                                             false, &secret, NULL),
+               RUSTY_SECRETS_ERROR_MISSING_SHARES);
+
+    /* Unsigned shares cannot be verified. */
+    CHECK_CODE(rusty_secrets_wrapped_recover((const char *const *)shares.shares, 2,
+                                             true, &secret, NULL),
+               RUSTY_SECRETS_ERROR_MISSING_SIGNATURE);
+
+    rusty_secrets_shares_free(&shares);
+}
+
+static void test_ss1(void) {
+    RustySecretsShares shares = { NULL, 0 };
+    RustySecretsShares again = { NULL, 0 };
+    RustySecretsBuffer secret = { NULL, 0 };
+    RustySecretsShareInfo info;
+
+    CHECK_CODE(rusty_secrets_ss1_split(3, 4, (const uint8_t *)SECRET, strlen(SECRET),
+                                       true, false, &shares),
+               RUSTY_SECRETS_OK);
+    CHECK_CODE(rusty_secrets_ss1_split(3, 4, (const uint8_t *)SECRET, strlen(SECRET),
+                                       true, false, &again),
+               RUSTY_SECRETS_OK);
+    CHECK(shares.count == 4 && again.count == 4);
+    CHECK(strcmp(shares.shares[0], again.shares[0]) == 0);
+
+    CHECK_CODE(rusty_secrets_ss1_inspect(shares.shares[3], &info), RUSTY_SECRETS_OK);
+    CHECK(info.id == 4);
+    CHECK(info.threshold == 3);
+    CHECK(info.shares_count == 4);
+    CHECK(!info.is_signed);
+
+    CHECK_CODE(rusty_secrets_ss1_recover((const char *const *)shares.shares, 3, false, &secret),
+               RUSTY_SECRETS_OK);
+    CHECK(secret_matches(&secret));
+    rusty_secrets_buffer_free(&secret);
+
+    CHECK_CODE(rusty_secrets_ss1_recover((const char *const *)shares.shares, 2, false, &secret),
+               RUSTY_SECRETS_ERROR_MISSING_SHARES);
+
+    /* Unsigned shares cannot be verified. */
+    CHECK_CODE(rusty_secrets_ss1_recover((const char *const *)shares.shares, 3, true, &secret),
+               RUSTY_SECRETS_ERROR_MISSING_SIGNATURE);
+
+    CHECK_CODE(rusty_secrets_ss1_inspect("not a share", &info),
+               RUSTY_SECRETS_ERROR_SHARE_PARSING);
+
+    rusty_secrets_shares_free(&shares);
+    rusty_secrets_shares_free(&again);
+
+    CHECK_CODE(rusty_secrets_ss1_split(2, 3, (const uint8_t *)SECRET, strlen(SECRET),
+                                       false, true, &shares),
+               RUSTY_SECRETS_OK);
+    CHECK_CODE(rusty_secrets_ss1_inspect(shares.shares[0], &info), RUSTY_SECRETS_OK);
+    CHECK(info.is_signed);
+    CHECK_CODE(rusty_secrets_ss1_recover((const char *const *)shares.shares + 1, 2, true,
+                                         &secret),
+               RUSTY_SECRETS_OK);
+    CHECK(secret_matches(&secret));
+    rusty_secrets_buffer_free(&secret);
+    rusty_secrets_shares_free(&shares);
+}
+
+int main(void) {
+    test_sss();
+    test_wrapped();
+    test_ss1();
+
+    if (failures > 0) {
+        fprintf(stderr, "%d check(s) failed\n", failures);
+        return EXIT_FAILURE;
+    }
+
+    printf("All checks passed\n");
+    return EXIT_SUCCESS;
+}
@@ -0,0 +1,174 @@
+extern crate base64;
+extern crate protobuf;
+extern crate rusty_secrets;
+
+use protobuf::Message;
+
+use rusty_secrets::errors::ErrorCode;
+use rusty_secrets::passphrase::{encrypt_share, recover_secret, EncryptedShare, KeyDerivation};
+use rusty_secrets::proto::wrapped::ShareProto;
+use rusty_secrets::scheme::{SchemeKind, SecretSharingScheme};
+use rusty_secrets::sss;
+use rusty_secrets::wrapped_secrets;
+
+fn key_derivation() -> KeyDerivation {
+    KeyDerivation::new(32, b"some salt, salty".to_vec()).unwrap()
+}
+
+#[test]
+fn test_recover_with_encrypted_shares() {
+    let sss = sss::SSS::default();
+    let shares = sss::split_secret(3, 5, b"Hello, World!", true).unwrap();
+
+    let first = encrypt_share(shares[0].as_str(), "alice", &key_derivation())
+        .unwrap()
+        .into_string();
+    let third = encrypt_share(shares[2].as_str(), "carol", &key_derivation())
+        .unwrap()
+        .into_string();
+    assert!(EncryptedShare::from_string(&first).is_ok());
+
+    let secret = recover_secret(
+        &sss,
+        &[
+            (first.as_str(), Some("alice")),
+            (shares[1].as_str(), None),
+            (third.as_str(), Some("carol")),
+        ],
+        true,
+    ).unwrap();
+    assert_eq!(secret, b"Hello, World!");
+}
+
+#[test]
+fn test_recover_with_every_scheme() {
+    for kind in &["sss", "wrapped_secrets", "thss", "ss1"] {
+        let kind = match kind.parse::<SchemeKind>() {
+            Ok(kind) => kind,
+            Err(_) => continue,
+        };
+
+        let shares = kind.split(2, 3, b"Hello, World!", false).unwrap();
+        let encrypted = encrypt_share(shares[1].as_str(), "bob", &key_derivation())
+            .unwrap()
+            .into_string();
+
+        let shares = [(encrypted.as_str(), Some("bob")), (shares[2].as_str(), None)];
+        assert_eq!(recover_secret(&kind, &shares, false).unwrap(), b"Hello, World!");
+    }
+}
+
+#[test]
+#[should_panic(expected = "WrongPassphrase")]
+fn test_recover_with_wrong_passphrase() {
+    let sss = sss::SSS::default();
+    let shares = sss::split_secret(2, 3, b"Hello, World!", false).unwrap();
+    let encrypted = encrypt_share(shares[0].as_str(), "alice", &key_derivation())
+        .unwrap()
+        .into_string();
+
+    let shares = [(encrypted.as_str(), Some("mallory")), (shares[1].as_str(), None)];
+    recover_secret(&sss, &shares, false).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "MissingPassphrase")]
+fn test_recover_without_passphrase() {
+    let sss = sss::SSS::default();
+    let shares = sss::split_secret(2, 3, b"Hello, World!", false).unwrap();
+    let encrypted = encrypt_share(shares[0].as_str(), "alice", &key_derivation())
+        .unwrap()
+        .into_string();
+
+    let shares = [(encrypted.as_str(), None), (shares[1].as_str(), None)];
+    recover_secret(&sss, &shares, false).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "InvalidKey")]
+fn test_key_derivation_without_iterations() {
+    KeyDerivation::new(0, b"some salt".to_vec()).unwrap();
+}
+
+#[test]
+fn test_recover_with_recovery_passphrase() {
+    let shares = sss::split_secret_with_passphrase(3, 5, b"Hello, World!", "team", true).unwrap();
+
+    let secret = sss::recover_secret_with_passphrase(&shares[2..], "team", true).unwrap();
+    assert_eq!(secret, b"Hello, World!");
+
+    // The shares are flagged as protected, so they cannot be recovered without it.
+    let error = sss::recover_secret(&shares[..3], true).unwrap_err();
+    assert_eq!(error.kind().error_code(), ErrorCode::MissingPassphrase);
+}
+
+#[test]
+fn test_recover_wrapped_with_recovery_passphrase() {
+    let shares = wrapped_secrets::split_secret_with_passphrase(
+        2,
+        3,
+        b"Hello, World!",
+        Some("text/plain".to_string()),
+        "team",
+        false,
+    ).unwrap();
+
+    let secret =
+        wrapped_secrets::recover_secret_with_passphrase(&shares[..2], "team", false).unwrap();
+    assert_eq!(secret.get_secret(), b"Hello, World!");
+    assert_eq!(secret.get_mime_type(), "text/plain");
+
+    let error = wrapped_secrets::recover_secret(&shares[..2], false).unwrap_err();
+    assert_eq!(error.kind().error_code(), ErrorCode::MissingPassphrase);
+}
+
+#[test]
+#[should_panic(expected = "WrongPassphrase")]
+fn test_recover_with_wrong_recovery_passphrase() {
+    let shares = sss::split_secret_with_passphrase(2, 3, b"Hello, World!", "team", false).unwrap();
+
+    sss::recover_secret_with_passphrase(&shares[..2], "mallory", false).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "WrongPassphrase")]
+fn test_recover_wrapped_with_wrong_recovery_passphrase() {
+    let shares =
+        wrapped_secrets::split_secret_with_passphrase(2, 3, b"Hello, World!", None, "team", false)
+            .unwrap();
+
+    wrapped_secrets::recover_secret_with_passphrase(&shares[..2], "mallory", false).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "SecretDeserializationError")]
+fn test_recover_unprotected_secret_with_recovery_passphrase() {
+    let shares = sss::split_secret(2, 3, b"Hello, World!", false).unwrap();
+
+    sss::recover_secret_with_passphrase(&shares[..2], "team", false).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "InvalidSignature")]
+fn test_recover_signed_shares_stripped_of_protection() {
+    let shares = sss::split_secret_with_passphrase(2, 3, b"Hello, World!", "team", true).unwrap();
+
+    let stripped = shares
+        .iter()
+        .map(|share| {
+            let parts = share.split('-').collect::<Vec<_>>();
+            let data = base64::decode_config(parts[2], base64::STANDARD_NO_PAD).unwrap();
+            let mut proto = protobuf::parse_from_bytes::<ShareProto>(&data).unwrap();
+            proto.set_passphrase_protected(false);
+            let data = proto.write_to_bytes().unwrap();
+            format!(
+                "{}-{}-{}",
+                parts[0],
+                parts[1],
+                base64::encode_config(&data, base64::STANDARD_NO_PAD)
+            )
+        })
+        .collect::<Vec<_>>();
+
+    sss::recover_secret(&stripped[..2], true).unwrap();
+}
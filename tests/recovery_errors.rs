@@ -58,6 +58,14 @@ fn test_recover_invalid_b64() {
     recover_secret(&shares, false).unwrap();
 }
 
+#[test]
+#[should_panic(expected = "InvalidSignature")]
+fn test_recover_invalid_proof() {
+    // The proof of inclusion of this share cannot be decoded.
+    let shares = vec!["2-1-CgEBGgL//w".to_string()];
+    recover_secret(&shares, true).unwrap();
+}
+
 #[test]
 #[should_panic(expected = "DuplicateShareId")]
 fn test_recover_duplicate_shares_number() {
@@ -134,3 +142,15 @@ fn test_recover_too_few_shares_bug() {
         Ok(recovered) => assert_ne!(original, recovered),
     }
 }
+
+#[test]
+#[should_panic(expected = "InconsistentShareSets")]
+fn test_recover_shares_from_different_sets() {
+    let secret = "The immoral cannot be made moral through the use of secret law.".to_string();
+    let shares_1 = split_secret(2, 3, &secret.as_bytes(), false).unwrap();
+    let shares_2 = split_secret(2, 3, &secret.as_bytes(), false).unwrap();
+
+    let shares = vec![shares_1[0].clone(), shares_2[1].clone()];
+
+    recover_secret(&shares, false).unwrap();
+}
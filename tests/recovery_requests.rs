@@ -0,0 +1,70 @@
+extern crate rusty_secrets;
+
+use rusty_secrets::scheme::{SchemeKind, SecretSharingScheme};
+use rusty_secrets::sealing::request::{respond, Recoverer, RecoveryRequest, RecoveryResponse};
+use rusty_secrets::sss;
+
+#[test]
+fn test_recover_from_responses() {
+    let shares = sss::split_secret(3, 5, b"Hello, World!", true).unwrap();
+
+    let mut recoverer = Recoverer::new().unwrap();
+    let request = RecoveryRequest::from_string(&recoverer.request().clone().into_string()).unwrap();
+
+    // The responses travel as strings, over untrusted channels.
+    let responses = shares[1..4]
+        .iter()
+        .map(|share| respond(&request, share).unwrap().into_string())
+        .map(|raw| RecoveryResponse::from_string(&raw).unwrap())
+        .collect::<Vec<_>>();
+
+    let secret = recoverer
+        .recover_secret(&SchemeKind::SSS, &responses, true)
+        .unwrap();
+    assert_eq!(secret, b"Hello, World!");
+}
+
+#[test]
+fn test_failed_recovery_does_not_consume_responses() {
+    let shares = sss::split_secret(2, 3, b"Hello, World!", false).unwrap();
+
+    let mut recoverer = Recoverer::new().unwrap();
+    let mut responses = vec![
+        respond(recoverer.request(), &shares[0]).unwrap(),
+        respond(recoverer.request(), &shares[1]).unwrap(),
+    ];
+    responses[1].ciphertext[0] ^= 1;
+    assert!(recoverer.recover_secret(&SchemeKind::SSS, &responses, false).is_err());
+
+    responses[1] = respond(recoverer.request(), &shares[2]).unwrap();
+    let secret = recoverer
+        .recover_secret(&SchemeKind::SSS, &responses, false)
+        .unwrap();
+    assert_eq!(secret, b"Hello, World!");
+}
+
+#[test]
+#[should_panic(expected = "ReplayedResponse")]
+fn test_replayed_response() {
+    let shares = sss::split_secret(2, 3, b"Hello, World!", false).unwrap();
+
+    let mut recoverer = Recoverer::new().unwrap();
+    let response = respond(recoverer.request(), &shares[0]).unwrap();
+    let responses = vec![response.clone(), response];
+
+    recoverer
+        .recover_secret(&SchemeKind::SSS, &responses, false)
+        .unwrap();
+}
+
+#[test]
+#[should_panic(expected = "WrongRequest")]
+fn test_response_to_another_request() {
+    let shares = sss::split_secret(2, 3, b"Hello, World!", false).unwrap();
+
+    let previous = Recoverer::new().unwrap();
+    let mut recoverer = Recoverer::new().unwrap();
+    let response = respond(previous.request(), &shares[0]).unwrap();
+
+    recoverer.open_response(&response).unwrap();
+}
@@ -0,0 +1,91 @@
+extern crate rusty_secrets;
+
+use rusty_secrets::hash::HashAlgorithm;
+use rusty_secrets::scheme::{SchemeKind, SecretSharingScheme};
+use rusty_secrets::sss::SSS;
+use rusty_secrets::wrapped_secrets::{self, WrappedSecrets};
+
+const TEST_SECRET: &[u8] =
+    b"These programs were never about terrorism: they're about economic spying, \
+      social control, and diplomatic manipulation. They're about power.";
+
+fn split_then_recover<S: SecretSharingScheme>(scheme: &S, kind: SchemeKind) {
+    assert_eq!(scheme.kind(), kind);
+
+    let raws = scheme
+        .split(3, 5, TEST_SECRET, true)
+        .unwrap()
+        .iter()
+        .map(|share| scheme.format_share(share))
+        .collect::<Vec<_>>();
+    let shares = raws.iter()
+        .skip(2)
+        .map(|raw| scheme.parse_share(raw).unwrap())
+        .collect::<Vec<_>>();
+
+    assert_eq!(scheme.recover(&shares, true).unwrap(), TEST_SECRET);
+    assert_eq!(kind.recover(&raws[..3], true).unwrap(), TEST_SECRET);
+}
+
+#[test]
+fn test_sss_scheme() {
+    split_then_recover(&SSS::default(), SchemeKind::SSS);
+}
+
+#[test]
+fn test_sss_signature_hash_algorithms() {
+    for &algorithm in &[HashAlgorithm::SHA256, HashAlgorithm::SHA384] {
+        let scheme = SSS {
+            signature_hash_algorithm: algorithm,
+        };
+        split_then_recover(&scheme, SchemeKind::SSS);
+    }
+}
+
+#[test]
+#[should_panic(expected = "UnsupportedHashAlgorithm")]
+fn test_sss_signature_with_xof() {
+    let scheme = SSS {
+        signature_hash_algorithm: HashAlgorithm::SHAKE256,
+    };
+    scheme.split(2, 3, TEST_SECRET, true).unwrap();
+}
+
+#[test]
+fn test_wrapped_secrets_scheme() {
+    let scheme = WrappedSecrets {
+        mime_type: Some("text/plain".to_string()),
+    };
+    split_then_recover(&scheme, SchemeKind::WrappedSecrets);
+
+    let raws = scheme
+        .split(2, 3, TEST_SECRET, false)
+        .unwrap()
+        .into_iter()
+        .map(|share| share.into_string())
+        .collect::<Vec<_>>();
+    let secret = wrapped_secrets::recover_secret(&raws, false).unwrap();
+    assert_eq!(secret.get_mime_type(), "text/plain");
+}
+
+#[test]
+#[cfg(feature = "dss")]
+fn test_dss_schemes() {
+    use rusty_secrets::dss::ss1::SS1;
+    use rusty_secrets::dss::thss::ThSS;
+
+    split_then_recover(&ThSS::default(), SchemeKind::ThSS);
+    split_then_recover(&SS1::default(), SchemeKind::SS1);
+}
+
+#[test]
+#[should_panic(expected = "ShareParsingError")]
+fn test_scheme_kind_rejects_invalid_share() {
+    SchemeKind::SSS.parse_share("2-1").unwrap();
+}
+
+#[test]
+#[should_panic(expected = "UnknownScheme")]
+fn test_unknown_scheme_kind() {
+    "shamir".parse::<SchemeKind>().unwrap();
+}
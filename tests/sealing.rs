@@ -0,0 +1,85 @@
+extern crate rusty_secrets;
+
+use rusty_secrets::scheme::{SchemeKind, SecretSharingScheme};
+use rusty_secrets::sealing::{open_share, split_sealed, PrivateKey, SealedShare};
+use rusty_secrets::sss;
+
+fn holders(count: usize) -> Vec<PrivateKey> {
+    (0..count)
+        .map(|_| PrivateKey::generate().unwrap())
+        .collect()
+}
+
+#[test]
+fn test_split_sealed_then_recover() {
+    let holders = holders(5);
+    let recipients = holders.iter().map(PrivateKey::public_key).collect::<Vec<_>>();
+
+    let sss = sss::SSS::default();
+    let sealed = split_sealed(&sss, 3, b"Hello, World!", &recipients, true).unwrap();
+    assert_eq!(sealed.len(), 5);
+
+    let shares = sealed
+        .into_iter()
+        .zip(&holders)
+        .skip(1)
+        .take(3)
+        .map(|(sealed, holder)| {
+            let raw = sealed.into_string();
+            let sealed = SealedShare::from_string(&raw).unwrap();
+            sss.parse_share(&open_share(&sealed, holder).unwrap())
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(sss.recover(&shares, true).unwrap(), b"Hello, World!");
+}
+
+#[test]
+fn test_split_sealed_with_every_scheme() {
+    let holders = holders(3);
+    let recipients = holders.iter().map(PrivateKey::public_key).collect::<Vec<_>>();
+
+    for kind in &["sss", "wrapped_secrets", "thss", "ss1"] {
+        let kind = match kind.parse::<SchemeKind>() {
+            Ok(kind) => kind,
+            Err(_) => continue,
+        };
+
+        let sealed = split_sealed(&kind, 2, b"Hello, World!", &recipients, false).unwrap();
+        let shares = vec![
+            open_share(&sealed[2], &holders[2]).unwrap(),
+            open_share(&sealed[0], &holders[0]).unwrap(),
+        ];
+        assert_eq!(kind.recover(&shares, false).unwrap(), b"Hello, World!");
+    }
+}
+
+#[test]
+#[should_panic(expected = "WrongRecipient")]
+fn test_open_share_with_another_key() {
+    let holders = holders(2);
+    let recipients = holders.iter().map(PrivateKey::public_key).collect::<Vec<_>>();
+
+    let sealed = split_sealed(&SchemeKind::SSS, 2, b"Hello, World!", &recipients, false).unwrap();
+    open_share(&sealed[0], &holders[1]).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "CannotOpenShare")]
+fn test_open_tampered_share() {
+    let holders = holders(2);
+    let recipients = holders.iter().map(PrivateKey::public_key).collect::<Vec<_>>();
+
+    let mut sealed =
+        split_sealed(&SchemeKind::SSS, 2, b"Hello, World!", &recipients, false).unwrap();
+    let last = sealed[1].ciphertext.len() - 1;
+    sealed[1].ciphertext[last] ^= 0x80;
+    open_share(&sealed[1], &holders[1]).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "InvalidKey")]
+fn test_invalid_public_key() {
+    "not a key".parse::<rusty_secrets::sealing::PublicKey>().unwrap();
+}
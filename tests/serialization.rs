@@ -0,0 +1,219 @@
+#![cfg(feature = "serialization")]
+
+extern crate rusty_secrets;
+extern crate serde;
+extern crate serde_json;
+
+use rusty_secrets::armor::Scheme;
+use rusty_secrets::recovery::RecoverySession;
+use rusty_secrets::share_set::ShareSetId;
+use rusty_secrets::sss;
+use rusty_secrets::xor;
+
+#[test]
+fn test_share_set_id_roundtrip() {
+    let id = ShareSetId::from_bytes(&[0xab; 16]).unwrap();
+    let json = serde_json::to_string(&id).unwrap();
+    assert_eq!(json, "\"abababababababababababababababab\"");
+    assert_eq!(id, serde_json::from_str(&json).unwrap());
+
+    assert!(serde_json::from_str::<ShareSetId>("\"abab\"").is_err());
+    assert!(serde_json::from_str::<ShareSetId>("\"not hexadecimal!\"").is_err());
+}
+
+#[test]
+fn test_sss_share_roundtrip() {
+    for &signed in &[false, true] {
+        let share = sss::split_secret(2, 3, b"Hello, World!", signed)
+            .unwrap()
+            .remove(0);
+        let json = format!("\"{}\"", share);
+
+        let parsed = serde_json::from_str::<sss::Share>(&json).unwrap();
+        assert_eq!(parsed.signature_pair.is_some(), signed);
+        assert_eq!(serde_json::to_string(&parsed).unwrap(), json);
+    }
+
+    assert!(serde_json::from_str::<sss::Share>("\"2-1-\"").is_err());
+}
+
+#[test]
+fn test_xor_share_roundtrip() {
+    for &signed in &[false, true] {
+        let share = xor::split_secret(3, b"Hello, World!", signed)
+            .unwrap()
+            .remove(0);
+        let json = format!("\"{}\"", share);
+
+        let parsed = serde_json::from_str::<xor::Share>(&json).unwrap();
+        assert_eq!(parsed.is_signed(), signed);
+        assert_eq!(serde_json::to_string(&parsed).unwrap(), json);
+    }
+}
+
+#[test]
+fn test_recovery_session_roundtrip() {
+    let shares = sss::split_secret(3, 5, b"Hello, World!", false).unwrap();
+    let mut session = RecoverySession::new(Scheme::SSS, false);
+    session.add_share(&shares[0]).unwrap();
+    session.add_share(&shares[3]).unwrap();
+
+    let json = serde_json::to_string(&session).unwrap();
+    let mut resumed = serde_json::from_str::<RecoverySession>(&json).unwrap();
+    assert_eq!(resumed, session);
+    assert_eq!(resumed.add_share(&shares[1]).unwrap(), 0);
+    assert_eq!(resumed.recover().unwrap(), b"Hello, World!");
+
+    // The shares are validated again.
+    let json = format!(
+        r#"{{"scheme":"SSS","verify_signatures":false,"shares":["{}","{}"]}}"#,
+        shares[0], shares[0]
+    );
+    assert!(serde_json::from_str::<RecoverySession>(&json).is_err());
+    assert!(serde_json::from_str::<RecoverySession>(r#"{"scheme":"ROT13"}"#).is_err());
+}
+
+#[cfg(feature = "dss")]
+mod dss {
+
+    use serde::{Deserialize, Serialize};
+    use serde_json;
+
+    use rusty_secrets::dss::thss::MetaData;
+    use rusty_secrets::dss::{ss1, thss, AccessStructure};
+
+    fn roundtrip<T>(value: &T) -> T
+    where
+        T: Serialize + for<'de> Deserialize<'de>,
+    {
+        let json = serde_json::to_string(value).unwrap();
+        serde_json::from_str(&json).unwrap()
+    }
+
+    fn metadata() -> MetaData {
+        let mut metadata = MetaData::new();
+        metadata
+            .tags
+            .insert("mime_type".to_string(), "text/plain".to_string());
+        metadata
+    }
+
+    #[test]
+    fn test_access_structure_roundtrip() {
+        let access = AccessStructure {
+            threshold: 7,
+            shares_count: 10,
+        };
+        assert_eq!(access, roundtrip(&access));
+    }
+
+    #[test]
+    fn test_metadata_roundtrip() {
+        assert_eq!(metadata(), roundtrip(&metadata()));
+        assert_eq!(MetaData::new(), roundtrip(&MetaData::new()));
+    }
+
+    #[test]
+    fn test_thss_share_roundtrip() {
+        let shares = thss::ThSS::builder()
+            .sign_shares(true)
+            .build()
+            .split_secret(2, 3, b"Hello, World!", &Some(metadata()))
+            .unwrap();
+        for share in shares {
+            assert_eq!(share, roundtrip(&share));
+        }
+    }
+
+    #[test]
+    fn test_ss1_share_roundtrip() {
+        let shares = ss1::SS1::builder()
+            .sign_shares(true)
+            .build()
+            .unwrap()
+            .split_secret(2, 3, b"Hello, World!", ss1::Reproducibility::reproducible(), &None)
+            .unwrap();
+        for share in shares {
+            assert_eq!(share, roundtrip(&share));
+        }
+    }
+
+    #[test]
+    fn test_ss1_legacy_share_deserialization() {
+        let shares =
+            ss1::split_secret(2, 3, b"Hello, World!", ss1::Reproducibility::none(), &None).unwrap();
+
+        // Shares serialized before their parameters were recorded.
+        let mut json = serde_json::to_value(&shares[0]).unwrap();
+        json.as_object_mut().unwrap().remove("parameters");
+        let share: ss1::Share = serde_json::from_value(json).unwrap();
+        assert_eq!(share.parameters, ss1::Parameters::default());
+        assert_eq!(share, shares[0]);
+    }
+
+    #[test]
+    fn test_legacy_signature_deserialization() {
+        let shares = thss::ThSS::builder()
+            .sign_shares(true)
+            .build()
+            .split_secret(2, 3, b"Hello, World!", &None)
+            .unwrap();
+
+        // Signatures serialized before their hash function was recorded.
+        let mut json = serde_json::to_value(&shares[0]).unwrap();
+        json["signature"]
+            .as_object_mut()
+            .unwrap()
+            .remove("hash_algorithm");
+        let share: thss::Share = serde_json::from_value(json).unwrap();
+        assert_eq!(share, shares[0]);
+    }
+
+    #[test]
+    fn test_reproducibility_roundtrip() {
+        let values = vec![
+            ss1::Reproducibility::reproducible(),
+            ss1::Reproducibility::none(),
+            ss1::Reproducibility::seeded(b"seed".to_vec()),
+            ss1::Reproducibility::with_entropy(vec![0, 1, 2, 255]),
+        ];
+        for value in values {
+            assert_eq!(value, roundtrip(&value));
+        }
+    }
+
+    #[test]
+    fn test_binary_fields_are_base64() {
+        let share = thss::Share {
+            id: 1,
+            threshold: 2,
+            shares_count: 3,
+            data: vec![0, 1, 2, 255],
+            metadata: None,
+            share_set_id: None,
+            signature: None,
+        };
+        let json = serde_json::to_string(&share).unwrap();
+        assert_eq!(
+            json,
+            "{\"id\":1,\"threshold\":2,\"shares_count\":3,\"data\":\"AAEC/w==\",\
+             \"metadata\":null,\"share_set_id\":null,\"signature\":null}"
+        );
+
+        let seeded = ss1::Reproducibility::seeded(vec![0, 1, 2, 255]);
+        let json = serde_json::to_string(&seeded).unwrap();
+        assert_eq!(json, "{\"Seeded\":\"AAEC/w==\"}");
+    }
+
+    #[test]
+    fn test_optional_fields_may_be_omitted() {
+        let json = "{\"id\":1,\"threshold\":2,\"shares_count\":3,\"data\":\"AAEC/w==\"}";
+        let share: thss::Share = serde_json::from_str(json).unwrap();
+        assert_eq!(share.data, vec![0, 1, 2, 255]);
+        assert_eq!(share.metadata, None);
+        assert_eq!(share.share_set_id, None);
+
+        let json = "{\"id\":1,\"threshold\":2,\"shares_count\":3}";
+        assert!(serde_json::from_str::<thss::Share>(json).is_err());
+    }
+}
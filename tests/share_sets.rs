@@ -0,0 +1,140 @@
+extern crate rusty_secrets;
+
+use rusty_secrets::sss;
+use rusty_secrets::wrapped_secrets;
+
+#[test]
+fn test_sort_mixed_sss_shares() {
+    let shares_1 = sss::split_secret(2, 3, b"first secret", true).unwrap();
+    let shares_2 = sss::split_secret(3, 5, b"second secret", true).unwrap();
+    let shares_3 = sss::split_secret(2, 4, b"third secret", true).unwrap();
+
+    let pile = vec![
+        shares_2[4].clone(),
+        shares_1[0].clone(),
+        "2-1-!!!".to_string(),
+        shares_3[3].clone(),
+        shares_2[0].clone(),
+        shares_1[2].clone(),
+        shares_1[2].clone(),
+        shares_2[2].clone(),
+    ];
+
+    let sorted = sss::sort_shares(&pile, true);
+
+    assert_eq!(sorted.sets.len(), 3);
+    assert_eq!(sorted.rejected.len(), 1);
+    assert_eq!(sorted.rejected[0].0, "2-1-!!!");
+
+    let second = &sorted.sets[0];
+    assert_eq!(second.threshold, 3);
+    assert_eq!(second.share_ids, vec![5, 1, 3]);
+    assert_eq!(second.missing, 0);
+    assert_eq!(
+        second.recovered.as_ref().unwrap().as_ref().unwrap(),
+        b"second secret"
+    );
+
+    let first = &sorted.sets[1];
+    assert_eq!(first.share_ids, vec![1, 3]);
+    assert!(first.is_complete());
+    assert_eq!(
+        first.recovered.as_ref().unwrap().as_ref().unwrap(),
+        b"first secret"
+    );
+
+    let third = &sorted.sets[2];
+    assert_eq!(third.threshold, 2);
+    assert_eq!(third.missing, 1);
+    assert!(third.recovered.is_none());
+
+    assert!(first.id.is_some() && second.id.is_some() && third.id.is_some());
+    assert!(first.id != second.id && second.id != third.id);
+}
+
+#[test]
+fn test_sort_mixed_wrapped_shares() {
+    let shares_1 = wrapped_secrets::split_secret(2, 3, b"first secret", None, false).unwrap();
+    let shares_2 = wrapped_secrets::split_secret(2, 3, b"other secret", None, false).unwrap();
+
+    let pile = vec![
+        shares_1[0].clone(),
+        shares_2[0].clone(),
+        shares_2[1].clone(),
+        shares_1[1].clone(),
+    ];
+
+    let sorted = wrapped_secrets::sort_shares(&pile, false);
+
+    assert_eq!(sorted.sets.len(), 2);
+    assert!(sorted.rejected.is_empty());
+
+    let secrets = sorted
+        .sets
+        .into_iter()
+        .map(|set| set.recovered.unwrap().unwrap().take_secret())
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        secrets,
+        vec![b"first secret".to_vec(), b"other secret".to_vec()]
+    );
+}
+
+#[test]
+fn test_sort_legacy_shares_without_share_set_id() {
+    let pile = vec![
+        "2-1-Cha7s14Q/mSwWko0ittr+/Uf79RHQMIP".to_string(),
+        "2-4-ChaydsUJDypD9ZWxwvIICh/cmZvzusOF".to_string(),
+    ];
+
+    let sorted = sss::sort_shares(&pile, false);
+
+    assert_eq!(sorted.sets.len(), 1);
+    assert_eq!(sorted.sets[0].id, None);
+    assert!(sorted.sets[0].recovered.as_ref().unwrap().is_ok());
+}
+
+#[cfg(feature = "dss")]
+mod dss {
+    use rusty_secrets::dss::{ss1, thss};
+
+    #[test]
+    fn test_sort_mixed_thss_shares() {
+        let shares_1 = thss::split_secret(2, 3, b"first secret", &None).unwrap();
+        let shares_2 = thss::split_secret(2, 3, b"other secret", &None).unwrap();
+
+        let pile = vec![
+            shares_1[0].clone().into_string(),
+            shares_2[0].clone().into_string(),
+            shares_1[2].clone().into_string(),
+        ];
+
+        let sorted = thss::sort_shares(&pile);
+
+        assert_eq!(sorted.sets.len(), 2);
+        let (secret, _, _) = sorted.sets[0].recovered.as_ref().unwrap().as_ref().unwrap().clone();
+        assert_eq!(secret, b"first secret".to_vec());
+        assert_eq!(sorted.sets[1].missing, 1);
+    }
+
+    #[test]
+    fn test_reproducible_ss1_shares_share_the_same_set() {
+        let secret = b"reproducible secret";
+        let shares_1 = ss1::split_secret(2, 3, secret, ss1::Reproducibility::reproducible(), &None)
+            .unwrap();
+        let shares_2 = ss1::split_secret(2, 3, secret, ss1::Reproducibility::reproducible(), &None)
+            .unwrap();
+
+        let pile = vec![
+            shares_1[0].clone().into_string(),
+            shares_2[1].clone().into_string(),
+        ];
+
+        let sorted = ss1::sort_shares(&pile);
+
+        assert_eq!(sorted.sets.len(), 1);
+        let (recovered, _, _) = sorted.sets[0].recovered.as_ref().unwrap().as_ref().unwrap().clone();
+        assert_eq!(recovered, secret.to_vec());
+    }
+}
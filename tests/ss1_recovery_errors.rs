@@ -1,7 +1,8 @@
 #![cfg(feature = "dss")]
 
 extern crate rusty_secrets;
-use rusty_secrets::dss::ss1::{recover_secret, split_secret, Reproducibility, Share};
+use rusty_secrets::dss::ss1::{recover_secret, split_secret, Parameters, Reproducibility, Share,
+                              SS1};
 
 const TEST_THRESHOLD: u8 = 2;
 const TEST_SHARES_COUNT: u8 = 2;
@@ -41,6 +42,9 @@ fn test_recover_2_parts_share() {
         data: "CgmKQZHMO+5n5pU".to_string().into_bytes(),
         hash: hash.clone(),
         metadata: None,
+        share_set_id: None,
+        parameters: Parameters::default(),
+        signature: None,
     };
     let share2 = Share {
         id: 2,
@@ -49,6 +53,9 @@ fn test_recover_2_parts_share() {
         data: "".to_string().into_bytes(),
         hash: hash.clone(),
         metadata: None,
+        share_set_id: None,
+        parameters: Parameters::default(),
+        signature: None,
     };
 
     let shares = vec![share1, share2];
@@ -68,6 +75,9 @@ fn test_recover_0_share_num() {
         data: "1YAYwmOHqZ69jA".to_string().into_bytes(),
         hash: hash.clone(),
         metadata: None,
+        share_set_id: None,
+        parameters: Parameters::default(),
+        signature: None,
     };
     let share2 = Share {
         id: 1,
@@ -76,6 +86,9 @@ fn test_recover_0_share_num() {
         data: "YJZQDGm22Y77Gw".to_string().into_bytes(),
         hash: hash.clone(),
         metadata: None,
+        share_set_id: None,
+        parameters: Parameters::default(),
+        signature: None,
     };
 
     let shares = vec![share1, share2];
@@ -94,14 +107,16 @@ fn test_recover_0_share_num() {
 //         threshold: 2,
 //         shares_count: 2,
 //         data: "1YAYwmOHqZ69jA".to_string().into_bytes(),
-//         metadata: None
+//         metadata: None,
+//         share_set_id: None
 //     };
 //     let share2 = Share {
 //         id: 2,
 //         threshold: 2,
 //         shares_count: 2,
 //         data: "YJZQDG((((m22Y)))77Gw".to_string().into_bytes(),
-//         metadata: None
+//         metadata: None,
+//         share_set_id: None
 //     };
 //
 //     let shares = vec![share1, share2];
@@ -120,6 +135,9 @@ fn test_recover_duplicate_shares_number() {
         data: "1YAYwmOHqZ69jA".to_string().into_bytes(),
         hash: hash.clone(),
         metadata: None,
+        share_set_id: None,
+        parameters: Parameters::default(),
+        signature: None,
     };
     let share2 = Share {
         id: 1,
@@ -128,6 +146,9 @@ fn test_recover_duplicate_shares_number() {
         data: "YJZQDGm22Y77Gw".to_string().into_bytes(),
         hash: hash.clone(),
         metadata: None,
+        share_set_id: None,
+        parameters: Parameters::default(),
+        signature: None,
     };
 
     let shares = vec![share1, share2];
@@ -146,6 +167,9 @@ fn test_recover_too_few_shares() {
         data: "1YAYwmOHqZ69jA".to_string().into_bytes(),
         hash: hash.clone(),
         metadata: None,
+        share_set_id: None,
+        parameters: Parameters::default(),
+        signature: None,
     };
     let share2 = Share {
         id: 2,
@@ -154,9 +178,43 @@ fn test_recover_too_few_shares() {
         data: "YJZQDGm22Y77Gw".to_string().into_bytes(),
         hash: hash.clone(),
         metadata: None,
+        share_set_id: None,
+        parameters: Parameters::default(),
+        signature: None,
     };
 
     let shares = vec![share1, share2];
 
     recover_secret(&shares).unwrap();
 }
+
+#[test]
+#[should_panic(expected = "MissingSignature")]
+fn test_recover_unsigned_shares_with_verification() {
+    let shares = split_secret(2, 3, TEST_SECRET, TEST_REPRODUCIBILITY, &None).unwrap();
+    let scheme = SS1::builder().verify_signatures(true).build().unwrap();
+
+    scheme.recover_secret(&shares).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "IncompatibleSets")]
+fn test_recover_shares_signed_by_different_dealers() {
+    // Reproducible shares of the same secret belong to the same share set,
+    // but each split is signed with fresh keys.
+    let scheme = SS1::builder()
+        .sign_shares(true)
+        .verify_signatures(true)
+        .build()
+        .unwrap();
+    let shares_1 = scheme
+        .split_secret(2, 3, TEST_SECRET, TEST_REPRODUCIBILITY, &None)
+        .unwrap();
+    let shares_2 = scheme
+        .split_secret(2, 3, TEST_SECRET, TEST_REPRODUCIBILITY, &None)
+        .unwrap();
+
+    let shares = vec![shares_1[0].clone(), shares_2[1].clone()];
+
+    scheme.recover_secret(&shares).unwrap();
+}
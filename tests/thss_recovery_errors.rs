@@ -2,7 +2,7 @@
 
 extern crate rusty_secrets;
 
-use rusty_secrets::dss::thss::{recover_secret, Share};
+use rusty_secrets::dss::thss::{recover_secret, split_secret, Share, ThSS};
 
 #[test]
 #[should_panic(expected = "EmptyShares")]
@@ -20,6 +20,8 @@ fn test_recover_2_parts_share() {
         shares_count: 2,
         data: "CgmKQZHMO+5n5pU".to_string().into_bytes(),
         metadata: None,
+        share_set_id: None,
+        signature: None,
     };
     let share2 = Share {
         id: 2,
@@ -27,6 +29,8 @@ fn test_recover_2_parts_share() {
         shares_count: 2,
         data: "".to_string().into_bytes(),
         metadata: None,
+        share_set_id: None,
+        signature: None,
     };
 
     let shares = vec![share1, share2];
@@ -43,6 +47,8 @@ fn test_recover_0_share_num() {
         shares_count: 2,
         data: "1YAYwmOHqZ69jA".to_string().into_bytes(),
         metadata: None,
+        share_set_id: None,
+        signature: None,
     };
     let share2 = Share {
         id: 1,
@@ -50,6 +56,8 @@ fn test_recover_0_share_num() {
         shares_count: 2,
         data: "YJZQDGm22Y77Gw".to_string().into_bytes(),
         metadata: None,
+        share_set_id: None,
+        signature: None,
     };
 
     let shares = vec![share1, share2];
@@ -68,14 +76,16 @@ fn test_recover_0_share_num() {
 //         threshold: 2,
 //         shares_count: 2,
 //         data: "1YAYwmOHqZ69jA".to_string().into_bytes(),
-//         metadata: None
+//         metadata: None,
+//         share_set_id: None
 //     };
 //     let share2 = Share {
 //         id: 2,
 //         threshold: 2,
 //         shares_count: 2,
 //         data: "YJZQDG((((m22Y)))77Gw".to_string().into_bytes(),
-//         metadata: None
+//         metadata: None,
+//         share_set_id: None
 //     };
 //
 //     let shares = vec![share1, share2];
@@ -92,6 +102,8 @@ fn test_recover_duplicate_shares_number() {
         shares_count: 2,
         data: "1YAYwmOHqZ69jA".to_string().into_bytes(),
         metadata: None,
+        share_set_id: None,
+        signature: None,
     };
     let share2 = Share {
         id: 1,
@@ -99,6 +111,8 @@ fn test_recover_duplicate_shares_number() {
         shares_count: 2,
         data: "YJZQDGm22Y77Gw".to_string().into_bytes(),
         metadata: None,
+        share_set_id: None,
+        signature: None,
     };
 
     let shares = vec![share1, share2];
@@ -115,6 +129,8 @@ fn test_recover_too_few_shares() {
         shares_count: 3,
         data: "1YAYwmOHqZ69jA".to_string().into_bytes(),
         metadata: None,
+        share_set_id: None,
+        signature: None,
     };
     let share2 = Share {
         id: 2,
@@ -122,9 +138,42 @@ fn test_recover_too_few_shares() {
         shares_count: 3,
         data: "YJZQDGm22Y77Gw".to_string().into_bytes(),
         metadata: None,
+        share_set_id: None,
+        signature: None,
     };
 
     let shares = vec![share1, share2];
 
     recover_secret(&shares).unwrap();
 }
+
+#[test]
+#[should_panic(expected = "MissingSignature")]
+fn test_recover_unsigned_shares_with_verification() {
+    let shares = split_secret(2, 3, b"secret", &None).unwrap();
+    let scheme = ThSS::builder().verify_signatures(true).build();
+
+    scheme.recover_secret(&shares).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "InvalidSignature")]
+fn test_recover_tampered_signed_share() {
+    let scheme = ThSS::builder()
+        .sign_shares(true)
+        .verify_signatures(true)
+        .build();
+    let mut shares = scheme.split_secret(2, 3, b"secret", &None).unwrap();
+    shares[1].data[0] ^= 1;
+
+    scheme.recover_secret(&shares[..2]).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "CorruptedShares")]
+fn test_recover_corrupted_share() {
+    let mut shares = split_secret(2, 3, b"secret", &None).unwrap();
+    shares[2].data[0] ^= 1;
+
+    recover_secret(&shares).unwrap();
+}
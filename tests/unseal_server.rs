@@ -0,0 +1,73 @@
+#![cfg(all(feature = "server", unix))]
+
+extern crate rusty_secrets;
+
+use std::env;
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::os::unix::fs::PermissionsExt;
+use std::os::unix::net::UnixStream;
+use std::path::PathBuf;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use rusty_secrets::armor::Scheme;
+use rusty_secrets::server::{SecretSink, Server, Unsealer, DEFAULT_TIMEOUT_SECS};
+use rusty_secrets::sss;
+
+fn socket_path(name: &str) -> PathBuf {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+    env::temp_dir().join(format!("rusty-secrets-{}-{}.sock", name, now.subsec_nanos()))
+}
+
+fn request(stream: &mut UnixStream, command: &str) -> String {
+    writeln!(stream, "{}", command).unwrap();
+    let mut response = String::new();
+    BufReader::new(stream.try_clone().unwrap())
+        .read_line(&mut response)
+        .unwrap();
+    response.trim().to_string()
+}
+
+#[test]
+fn test_unseal_over_socket() {
+    let path = socket_path("unseal");
+    let shares = sss::split_secret(3, 5, b"Hello, World!", true).unwrap();
+
+    let (sender, receiver) = mpsc::channel();
+    let server = {
+        let path = path.clone();
+        thread::spawn(move || {
+            let sink = SecretSink::Callback(Box::new(move |secret| {
+                sender.send(secret).unwrap();
+                Ok(())
+            }));
+            let timeout = Duration::from_secs(DEFAULT_TIMEOUT_SECS);
+            let unsealer = Unsealer::new(Scheme::SSS, true, timeout, sink);
+            Server::bind(&path, unsealer).unwrap().run().unwrap();
+        })
+    };
+
+    while !path.exists() {
+        thread::sleep(Duration::from_millis(10));
+    }
+    let mode = fs::metadata(&path).unwrap().permissions().mode();
+    assert_eq!(mode & 0o777, 0o600);
+
+    // Operators submit their shares over separate connections.
+    let mut first = UnixStream::connect(&path).unwrap();
+    assert!(request(&mut first, &format!("share {}", shares[1])).contains("missing=2"));
+    assert!(request(&mut first, &format!("share {}", shares[1])).starts_with("error 32 "));
+    drop(first);
+
+    let mut second = UnixStream::connect(&path).unwrap();
+    assert!(request(&mut second, "status").contains("shares=1"));
+    assert!(request(&mut second, &format!("share {}", shares[4])).contains("missing=1"));
+    let response = request(&mut second, &format!("share {}", shares[0]));
+    assert!(response.starts_with("ok state=unsealed"));
+
+    server.join().unwrap();
+    assert_eq!(receiver.recv().unwrap(), b"Hello, World!");
+    assert!(!path.exists());
+}
@@ -0,0 +1,70 @@
+extern crate rusty_secrets;
+
+use rusty_secrets::xor::{recover_secret, split_secret};
+
+#[test]
+#[should_panic(expected = "EmptyShares")]
+fn test_recover_no_shares() {
+    let shares = vec![];
+    recover_secret(&shares, false).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "MissingShares")]
+fn test_recover_missing_share() {
+    let shares = split_secret(3, b"Hello, World!", false).unwrap();
+    recover_secret(&shares[..2], false).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "DuplicateShareId")]
+fn test_recover_duplicate_share() {
+    let mut shares = split_secret(3, b"Hello, World!", false).unwrap();
+    shares[2] = shares[0].clone();
+    recover_secret(&shares, false).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "InconsistentShareSets")]
+fn test_recover_shares_of_different_splits() {
+    let mut shares = split_secret(3, b"Hello, World!", false).unwrap();
+    let others = split_secret(3, b"Hello, World!", false).unwrap();
+    shares[1] = others[1].clone();
+    recover_secret(&shares, false).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "ShareParsingInvalidShareId")]
+fn test_recover_share_id_above_shares_count() {
+    let share = split_secret(2, b"Hello, World!", false).unwrap().remove(0);
+    let share = format!("2-3-{}", share.splitn(3, '-').nth(2).unwrap());
+    recover_secret(&[share], false).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "The share is not an Xor share.")]
+fn test_recover_sss_share() {
+    let shares = rusty_secrets::sss::split_secret(2, 2, b"Hello, World!", false).unwrap();
+    recover_secret(&shares, false).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "MissingSignature")]
+fn test_recover_unsigned_shares_with_verification() {
+    let shares = split_secret(3, b"Hello, World!", false).unwrap();
+    recover_secret(&shares, true).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "InvalidXorThreshold")]
+fn test_split_threshold_below_shares_count() {
+    use rusty_secrets::scheme::{SchemeKind, SecretSharingScheme};
+
+    SchemeKind::Xor.split(2, 3, b"Hello, World!", false).unwrap();
+}
+
+#[test]
+fn test_recover_signed_shares() {
+    let shares = split_secret(5, b"Hello, World!", true).unwrap();
+    assert_eq!(recover_secret(&shares, true).unwrap(), b"Hello, World!");
+}